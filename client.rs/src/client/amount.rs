@@ -3,17 +3,87 @@ use near_sdk::json_types::U128;
 use serde::{de, Deserialize, Serialize};
 use std::str::FromStr;
 
+use super::result::{ClientError, Result as ClientResult};
+
+// Largest power of ten that fits into U256
+const MAX_DECIMALS: u8 = 77;
+
+/// Token amount in base units
 #[derive(Default, Clone, Debug, PartialEq, PartialOrd, Eq)]
 pub struct Amount(U256);
 
 impl Amount {
-    pub fn normalize_to_u64(&self, decimals: usize) -> u64 {
-        let unit: U256 = U256::exp10(decimals);
+    /// Parses an amount in whole tokens, like `1.5`, into base units of a token with the decimals
+    pub fn from_decimal_str(s: &str, decimals: u8) -> ClientResult<Self> {
+        let invalid = |reason: &str| ClientError::InvalidAmount(format!("`{s}`: {reason}"));
+
+        let (integer, fraction) = s.trim().split_once('.').unwrap_or((s.trim(), ""));
+
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(invalid("no digits"));
+        }
+
+        if !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid("not a decimal number"));
+        }
+
+        if fraction.len() > decimals as usize {
+            return Err(invalid(&format!("more than {decimals} decimal places")));
+        }
+
+        let parse = |digits: &str| match digits {
+            "" => Ok(U256::zero()),
+            _ => U256::from_dec_str(digits).map_err(|_| invalid("too large")),
+        };
+
+        let fraction_unit = unit(decimals - fraction.len() as u8)?;
+        let (integer, fraction) = (parse(integer)?, parse(fraction)?);
+
+        integer
+            .checked_mul(unit(decimals)?)
+            .and_then(|value| value.checked_add(fraction.checked_mul(fraction_unit)?))
+            .map(Self)
+            .ok_or_else(|| invalid("too large"))
+    }
+
+    /// Formats the amount in whole tokens of a token with the decimals
+    pub fn to_decimal_string(&self, decimals: u8) -> ClientResult<String> {
+        let unit = unit(decimals)?;
+        let (integer, fraction) = (self.0 / unit, self.0 % unit);
+
+        if fraction.is_zero() {
+            return Ok(integer.to_string());
+        }
 
-        (self.0 / unit).as_u64()
+        let fraction = format!(
+            "{:0>width$}",
+            fraction.to_string(),
+            width = decimals as usize
+        );
+
+        Ok(format!("{integer}.{}", fraction.trim_end_matches('0')))
+    }
+
+    /// Amount in whole tokens of a token with the decimals, rounded down
+    pub fn normalize_to_u64(&self, decimals: u8) -> ClientResult<u64> {
+        Self(self.0 / unit(decimals)?).try_into()
     }
 }
 
+fn unit(decimals: u8) -> ClientResult<U256> {
+    if decimals > MAX_DECIMALS {
+        return Err(ClientError::InvalidAmount(format!(
+            "{decimals} decimals are not supported"
+        )));
+    }
+
+    Ok(U256::exp10(decimals as usize))
+}
+
 impl Serialize for Amount {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         self.0.to_string().serialize(serializer)
@@ -59,15 +129,27 @@ impl From<Amount> for U256 {
     }
 }
 
-impl From<Amount> for U128 {
-    fn from(value: Amount) -> Self {
-        U128(value.0.as_u128())
+impl TryFrom<Amount> for U128 {
+    type Error = ClientError;
+
+    fn try_from(value: Amount) -> ClientResult<Self> {
+        if value.0.bits() > 128 {
+            return Err(ClientError::InvalidAmount(format!("{value} exceeds u128")));
+        }
+
+        Ok(U128(value.0.as_u128()))
     }
 }
 
-impl From<Amount> for u64 {
-    fn from(value: Amount) -> Self {
-        value.0.as_u64()
+impl TryFrom<Amount> for u64 {
+    type Error = ClientError;
+
+    fn try_from(value: Amount) -> ClientResult<Self> {
+        if value.0.bits() > 64 {
+            return Err(ClientError::InvalidAmount(format!("{value} exceeds u64")));
+        }
+
+        Ok(value.0.as_u64())
     }
 }
 
@@ -78,3 +160,43 @@ impl FromStr for Amount {
         Ok(Self(U256::from_dec_str(s)?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_str() {
+        assert_eq!(
+            Amount::from_decimal_str("1.5", 6).unwrap(),
+            Amount::from(1_500_000)
+        );
+        assert_eq!(Amount::from_decimal_str("2", 0).unwrap(), Amount::from(2));
+        assert_eq!(
+            Amount::from_decimal_str(".25", 2).unwrap(),
+            Amount::from(25)
+        );
+
+        assert!(Amount::from_decimal_str("1.234", 2).is_err());
+        assert!(Amount::from_decimal_str("1,5", 6).is_err());
+        assert!(Amount::from_decimal_str(".", 6).is_err());
+        assert!(Amount::from_decimal_str("-1", 6).is_err());
+        assert!(Amount::from_decimal_str(&U256::MAX.to_string(), 1).is_err());
+
+        assert_eq!(Amount::from(1_500_000).to_decimal_string(6).unwrap(), "1.5");
+        assert_eq!(Amount::from(25).to_decimal_string(2).unwrap(), "0.25");
+        assert_eq!(Amount::from(3000).to_decimal_string(3).unwrap(), "3");
+    }
+
+    #[test]
+    fn test_checked_conversions() {
+        let large = Amount::from(U256::from(u64::MAX) + 1);
+
+        assert_eq!(u64::try_from(Amount::from(42)).unwrap(), 42);
+        assert!(u64::try_from(large.clone()).is_err());
+        assert_eq!(U128::try_from(large).unwrap(), U128(u64::MAX as u128 + 1));
+        assert!(U128::try_from(Amount::from(U256::MAX)).is_err());
+
+        assert_eq!(Amount::from(1_500_000).normalize_to_u64(6).unwrap(), 1);
+    }
+}
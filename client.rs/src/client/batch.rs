@@ -0,0 +1,155 @@
+use serde::Serialize;
+
+use super::{
+    entities::{
+        address::{CreateAddressInput, UpdateAddressInput},
+        asset::{CreateAssetInput, UpdateAssetInput},
+        case::{CreateCaseInput, UpdateCaseInput},
+    },
+    result::{ClientError, Tx},
+};
+
+/// Operation that can be sent in a batch
+#[derive(Clone, Debug)]
+pub enum BatchOperation {
+    CreateCase(CreateCaseInput),
+    UpdateCase(UpdateCaseInput),
+    CreateAddress(CreateAddressInput),
+    UpdateAddress(UpdateAddressInput),
+    CreateAsset(CreateAssetInput),
+    UpdateAsset(UpdateAssetInput),
+}
+
+impl BatchOperation {
+    /// Contract method of the operation
+    pub fn method(&self) -> &'static str {
+        match self {
+            Self::CreateCase(_) => "create_case",
+            Self::UpdateCase(_) => "update_case",
+            Self::CreateAddress(_) => "create_address",
+            Self::UpdateAddress(_) => "update_address",
+            Self::CreateAsset(_) => "create_asset",
+            Self::UpdateAsset(_) => "update_asset",
+        }
+    }
+}
+
+/// Builder of operations that are sent in as few transactions as the network allows
+///
+/// Operations are executed in the order they are added, so a case can be created together with
+/// its addresses and assets. EVM and NEAR send the batch atomically in one transaction, NEAR
+/// rejects batches that don't fit into the gas limit of a transaction. Solana splits the batch
+/// into transactions that fit into the packet size.
+#[derive(Default, Clone, Debug)]
+pub struct Batch {
+    operations: Vec<BatchOperation>,
+}
+
+impl Batch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn create_case(mut self, input: CreateCaseInput) -> Self {
+        self.operations.push(BatchOperation::CreateCase(input));
+        self
+    }
+
+    pub fn update_case(mut self, input: UpdateCaseInput) -> Self {
+        self.operations.push(BatchOperation::UpdateCase(input));
+        self
+    }
+
+    pub fn create_address(mut self, input: CreateAddressInput) -> Self {
+        self.operations.push(BatchOperation::CreateAddress(input));
+        self
+    }
+
+    pub fn update_address(mut self, input: UpdateAddressInput) -> Self {
+        self.operations.push(BatchOperation::UpdateAddress(input));
+        self
+    }
+
+    pub fn create_asset(mut self, input: CreateAssetInput) -> Self {
+        self.operations.push(BatchOperation::CreateAsset(input));
+        self
+    }
+
+    pub fn update_asset(mut self, input: UpdateAssetInput) -> Self {
+        self.operations.push(BatchOperation::UpdateAsset(input));
+        self
+    }
+
+    pub fn operations(&self) -> &[BatchOperation] {
+        &self.operations
+    }
+
+    pub fn into_operations(self) -> Vec<BatchOperation> {
+        self.operations
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
+
+/// Outcome of a sent batch
+#[derive(Default, Clone, Debug, Serialize)]
+pub struct BatchResult {
+    /// Transactions that landed, in the order they were sent
+    pub txs: Vec<Tx>,
+    /// Index in `txs` of the transaction each operation landed in, none if it didn't land
+    pub landed: Vec<Option<usize>>,
+    /// Error of the transaction that failed, the operations after it are not sent
+    pub error: Option<String>,
+}
+
+impl BatchResult {
+    pub(crate) fn new(operation_count: usize) -> Self {
+        Self {
+            landed: vec![None; operation_count],
+            ..Default::default()
+        }
+    }
+
+    /// Records the transaction that the operations landed in
+    pub(crate) fn push(&mut self, tx: Tx, operations: impl IntoIterator<Item = usize>) {
+        for operation in operations {
+            self.landed[operation] = Some(self.txs.len());
+        }
+
+        self.txs.push(tx);
+    }
+
+    /// Records the error that stopped the batch
+    pub(crate) fn fail(&mut self, error: ClientError) {
+        self.error = Some(error.to_string());
+    }
+
+    /// Whether all operations have landed
+    pub fn is_complete(&self) -> bool {
+        self.landed.iter().all(Option::is_some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_result() {
+        let mut result = BatchResult::new(3);
+
+        result.push(Tx::default(), [0, 1]);
+        assert!(!result.is_complete());
+
+        result.fail(ClientError::InvalidData("failed".to_string()));
+
+        assert_eq!(result.landed, vec![Some(0), Some(0), None]);
+        assert_eq!(result.error.as_deref(), Some("Invalid data: failed"));
+    }
+}
@@ -0,0 +1,89 @@
+use anyhow::bail;
+use serde::{de, Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// State of the chain that reads are made at and writes are awaited for
+///
+/// A number of confirmations is specific to EVM networks, Solana and NEAR treat it as finalized.
+/// The default keeps the behavior of clients created before commitments were configurable:
+/// EVM writes wait for one confirmation and Solana writes for a confirmed block.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Commitment {
+    /// Latest block, which may still be rolled back
+    #[default]
+    Processed,
+    /// Block voted on by the network majority
+    Confirmed,
+    /// Block that can't be rolled back
+    Finalized,
+    /// Block with the given number of blocks on top of it, including itself
+    Confirmations(u64),
+}
+
+impl Display for Commitment {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Processed => write!(f, "processed"),
+            Self::Confirmed => write!(f, "confirmed"),
+            Self::Finalized => write!(f, "finalized"),
+            Self::Confirmations(count) => write!(f, "{count}"),
+        }
+    }
+}
+
+impl FromStr for Commitment {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "processed" => Ok(Self::Processed),
+            "confirmed" => Ok(Self::Confirmed),
+            "finalized" => Ok(Self::Finalized),
+            _ => match s.parse::<u64>() {
+                Ok(0) => bail!("At least one confirmation is required"),
+                Ok(count) => Ok(Self::Confirmations(count)),
+                Err(_) => bail!("Invalid commitment: {s}"),
+            },
+        }
+    }
+}
+
+impl Serialize for Commitment {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Commitment {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commitment_from_str() {
+        assert_eq!(
+            "finalized".parse::<Commitment>().unwrap(),
+            Commitment::Finalized
+        );
+        assert_eq!(
+            "12".parse::<Commitment>().unwrap(),
+            Commitment::Confirmations(12)
+        );
+        assert!("0".parse::<Commitment>().is_err());
+        assert!("safe".parse::<Commitment>().is_err());
+    }
+
+    #[test]
+    fn test_default_commitment() {
+        assert_eq!(Commitment::default(), Commitment::Processed);
+    }
+}
@@ -0,0 +1,64 @@
+use serde::Serialize;
+use thiserror::Error;
+
+/// Business rule violation reported by the HAPI Core contract of any network
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum HapiContractError {
+    /// Caller lacks the authority, role or ownership required by the method
+    #[error("Caller is not authorized to perform this action")]
+    Unauthorized,
+    /// Caller is not a reporter, or the reporter belongs to another account
+    #[error("Invalid reporter")]
+    InvalidReporter,
+    #[error("Reporter not found")]
+    ReporterNotFound,
+    #[error("Reporter already exists")]
+    ReporterExists,
+    /// Reporter status doesn't allow the method, e.g. activating an active reporter
+    #[error("Invalid reporter status")]
+    InvalidReporterStatus,
+    #[error("Reporter is not active")]
+    ReporterInactive,
+    #[error("Reporter is frozen")]
+    ReporterFrozen,
+    /// Unlock duration of an unstaking reporter hasn't passed yet
+    #[error("Reporter stake is locked")]
+    ReporterLocked,
+    /// Case or reporter with the same ID exists, EVM doesn't tell them apart
+    #[error("Duplicate ID")]
+    DuplicateId,
+    #[error("Case not found")]
+    CaseNotFound,
+    #[error("Case already exists")]
+    CaseExists,
+    #[error("Case is closed")]
+    CaseClosed,
+    #[error("Case mismatch")]
+    CaseMismatch,
+    #[error("Address not found")]
+    AddressNotFound,
+    #[error("Address already exists")]
+    AddressExists,
+    #[error("Asset not found")]
+    AssetNotFound,
+    #[error("Asset already exists")]
+    AssetExists,
+    #[error("Already confirmed")]
+    AlreadyConfirmed,
+    #[error("Reporter can't confirm its own report")]
+    CannotConfirmOwnReport,
+    #[error("Risk score must be in 0..10 range")]
+    RiskOutOfRange,
+    /// Token doesn't match the configured one, or can't be changed
+    #[error("Invalid token")]
+    InvalidToken,
+    #[error("Insufficient tokens or allowance")]
+    InsufficientTokens,
+    #[error("Stake or reward configuration is not set")]
+    ConfigurationNotSet,
+    #[error("Invalid data")]
+    InvalidData,
+    /// Failure that doesn't correspond to a known contract error
+    #[error("{0}")]
+    Other(String),
+}
@@ -0,0 +1,107 @@
+use anyhow::bail;
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+    time::Duration,
+};
+
+/// Transaction type and fee fields that EVM transactions are priced with
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeStrategy {
+    /// Single gas price
+    Legacy,
+    /// Max fee and priority fee per gas
+    #[default]
+    Eip1559,
+}
+
+impl Display for FeeStrategy {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Legacy => write!(f, "legacy"),
+            Self::Eip1559 => write!(f, "eip1559"),
+        }
+    }
+}
+
+impl FromStr for FeeStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "legacy" => Ok(Self::Legacy),
+            "eip1559" => Ok(Self::Eip1559),
+            _ => bail!("Invalid fee strategy: {s}"),
+        }
+    }
+}
+
+/// Compute unit price that Solana transactions pay for priority
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputeUnitPrice {
+    /// Fixed price in micro-lamports
+    Fixed(u64),
+    /// Price estimated from the recent prioritization fees of the written accounts
+    Auto,
+}
+
+impl Display for ComputeUnitPrice {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Fixed(price) => write!(f, "{price}"),
+            Self::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+impl FromStr for ComputeUnitPrice {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            _ => match s.parse::<u64>() {
+                Ok(price) => Ok(Self::Fixed(price)),
+                Err(_) => bail!("Invalid compute unit price: {s}"),
+            },
+        }
+    }
+}
+
+/// Fee settings of EVM and Solana transactions
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeOptions {
+    pub strategy: FeeStrategy,
+    /// Multiplier of the estimated gas limit
+    pub gas_multiplier: f64,
+    /// Multiplier of the estimated gas price, or of the max fee and priority fee
+    pub fee_multiplier: f64,
+    /// Cap of the gas price, or of the max fee per gas, in wei
+    pub max_fee_per_gas: Option<u128>,
+    /// Cap of the priority fee per gas in wei
+    pub max_priority_fee_per_gas: Option<u128>,
+    /// Time after which a pending transaction is replaced with bumped fees, never if not set
+    pub replacement_timeout: Option<Duration>,
+    /// Compute unit limit of Solana transactions, the runtime default if not set
+    pub compute_unit_limit: Option<u32>,
+    /// Compute unit price of Solana transactions, no priority fee if not set
+    pub compute_unit_price: Option<ComputeUnitPrice>,
+    /// Cap of the estimated compute unit price in micro-lamports
+    pub max_compute_unit_price: Option<u64>,
+}
+
+impl Default for FeeOptions {
+    fn default() -> Self {
+        Self {
+            strategy: FeeStrategy::default(),
+            gas_multiplier: 1.0,
+            fee_multiplier: 1.0,
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            replacement_timeout: None,
+            compute_unit_limit: None,
+            compute_unit_price: None,
+            max_compute_unit_price: None,
+        }
+    }
+}
@@ -1,17 +1,21 @@
 use async_trait::async_trait;
 use ethers::{
     abi::Token,
-    prelude::{abigen, SignerMiddleware},
-    providers::{Http, Provider as EthersProvider},
+    contract::ContractCall,
+    prelude::abigen,
+    providers::{Middleware, Provider as EthersProvider},
     signers::{LocalWallet, Signer as EthersSigner},
-    types::Address as EthAddress,
+    types::{Address as EthAddress, BlockId, BlockNumber, Bytes, TransactionReceipt},
 };
 use serde::{Deserialize, Serialize};
-use std::{str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc, time::Duration};
+use tokio::time::{sleep, Instant};
 use uuid::Uuid;
 
 use crate::{
     client::{
+        batch::{Batch, BatchOperation, BatchResult},
+        commitment::Commitment,
         configuration::{RewardConfiguration, StakeConfiguration},
         entities::{
             address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
@@ -19,25 +23,33 @@ use crate::{
             case::{Case, CreateCaseInput, UpdateCaseInput},
             reporter::{CreateReporterInput, Reporter, UpdateReporterInput},
         },
+        events::EventName,
+        fees::FeeOptions,
         interface::HapiCoreOptions,
         result::{ClientError, Result, Tx},
     },
     HapiCore,
 };
 
-use super::error::map_ethers_error;
+use super::{
+    error::map_ethers_error,
+    fees::{build_signer, send_transaction, Signer},
+    transport::{connect, FailoverHttp},
+};
 
 abigen!(
     HAPI_CORE_CONTRACT,
     "./src/client/implementations/evm/abi/HapiCore.json"
 );
 
-pub(super) type Provider = EthersProvider<Http>;
-pub(super) type Signer = SignerMiddleware<Provider, LocalWallet>;
+pub(super) type Provider = EthersProvider<FailoverHttp>;
 
 const ZERO_PK: &str = "0000000000000000000000000000000000000000000000000000000000000001";
 pub(crate) const LOCAL_CHAIN_ID: u64 = 31337;
 
+// Time to wait for the block of a mined transaction to be finalized
+const FINALITY_TIMEOUT: Duration = Duration::from_secs(1800);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogHeader {
     pub name: String,
@@ -50,24 +62,26 @@ impl LogHeader {
     }
 }
 
+#[derive(Clone)]
 pub struct HapiCoreEvm {
     pub provider: Provider,
     pub signer: LocalWallet,
     pub contract: HAPI_CORE_CONTRACT<Signer>,
     pub client: Arc<Signer>,
+    commitment: Commitment,
+    fees: FeeOptions,
 }
 
 impl HapiCoreEvm {
     pub fn new(options: HapiCoreOptions) -> Result<Self> {
-        let provider = Provider::try_from(options.provider_url.as_str())
-            .map_err(|e| ClientError::UrlParseError(format!("`provider-url`: {e}")))?;
+        let provider = connect(&options)?;
 
         let signer =
             LocalWallet::from_str(options.private_key.unwrap_or(ZERO_PK.to_string()).as_str())
                 .map_err(|e| ClientError::Ethers(format!("`private-key`: {e}")))?
                 .with_chain_id(options.chain_id.unwrap_or(LOCAL_CHAIN_ID));
 
-        let client = Signer::new(provider.clone(), signer.clone());
+        let client = build_signer(provider.clone(), signer.clone(), options.fees.clone());
 
         let client = Arc::new(client);
 
@@ -84,6 +98,8 @@ impl HapiCoreEvm {
             signer,
             contract,
             client,
+            commitment: options.commitment,
+            fees: options.fees,
         })
     }
 
@@ -111,32 +127,202 @@ impl HapiCoreEvm {
 
         Ok(None)
     }
+
+    /// Builds the receipt of a mined transaction with the HAPI events it emitted
+    async fn get_tx(&self, receipt: TransactionReceipt) -> Result<Tx> {
+        let mut events = vec![];
+
+        for log in receipt
+            .logs
+            .iter()
+            .filter(|log| log.address == self.contract.address())
+        {
+            if let Some(header) = self.decode_event(log)? {
+                events.extend(header.name.parse::<EventName>().ok());
+            }
+        }
+
+        wait_for_finality(&self.provider, &receipt, self.commitment).await?;
+
+        build_tx(&self.provider, receipt, events).await
+    }
+
+    /// Contract call of the operation
+    fn get_operation_call(&self, operation: BatchOperation) -> Result<ContractCall<Signer, ()>> {
+        let call = match operation {
+            BatchOperation::CreateCase(input) => {
+                self.contract
+                    .create_case(input.id.as_u128(), input.name, input.url)
+            }
+            BatchOperation::UpdateCase(input) => self.contract.update_case(
+                input.id.as_u128(),
+                input.name,
+                input.url,
+                input.status as u8,
+            ),
+            BatchOperation::CreateAddress(input) => self.contract.create_address(
+                parse_address(&input.address)?,
+                input.case_id.as_u128(),
+                input.risk,
+                input.category as u8,
+            ),
+            BatchOperation::UpdateAddress(input) => self.contract.update_address(
+                parse_address(&input.address)?,
+                input.risk,
+                input.category as u8,
+                input.case_id.as_u128(),
+            ),
+            BatchOperation::CreateAsset(input) => self.contract.create_asset(
+                parse_address(&input.address)?,
+                input.asset_id.into(),
+                input.case_id.as_u128(),
+                input.risk,
+                input.category as u8,
+            ),
+            BatchOperation::UpdateAsset(input) => self.contract.update_asset(
+                parse_address(&input.address)?,
+                input.asset_id.into(),
+                input.risk,
+                input.category as u8,
+                input.case_id.as_u128(),
+            ),
+        };
+
+        Ok(call)
+    }
+
+    /// Encoded contract calls of the batch operations in the order they are added
+    fn get_batch_calls(&self, batch: Batch) -> Result<Vec<Bytes>> {
+        batch
+            .into_operations()
+            .into_iter()
+            .map(|operation| {
+                let method = operation.method();
+
+                self.get_operation_call(operation)?
+                    .calldata()
+                    .ok_or_else(|| ClientError::Ethers(format!("failed to encode `{method}`")))
+            })
+            .collect()
+    }
 }
 
-macro_rules! handle_send {
-    ($call:expr, $method_name:expr) => {
-        $call
-            .send()
-            .await
-            .map_err(|e| map_ethers_error($method_name, e))?
+fn parse_address(address: &str) -> Result<EthAddress> {
+    address
+        .parse()
+        .map_err(|e| ClientError::Ethers(format!("failed to parse address `{address}`: {e}")))
+}
+
+/// Block that reads are made at for the commitment
+pub(super) async fn get_read_block(provider: &Provider, commitment: Commitment) -> Result<BlockId> {
+    let block = match commitment {
+        Commitment::Finalized => BlockNumber::Finalized,
+        Commitment::Confirmations(count) if count > 1 => {
+            let latest = provider.get_block_number().await?;
+            BlockNumber::Number(latest.saturating_sub((count - 1).into()))
+        }
+        _ => BlockNumber::Latest,
+    };
+
+    Ok(block.into())
+}
+
+/// Number of confirmations that transactions are awaited for
+pub(super) fn get_confirmations(commitment: Commitment) -> usize {
+    match commitment {
+        Commitment::Confirmations(count) => count.max(1) as usize,
+        _ => 1,
+    }
+}
+
+/// Waits until the block of the mined transaction is finalized, if the commitment requires it,
+/// fails with a timeout error if it's not finalized in `FINALITY_TIMEOUT`
+pub(super) async fn wait_for_finality(
+    provider: &Provider,
+    receipt: &TransactionReceipt,
+    commitment: Commitment,
+) -> Result<()> {
+    let Some(block) = receipt
+        .block_number
+        .filter(|_| commitment == Commitment::Finalized)
+    else {
+        return Ok(());
+    };
+
+    let deadline = Instant::now() + FINALITY_TIMEOUT;
+
+    loop {
+        let finalized = provider
+            .get_block(BlockNumber::Finalized)
             .await?
-            .map_or_else(
-                || {
-                    Err(ClientError::Ethers(format!(
-                        "`{}` failed: no receipt",
-                        $method_name
-                    )))
-                },
-                |receipt| {
-                    Ok(Tx {
-                        hash: format!("{:?}", receipt.transaction_hash),
-                    })
-                },
-            )
+            .and_then(|block| block.number);
+
+        if finalized.map_or(false, |finalized| finalized >= block) {
+            return Ok(());
+        }
+
+        if Instant::now() > deadline {
+            return Err(ClientError::TimeoutError(format!(
+                "block {block} is not finalized in {}s",
+                FINALITY_TIMEOUT.as_secs()
+            )));
+        }
+
+        sleep(provider.get_interval()).await;
+    }
+}
+
+/// Builds the transaction receipt, the block is fetched for its timestamp
+pub(super) async fn build_tx(
+    provider: &Provider,
+    receipt: TransactionReceipt,
+    events: Vec<EventName>,
+) -> Result<Tx> {
+    let timestamp = match receipt.block_hash {
+        Some(hash) => provider
+            .get_block(hash)
+            .await?
+            .map_or(0, |block| block.timestamp.low_u64()),
+        None => 0,
     };
+
+    let gas_used = receipt.gas_used.unwrap_or_default();
+    let fee = gas_used * receipt.effective_gas_price.unwrap_or_default();
+
+    Ok(Tx {
+        hash: format!("{:?}", receipt.transaction_hash),
+        block: receipt.block_number.map_or(0, |block| block.as_u64()),
+        timestamp,
+        fee: fee.into(),
+        gas_used: gas_used.low_u64(),
+        success: receipt.status == Some(1.into()),
+        events,
+    })
+}
+
+macro_rules! handle_send {
+    ($self:expr, $call:expr, $method_name:expr) => {{
+        let receipt = send_transaction(
+            &$self.client,
+            $call.tx,
+            &$self.fees,
+            get_confirmations($self.commitment),
+            $method_name,
+        )
+        .await?;
+
+        $self.get_tx(receipt).await
+    }};
 }
 
+// Reads at the client commitment, or at the block set in the call
 macro_rules! handle_call {
+    ($self:expr, $call:expr, $method_name:expr) => {
+        handle_call!(
+            $call.block(get_read_block(&$self.provider, $self.commitment).await?),
+            $method_name
+        )
+    };
     ($call:expr, $method_name:expr) => {
         $call
             .call()
@@ -145,6 +331,56 @@ macro_rules! handle_call {
     };
 }
 
+impl HapiCoreEvm {
+    /// Get reporter state at the given block
+    pub async fn get_reporter_at_block(&self, id: &str, block: u64) -> Result<Reporter> {
+        let id = id.parse::<Uuid>()?.as_u128();
+
+        handle_call!(self.contract.get_reporter(id).block(block), "get_reporter")
+            .map(|c| c.try_into())?
+    }
+
+    /// Get case state at the given block
+    pub async fn get_case_at_block(&self, id: &str, block: u64) -> Result<Case> {
+        let id = id.parse::<Uuid>()?.as_u128();
+
+        handle_call!(self.contract.get_case(id).block(block), "get_case").map(|c| c.try_into())?
+    }
+
+    /// Get address state at the given block
+    pub async fn get_address_at_block(&self, address: &str, block: u64) -> Result<Address> {
+        let address = address.parse().map_err(|e| {
+            ClientError::Ethers(format!("failed to parse address `{}`: {}", address, e))
+        })?;
+
+        handle_call!(
+            self.contract.get_address(address).block(block),
+            "get_address"
+        )
+        .map(|c| c.try_into())?
+    }
+
+    /// Get asset state at the given block
+    pub async fn get_asset_at_block(
+        &self,
+        address: &str,
+        id: &AssetId,
+        block: u64,
+    ) -> Result<Asset> {
+        let address = address.parse().map_err(|e| {
+            ClientError::Ethers(format!("failed to parse address `{}`: {}", address, e))
+        })?;
+
+        handle_call!(
+            self.contract
+                .get_asset(address, id.clone().into())
+                .block(block),
+            "get_asset"
+        )
+        .map(|c| c.try_into())?
+    }
+}
+
 #[async_trait]
 impl HapiCore for HapiCoreEvm {
     fn is_valid_address(&self, address: &str) -> Result<()> {
@@ -155,16 +391,27 @@ impl HapiCore for HapiCoreEvm {
         Ok(())
     }
 
+    fn with_commitment(&self, commitment: Commitment) -> Box<dyn HapiCore> {
+        Box::new(Self {
+            commitment,
+            ..self.clone()
+        })
+    }
+
     async fn set_authority(&self, address: &str) -> Result<Tx> {
         let authority: EthAddress = address
             .parse()
             .map_err(|e| ClientError::EthAddressParse(format!("`address`: {e}")))?;
 
-        handle_send!(self.contract.set_authority(authority), "set_authority")
+        handle_send!(
+            self,
+            self.contract.set_authority(authority),
+            "set_authority"
+        )
     }
 
     async fn get_authority(&self) -> Result<String> {
-        handle_call!(self.contract.authority(), "authority").map(|a| format!("{a:?}"))
+        handle_call!(self, self.contract.authority(), "authority").map(|a| format!("{a:?}"))
     }
 
     async fn update_stake_configuration(&self, configuration: StakeConfiguration) -> Result<Tx> {
@@ -174,6 +421,7 @@ impl HapiCore for HapiCoreEvm {
             .map_err(|e| ClientError::EthAddressParse(format!("`token`: {e}")))?;
 
         handle_send!(
+            self,
             self.contract.update_stake_configuration(
                 token,
                 configuration.unlock_duration.into(),
@@ -187,7 +435,12 @@ impl HapiCore for HapiCoreEvm {
     }
 
     async fn get_stake_configuration(&self) -> Result<StakeConfiguration> {
-        handle_call!(self.contract.stake_configuration(), "stake_configuration").map(|c| c.into())
+        handle_call!(
+            self,
+            self.contract.stake_configuration(),
+            "stake_configuration"
+        )
+        .map(|c| c.into())
     }
 
     async fn update_reward_configuration(&self, configuration: RewardConfiguration) -> Result<Tx> {
@@ -197,6 +450,7 @@ impl HapiCore for HapiCoreEvm {
             .map_err(|e| ClientError::EthAddressParse(format!("`token`: {e}")))?;
 
         handle_send!(
+            self,
             self.contract.update_reward_configuration(
                 token,
                 configuration.address_confirmation_reward.into(),
@@ -209,7 +463,12 @@ impl HapiCore for HapiCoreEvm {
     }
 
     async fn get_reward_configuration(&self) -> Result<RewardConfiguration> {
-        handle_call!(self.contract.reward_configuration(), "reward_configuration").map(|c| c.into())
+        handle_call!(
+            self,
+            self.contract.reward_configuration(),
+            "reward_configuration"
+        )
+        .map(|c| c.into())
     }
 
     async fn create_reporter(&self, input: CreateReporterInput) -> Result<Tx> {
@@ -219,6 +478,7 @@ impl HapiCore for HapiCoreEvm {
             .map_err(|e| ClientError::EthAddressParse(format!("`addr`: {e}")))?;
 
         handle_send!(
+            self,
             self.contract.create_reporter(
                 input.id.as_u128(),
                 addr,
@@ -237,6 +497,7 @@ impl HapiCore for HapiCoreEvm {
             .map_err(|e| ClientError::EthAddressParse(format!("`addr`: {e}")))?;
 
         handle_send!(
+            self,
             self.contract.update_reporter(
                 input.id.as_u128(),
                 addr,
@@ -251,15 +512,21 @@ impl HapiCore for HapiCoreEvm {
     async fn get_reporter(&self, id: &str) -> Result<Reporter> {
         let id = id.parse::<Uuid>()?.as_u128();
 
-        handle_call!(self.contract.get_reporter(id), "get_reporter").map(|c| c.try_into())?
+        handle_call!(self, self.contract.get_reporter(id), "get_reporter").map(|c| c.try_into())?
     }
 
     async fn get_reporter_count(&self) -> Result<u64> {
-        handle_call!(self.contract.get_reporter_count(), "get_reporter_count").map(|c| c.as_u64())
+        handle_call!(
+            self,
+            self.contract.get_reporter_count(),
+            "get_reporter_count"
+        )
+        .map(|c| c.as_u64())
     }
 
     async fn get_reporters(&self, skip: u64, take: u64) -> Result<Vec<Reporter>> {
         handle_call!(
+            self,
             self.contract.get_reporters(skip.into(), take.into()),
             "get_reporters"
         )
@@ -267,39 +534,40 @@ impl HapiCore for HapiCoreEvm {
     }
 
     async fn activate_reporter(&self) -> Result<Tx> {
-        handle_send!(self.contract.activate_reporter(), "activate_reporter")
+        handle_send!(self, self.contract.activate_reporter(), "activate_reporter")
     }
 
     async fn deactivate_reporter(&self) -> Result<Tx> {
-        handle_send!(self.contract.deactivate_reporter(), "deactivate_reporter")
+        handle_send!(
+            self,
+            self.contract.deactivate_reporter(),
+            "deactivate_reporter"
+        )
     }
 
     async fn unstake_reporter(&self) -> Result<Tx> {
-        handle_send!(self.contract.unstake(), "unstake")
+        handle_send!(self, self.contract.unstake(), "unstake")
     }
 
     async fn create_case(&self, input: CreateCaseInput) -> Result<Tx> {
         handle_send!(
-            self.contract
-                .create_case(input.id.as_u128(), input.name, input.url),
+            self,
+            self.get_operation_call(BatchOperation::CreateCase(input))?,
             "create_case"
         )
     }
 
     async fn update_case(&self, input: UpdateCaseInput) -> Result<Tx> {
         handle_send!(
-            self.contract.update_case(
-                input.id.as_u128(),
-                input.name,
-                input.url,
-                input.status as u8,
-            ),
+            self,
+            self.get_operation_call(BatchOperation::UpdateCase(input))?,
             "update_case"
         )
     }
 
     async fn get_case(&self, id: &str) -> Result<Case> {
         handle_call!(
+            self,
             self.contract.get_case(id.parse::<Uuid>()?.as_u128()),
             "get_case"
         )
@@ -307,11 +575,12 @@ impl HapiCore for HapiCoreEvm {
     }
 
     async fn get_case_count(&self) -> Result<u64> {
-        handle_call!(self.contract.get_case_count(), "get_case_count").map(|c| c.as_u64())
+        handle_call!(self, self.contract.get_case_count(), "get_case_count").map(|c| c.as_u64())
     }
 
     async fn get_cases(&self, skip: u64, take: u64) -> Result<Vec<Case>> {
         handle_call!(
+            self,
             self.contract.get_cases(skip.into(), take.into()),
             "get_cases"
         )
@@ -319,33 +588,17 @@ impl HapiCore for HapiCoreEvm {
     }
 
     async fn create_address(&self, input: CreateAddressInput) -> Result<Tx> {
-        let case_id = input.case_id.as_u128();
-        let address = input.address.parse().map_err(|e| {
-            ClientError::Ethers(format!(
-                "failed to parse address `{}`: {}",
-                input.address, e
-            ))
-        })?;
-
         handle_send!(
-            self.contract
-                .create_address(address, case_id, input.risk, input.category as u8),
+            self,
+            self.get_operation_call(BatchOperation::CreateAddress(input))?,
             "create_address"
         )
     }
 
     async fn update_address(&self, input: UpdateAddressInput) -> Result<Tx> {
-        let case_id = input.case_id.as_u128();
-        let address = input.address.parse().map_err(|e| {
-            ClientError::Ethers(format!(
-                "failed to parse address `{}`: {}",
-                input.address, e
-            ))
-        })?;
-
         handle_send!(
-            self.contract
-                .update_address(address, input.risk, input.category as u8, case_id),
+            self,
+            self.get_operation_call(BatchOperation::UpdateAddress(input))?,
             "update_address"
         )
     }
@@ -358,7 +611,11 @@ impl HapiCore for HapiCoreEvm {
             ))
         })?;
 
-        handle_send!(self.contract.confirm_address(address), "confirm_address")
+        handle_send!(
+            self,
+            self.contract.confirm_address(address),
+            "confirm_address"
+        )
     }
 
     async fn get_address(&self, address: &str) -> Result<Address> {
@@ -366,15 +623,18 @@ impl HapiCore for HapiCoreEvm {
             ClientError::Ethers(format!("failed to parse address `{}`: {}", address, e))
         })?;
 
-        handle_call!(self.contract.get_address(address), "get_address").map(|c| c.try_into())?
+        handle_call!(self, self.contract.get_address(address), "get_address")
+            .map(|c| c.try_into())?
     }
 
     async fn get_address_count(&self) -> Result<u64> {
-        handle_call!(self.contract.get_address_count(), "get_address_count").map(|c| c.as_u64())
+        handle_call!(self, self.contract.get_address_count(), "get_address_count")
+            .map(|c| c.as_u64())
     }
 
     async fn get_addresses(&self, skip: u64, take: u64) -> Result<Vec<Address>> {
         handle_call!(
+            self,
             self.contract.get_addresses(skip.into(), take.into()),
             "get_addresses"
         )
@@ -382,41 +642,17 @@ impl HapiCore for HapiCoreEvm {
     }
 
     async fn create_asset(&self, input: CreateAssetInput) -> Result<Tx> {
-        let address = input.address.parse().map_err(|e| {
-            ClientError::Ethers(format!(
-                "failed to parse address `{}`: {}",
-                input.address, e
-            ))
-        })?;
-
         handle_send!(
-            self.contract.create_asset(
-                address,
-                input.asset_id.into(),
-                input.case_id.as_u128(),
-                input.risk,
-                input.category as u8,
-            ),
+            self,
+            self.get_operation_call(BatchOperation::CreateAsset(input))?,
             "create_asset"
         )
     }
 
     async fn update_asset(&self, input: UpdateAssetInput) -> Result<Tx> {
-        let address = input.address.parse().map_err(|e| {
-            ClientError::Ethers(format!(
-                "failed to parse address `{}`: {}",
-                input.address, e
-            ))
-        })?;
-
         handle_send!(
-            self.contract.update_asset(
-                address,
-                input.asset_id.into(),
-                input.risk,
-                input.category as u8,
-                input.case_id.as_u128(),
-            ),
+            self,
+            self.get_operation_call(BatchOperation::UpdateAsset(input))?,
             "update_asset"
         )
     }
@@ -430,6 +666,7 @@ impl HapiCore for HapiCoreEvm {
         })?;
 
         handle_send!(
+            self,
             self.contract.confirm_asset(address, input.asset_id.into(),),
             "confirm_asset"
         )
@@ -441,6 +678,7 @@ impl HapiCore for HapiCoreEvm {
         })?;
 
         handle_call!(
+            self,
             self.contract.get_asset(address, id.clone().into()),
             "get_asset"
         )
@@ -448,14 +686,109 @@ impl HapiCore for HapiCoreEvm {
     }
 
     async fn get_asset_count(&self) -> Result<u64> {
-        handle_call!(self.contract.get_asset_count(), "get_asset_count").map(|c| c.as_u64())
+        handle_call!(self, self.contract.get_asset_count(), "get_asset_count").map(|c| c.as_u64())
     }
 
     async fn get_assets(&self, skip: u64, take: u64) -> Result<Vec<Asset>> {
         handle_call!(
+            self,
             self.contract.get_assets(skip.into(), take.into()),
             "get_assets"
         )
         .map(|c| c.into_iter().map(|r| r.try_into()).collect())?
     }
+
+    async fn send_batch(&self, batch: Batch) -> Result<BatchResult> {
+        let mut result = BatchResult::new(batch.len());
+
+        if batch.is_empty() {
+            return Ok(result);
+        }
+
+        let count = batch.len();
+        let calls = self.get_batch_calls(batch)?;
+
+        // Multicall reverts all operations if one of them fails
+        let tx = async { handle_send!(self, self.contract.multicall(calls), "multicall") }.await;
+
+        match tx {
+            Ok(tx) => result.push(tx, 0..count),
+            Err(e) => result.fail(e),
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{
+        entities::{category::Category, network::HapiCoreNetwork},
+        transport::TransportOptions,
+    };
+
+    use super::*;
+
+    fn get_client() -> HapiCoreEvm {
+        HapiCoreEvm::new(HapiCoreOptions {
+            provider_urls: vec!["http://127.0.0.1:8545".to_string()],
+            contract_address: "0x5FbDB2315678afecb367f032d93F642f64180aa3".to_string(),
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network: HapiCoreNetwork::Ethereum,
+            transport: TransportOptions::default(),
+            commitment: Commitment::default(),
+            fees: FeeOptions::default(),
+            nonce_account: None,
+        })
+        .expect("Failed to create client")
+    }
+
+    #[test]
+    fn test_get_batch_calls() {
+        let client = get_client();
+        let case_id = Uuid::new_v4();
+
+        let batch = Batch::new()
+            .create_case(CreateCaseInput {
+                id: case_id,
+                name: "case".to_string(),
+                url: "https://hapi.one".to_string(),
+            })
+            .create_address(CreateAddressInput {
+                address: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
+                case_id,
+                risk: 5,
+                category: Category::Scam,
+            });
+
+        let calls = client.get_batch_calls(batch).unwrap();
+
+        assert_eq!(calls.len(), 2);
+
+        let selector = |name: &str| {
+            client
+                .contract
+                .abi()
+                .function(name)
+                .expect("Unknown function")
+                .short_signature()
+        };
+
+        assert_eq!(calls[0][..4], selector("createCase"));
+        assert_eq!(calls[1][..4], selector("createAddress"));
+    }
+
+    #[test]
+    fn test_get_batch_calls_invalid_address() {
+        let batch = Batch::new().create_address(CreateAddressInput {
+            address: "invalid".to_string(),
+            case_id: Uuid::new_v4(),
+            risk: 5,
+            category: Category::Scam,
+        });
+
+        assert!(get_client().get_batch_calls(batch).is_err());
+    }
 }
@@ -1,25 +1,110 @@
+use ethers::utils::id;
 use ethers_contract::ContractError;
 
-use crate::client::result::ClientError;
+use crate::client::{contract_error::HapiContractError, result::ClientError};
+
+// Custom errors of the HAPI Core contract, including the inherited OpenZeppelin ones
+const CONTRACT_ERRORS: &[(&str, HapiContractError)] = &[
+    (
+        "AddressAlreadyConfirmed(address,uint128)",
+        HapiContractError::AlreadyConfirmed,
+    ),
+    (
+        "AddressNotFound(address)",
+        HapiContractError::AddressNotFound,
+    ),
+    (
+        "AssetAlreadyConfirmed(address,uint256,uint128)",
+        HapiContractError::AlreadyConfirmed,
+    ),
+    (
+        "AssetNotFound(address,uint256)",
+        HapiContractError::AssetNotFound,
+    ),
+    (
+        "CannotConfirmOwnAddress(address,uint128)",
+        HapiContractError::CannotConfirmOwnReport,
+    ),
+    (
+        "CannotConfirmOwnAsset(address,uint256,uint128)",
+        HapiContractError::CannotConfirmOwnReport,
+    ),
+    ("CaseNotFound(uint128)", HapiContractError::CaseNotFound),
+    (
+        "ContractNotConfigured()",
+        HapiContractError::ConfigurationNotSet,
+    ),
+    (
+        "DuplicateAddress(address)",
+        HapiContractError::AddressExists,
+    ),
+    (
+        "DuplicateAsset(address,uint256)",
+        HapiContractError::AssetExists,
+    ),
+    ("DuplicateId(uint128)", HapiContractError::DuplicateId),
+    (
+        "InsufficientTokensOrAllowance()",
+        HapiContractError::InsufficientTokens,
+    ),
+    (
+        "InvalidCaseStatus(uint128,uint8)",
+        HapiContractError::CaseClosed,
+    ),
+    (
+        "InvalidReporter(address)",
+        HapiContractError::InvalidReporter,
+    ),
+    (
+        "InvalidReporterStatus(uint128,uint8)",
+        HapiContractError::InvalidReporterStatus,
+    ),
+    (
+        "InvalidRoleStakeConfiguration()",
+        HapiContractError::InvalidData,
+    ),
+    (
+        "MustBeCaseReporterOrAuthority()",
+        HapiContractError::Unauthorized,
+    ),
+    (
+        "ReporterLocked(uint128,uint256)",
+        HapiContractError::ReporterLocked,
+    ),
+    (
+        "ReporterNotFound(uint128)",
+        HapiContractError::ReporterNotFound,
+    ),
+    ("RiskOutOfRange(uint8)", HapiContractError::RiskOutOfRange),
+    (
+        "AccessControlUnauthorizedAccount(address,bytes32)",
+        HapiContractError::Unauthorized,
+    ),
+    (
+        "OwnableUnauthorizedAccount(address)",
+        HapiContractError::Unauthorized,
+    ),
+];
 
 pub(super) fn map_ethers_error<M: ethers_providers::Middleware>(
     caller: &str,
     e: ContractError<M>,
 ) -> ClientError {
     match e {
-        ContractError::Revert(e) => match e {
-            _ if e.is_empty() => {
+        ContractError::Revert(e) => match decode_revert(&e) {
+            Some(error) => error.into(),
+            None if e.is_empty() => {
                 ClientError::Ethers(format!("`{caller}` reverted with empty message"))
             }
             // TODO: get rid of black magic parsing
-            _ if e.len() > 64 => ClientError::Ethers(format!(
+            None if e.len() > 64 => ClientError::Ethers(format!(
                 "`{caller}` reverted with: {}",
                 String::from_utf8_lossy(&e[64..])
                     .chars()
                     .filter(|c| !c.is_control())
                     .collect::<String>()
             )),
-            e => ClientError::Ethers(format!(
+            None => ClientError::Ethers(format!(
                 "`{caller}` reverted with: {}",
                 String::from_utf8_lossy(&e)
                     .chars()
@@ -30,3 +115,45 @@ pub(super) fn map_ethers_error<M: ethers_providers::Middleware>(
         _ => ClientError::Ethers(format!("`{caller}` failed: {e}")),
     }
 }
+
+/// Decodes the custom error of the revert data, none for unknown errors and revert strings
+fn decode_revert(data: &[u8]) -> Option<HapiContractError> {
+    let selector: [u8; 4] = data.get(..4)?.try_into().ok()?;
+
+    CONTRACT_ERRORS
+        .iter()
+        .find(|(signature, _)| id(signature) == selector)
+        .map(|(_, error)| error.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::abi::{encode, Token};
+
+    use super::*;
+
+    #[test]
+    fn test_decode_revert() {
+        let data = [
+            id("RiskOutOfRange(uint8)").to_vec(),
+            encode(&[Token::Uint(11.into())]),
+        ]
+        .concat();
+
+        assert_eq!(
+            decode_revert(&data),
+            Some(HapiContractError::RiskOutOfRange)
+        );
+
+        let data = [
+            id("Error(string)").to_vec(),
+            encode(&[Token::String("Paused".to_string())]),
+        ]
+        .concat();
+
+        assert_eq!(decode_revert(&data), None);
+
+        assert_eq!(decode_revert(&[0xde, 0xad, 0xbe, 0xef]), None);
+        assert_eq!(decode_revert(&[]), None);
+    }
+}
@@ -0,0 +1,423 @@
+use async_trait::async_trait;
+use ethers::{
+    middleware::{NonceManagerMiddleware, SignerMiddleware},
+    providers::{
+        JsonRpcClient, Middleware, MiddlewareError, PendingTransaction, Provider as EthersProvider,
+    },
+    signers::LocalWallet,
+    types::{
+        transaction::eip2718::TypedTransaction, BlockId, BlockNumber, TransactionReceipt, TxHash,
+        U256,
+    },
+};
+use ethers_contract::ContractError;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::{sleep, timeout, Instant};
+
+use crate::client::{
+    fees::{FeeOptions, FeeStrategy},
+    result::{ClientError, Result},
+};
+
+use super::{client::Provider, error::map_ethers_error};
+
+pub(super) type Signer =
+    NonceManagerMiddleware<SignerMiddleware<FeeMiddleware<Provider>, LocalWallet>>;
+
+// Fee increase of a replacement transaction, nodes require at least 10%
+const REPLACEMENT_FEE_BUMP_PERCENT: u64 = 20;
+const MIN_REPLACEMENT_FEE_BUMP_PERCENT: u64 = 10;
+
+// Multipliers are applied with a precision of 1/1000
+const MULTIPLIER_PRECISION: f64 = 1000.0;
+
+// Time to wait for a transaction or one of its replacements to be mined
+const TRANSACTION_TIMEOUT: Duration = Duration::from_secs(300);
+
+// Node errors of a transaction sent with a nonce out of sync with the network
+const NONCE_ERRORS: [&str; 2] = ["nonce too low", "nonce too high"];
+
+#[derive(Error, Debug)]
+pub enum FeeMiddlewareError<M: Middleware> {
+    #[error("{0}")]
+    MiddlewareError(M::Error),
+}
+
+impl<M: Middleware> MiddlewareError for FeeMiddlewareError<M> {
+    type Inner = M::Error;
+
+    fn from_err(e: Self::Inner) -> Self {
+        Self::MiddlewareError(e)
+    }
+
+    fn as_inner(&self) -> Option<&Self::Inner> {
+        match self {
+            Self::MiddlewareError(e) => Some(e),
+        }
+    }
+}
+
+/// Middleware that fills the gas limit and fees of transactions according to the fee options
+#[derive(Debug, Clone)]
+pub struct FeeMiddleware<M> {
+    inner: M,
+    options: FeeOptions,
+}
+
+impl<M: Middleware> FeeMiddleware<M> {
+    pub fn new(inner: M, options: FeeOptions) -> Self {
+        Self { inner, options }
+    }
+
+    fn fee(&self, estimate: U256, cap: Option<u128>) -> U256 {
+        let fee = multiply(estimate, self.options.fee_multiplier);
+
+        cap.map_or(fee, |cap| fee.min(cap.into()))
+    }
+}
+
+#[async_trait]
+impl<M: Middleware> Middleware for FeeMiddleware<M> {
+    type Error = FeeMiddlewareError<M>;
+    type Provider = M::Provider;
+    type Inner = M;
+
+    fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    async fn fill_transaction(
+        &self,
+        tx: &mut TypedTransaction,
+        block: Option<BlockId>,
+    ) -> std::result::Result<(), Self::Error> {
+        if let (FeeStrategy::Legacy, TypedTransaction::Eip1559(inner)) =
+            (self.options.strategy, &*tx)
+        {
+            *tx = TypedTransaction::Legacy(inner.clone().into());
+        }
+
+        match tx {
+            TypedTransaction::Eip1559(inner) => {
+                if inner.max_fee_per_gas.is_none() || inner.max_priority_fee_per_gas.is_none() {
+                    let (max_fee, priority_fee) = self
+                        .inner
+                        .estimate_eip1559_fees(None)
+                        .await
+                        .map_err(FeeMiddlewareError::MiddlewareError)?;
+
+                    let max_fee = self.fee(max_fee, self.options.max_fee_per_gas);
+                    let priority_fee = self
+                        .fee(priority_fee, self.options.max_priority_fee_per_gas)
+                        .min(max_fee);
+
+                    inner.max_fee_per_gas.get_or_insert(max_fee);
+                    inner.max_priority_fee_per_gas.get_or_insert(priority_fee);
+                }
+            }
+            _ => {
+                if tx.gas_price().is_none() {
+                    let gas_price = self
+                        .inner
+                        .get_gas_price()
+                        .await
+                        .map_err(FeeMiddlewareError::MiddlewareError)?;
+
+                    tx.set_gas_price(self.fee(gas_price, self.options.max_fee_per_gas));
+                }
+            }
+        }
+
+        if tx.gas().is_none() {
+            let gas = self
+                .inner
+                .estimate_gas(tx, block)
+                .await
+                .map_err(FeeMiddlewareError::MiddlewareError)?;
+
+            tx.set_gas(multiply(gas, self.options.gas_multiplier));
+        }
+
+        self.inner
+            .fill_transaction(tx, block)
+            .await
+            .map_err(FeeMiddlewareError::MiddlewareError)
+    }
+}
+
+fn multiply(value: U256, multiplier: f64) -> U256 {
+    let multiplier = (multiplier * MULTIPLIER_PRECISION).round() as u64;
+
+    value.saturating_mul(multiplier.into()) / U256::from(MULTIPLIER_PRECISION as u64)
+}
+
+/// Signer that fills fees and gas with the options and takes nonces from a local counter
+pub(super) fn build_signer(provider: Provider, wallet: LocalWallet, options: FeeOptions) -> Signer {
+    let address = ethers::signers::Signer::address(&wallet);
+
+    NonceManagerMiddleware::new(
+        SignerMiddleware::new(FeeMiddleware::new(provider, options), wallet),
+        address,
+    )
+}
+
+/// Sends the transaction and waits for the confirmations of its receipt
+///
+/// If the transaction is pending longer than the replacement timeout, it's replaced with a
+/// transaction with the same nonce and bumped fees, until the fees reach their caps.
+/// Fails with a timeout error if none of the transactions is mined in `TRANSACTION_TIMEOUT`.
+pub(super) async fn send_transaction(
+    client: &Signer,
+    mut tx: TypedTransaction,
+    options: &FeeOptions,
+    confirmations: usize,
+    caller: &str,
+) -> Result<TransactionReceipt> {
+    let signer = client.inner();
+    let pending_block = Some(BlockNumber::Pending.into());
+
+    // Gas is estimated before a nonce is taken, so that a reverting call leaves no nonce gap
+    if let Some(from) = client.default_sender() {
+        tx.set_from(from);
+    }
+
+    signer
+        .inner()
+        .fill_transaction(&mut tx, pending_block)
+        .await
+        .map_err(|e| map_signer_error(caller, MiddlewareError::from_err(e)))?;
+
+    client
+        .fill_transaction(&mut tx, pending_block)
+        .await
+        .map_err(|e| map_ethers_error(caller, ContractError::<Signer>::from_middleware_error(e)))?;
+
+    // Sent through the signer, as the nonce manager resends failed transactions with a new nonce
+    let mut hashes = match signer.send_transaction(tx.clone(), None).await {
+        Ok(pending) => vec![pending.tx_hash()],
+        Err(e) => {
+            // The counter is out of sync with the network, e.g. after transactions sent by
+            // another client. Other errors keep it, as the nonce may still be taken by the node
+            if is_nonce_error(&e.to_string()) {
+                let _ = client.initialize_nonce(pending_block).await;
+            }
+
+            return Err(map_signer_error(caller, e));
+        }
+    };
+
+    let provider = client.provider();
+    let deadline = Instant::now() + TRANSACTION_TIMEOUT;
+
+    let hash = loop {
+        let Some(replacement_timeout) = options.replacement_timeout else {
+            break find_receipt(provider, &hashes, deadline).await?;
+        };
+
+        if let Ok(hash) = timeout(
+            replacement_timeout,
+            find_receipt(provider, &hashes, deadline),
+        )
+        .await
+        {
+            break hash?;
+        }
+
+        if !bump_fees(&mut tx, options) {
+            break find_receipt(provider, &hashes, deadline).await?;
+        }
+
+        // Sending fails if one of the previous transactions has been mined meanwhile
+        if let Ok(pending) = signer.send_transaction(tx.clone(), None).await {
+            hashes.push(pending.tx_hash());
+        }
+    };
+
+    PendingTransaction::new(hash, provider)
+        .confirmations(confirmations)
+        .await?
+        .ok_or_else(|| ClientError::Ethers(format!("`{caller}` failed: no receipt")))
+}
+
+fn map_signer_error(
+    caller: &str,
+    e: <SignerMiddleware<FeeMiddleware<Provider>, LocalWallet> as Middleware>::Error,
+) -> ClientError {
+    map_ethers_error(
+        caller,
+        ContractError::<Signer>::from_middleware_error(MiddlewareError::from_err(e)),
+    )
+}
+
+fn is_nonce_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+
+    NONCE_ERRORS.iter().any(|error| message.contains(error))
+}
+
+/// Waits until one of the transactions with the same nonce is mined or the deadline is reached
+async fn find_receipt<P: JsonRpcClient>(
+    provider: &EthersProvider<P>,
+    hashes: &[TxHash],
+    deadline: Instant,
+) -> Result<TxHash> {
+    loop {
+        for hash in hashes {
+            if provider.get_transaction_receipt(*hash).await?.is_some() {
+                return Ok(*hash);
+            }
+        }
+
+        if Instant::now() > deadline {
+            return Err(ClientError::TimeoutError(format!(
+                "transaction is not mined in {}s",
+                TRANSACTION_TIMEOUT.as_secs()
+            )));
+        }
+
+        sleep(provider.get_interval()).await;
+    }
+}
+
+/// Raises the fees of the transaction to replace it, returns false if the caps don't allow it
+fn bump_fees(tx: &mut TypedTransaction, options: &FeeOptions) -> bool {
+    let bump = |fee: U256, cap: Option<u128>| {
+        let bumped = fee * (100 + REPLACEMENT_FEE_BUMP_PERCENT) / 100;
+        let bumped = cap.map_or(bumped, |cap| bumped.min(cap.into()));
+
+        (bumped >= fee * (100 + MIN_REPLACEMENT_FEE_BUMP_PERCENT) / 100).then_some(bumped)
+    };
+
+    match tx {
+        TypedTransaction::Eip1559(inner) => {
+            let (Some(max_fee), Some(priority_fee)) =
+                (inner.max_fee_per_gas, inner.max_priority_fee_per_gas)
+            else {
+                return false;
+            };
+
+            let (Some(max_fee), Some(priority_fee)) = (
+                bump(max_fee, options.max_fee_per_gas),
+                bump(priority_fee, options.max_priority_fee_per_gas),
+            ) else {
+                return false;
+            };
+
+            inner.max_fee_per_gas = Some(max_fee);
+            inner.max_priority_fee_per_gas = Some(priority_fee.min(max_fee));
+        }
+        _ => {
+            let Some(gas_price) = tx
+                .gas_price()
+                .and_then(|gas_price| bump(gas_price, options.max_fee_per_gas))
+            else {
+                return false;
+            };
+
+            tx.set_gas_price(gas_price);
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{Eip1559TransactionRequest, TransactionRequest};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_find_replacement_receipt() {
+        let (provider, mock) = EthersProvider::mocked();
+        let provider = provider.interval(Duration::from_millis(10));
+        let hashes = [TxHash::random(), TxHash::random()];
+
+        // Responses are returned in the reverse order: the original transaction is not mined,
+        // its replacement is
+        mock.push(TransactionReceipt::default()).unwrap();
+        mock.push(Option::<TransactionReceipt>::None).unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(1);
+
+        assert_eq!(
+            find_receipt(&provider, &hashes, deadline).await.unwrap(),
+            hashes[1]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_receipt_timeout() {
+        let (provider, mock) = EthersProvider::mocked();
+        let provider = provider.interval(Duration::from_millis(10));
+
+        for _ in 0..100 {
+            mock.push(Option::<TransactionReceipt>::None).unwrap();
+        }
+
+        let deadline = Instant::now() + Duration::from_millis(50);
+
+        assert!(matches!(
+            find_receipt(&provider, &[TxHash::random()], deadline).await,
+            Err(ClientError::TimeoutError(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_nonce_error() {
+        assert!(is_nonce_error(
+            "(code: -32000, message: nonce too low, data: None)"
+        ));
+        assert!(is_nonce_error("Nonce too high"));
+        assert!(!is_nonce_error(
+            "insufficient funds for gas * price + value"
+        ));
+        assert!(!is_nonce_error("replacement transaction underpriced"));
+    }
+
+    #[test]
+    fn test_multiply() {
+        assert_eq!(multiply(1000.into(), 1.0), 1000.into());
+        assert_eq!(multiply(1000.into(), 1.25), 1250.into());
+        assert_eq!(multiply(1000.into(), 0.5), 500.into());
+    }
+
+    #[test]
+    fn test_bump_legacy_fees() {
+        let mut tx: TypedTransaction = TransactionRequest::new().gas_price(100u64).into();
+
+        assert!(bump_fees(&mut tx, &FeeOptions::default()));
+        assert_eq!(tx.gas_price(), Some(120.into()));
+
+        let options = FeeOptions {
+            max_fee_per_gas: Some(130),
+            ..Default::default()
+        };
+
+        assert!(!bump_fees(&mut tx, &options));
+        assert_eq!(tx.gas_price(), Some(120.into()));
+    }
+
+    #[test]
+    fn test_bump_eip1559_fees() {
+        let mut tx: TypedTransaction = Eip1559TransactionRequest::new()
+            .max_fee_per_gas(1000u64)
+            .max_priority_fee_per_gas(100u64)
+            .into();
+
+        let options = FeeOptions {
+            max_fee_per_gas: Some(1150),
+            ..Default::default()
+        };
+
+        assert!(bump_fees(&mut tx, &options));
+
+        let TypedTransaction::Eip1559(inner) = tx else {
+            panic!("Unexpected transaction type");
+        };
+
+        assert_eq!(inner.max_fee_per_gas, Some(1150.into()));
+        assert_eq!(inner.max_priority_fee_per_gas, Some(120.into()));
+    }
+}
@@ -1,7 +1,11 @@
 mod client;
 mod conversion;
 mod error;
+mod fees;
 pub mod token;
+mod transport;
 
 pub use client::{HapiCoreEvm, LogHeader};
+pub use fees::{FeeMiddleware, FeeMiddlewareError};
 pub use token::TokenContractEvm;
+pub use transport::FailoverHttp;
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use ethers::{
     prelude::abigen,
+    providers::Middleware,
     signers::{LocalWallet, Signer as EthersSigner},
     types::Address as EthAddress,
 };
@@ -8,6 +9,8 @@ use std::{str::FromStr, sync::Arc};
 
 use crate::{
     client::{
+        commitment::Commitment,
+        fees::FeeOptions,
         interface::HapiCoreOptions,
         result::{ClientError, Result, Tx},
         token::TokenContract,
@@ -16,8 +19,10 @@ use crate::{
 };
 
 use super::{
-    client::{Provider, Signer},
+    client::{build_tx, get_confirmations, get_read_block, wait_for_finality},
     error::map_ethers_error,
+    fees::{build_signer, send_transaction, Signer},
+    transport::connect,
 };
 
 use super::client::LOCAL_CHAIN_ID;
@@ -29,18 +34,19 @@ abigen!(
 
 pub struct TokenContractEvm {
     pub contract: TOKEN_CONTRACT<Signer>,
+    commitment: Commitment,
+    fees: FeeOptions,
 }
 
 impl TokenContractEvm {
     pub fn new(options: HapiCoreOptions) -> Result<Self> {
-        let provider = Provider::try_from(options.provider_url.as_str())
-            .map_err(|e| ClientError::UrlParseError(format!("`provider_url`: {e}")))?;
+        let provider = connect(&options)?;
 
         let signer = LocalWallet::from_str(options.private_key.unwrap_or_default().as_str())
             .map_err(|e| ClientError::Ethers(format!("`private_key`: {e}")))?
             .with_chain_id(options.chain_id.unwrap_or(LOCAL_CHAIN_ID));
 
-        let client = Signer::new(provider, signer);
+        let client = build_signer(provider, signer, options.fees.clone());
 
         let client = Arc::new(client);
 
@@ -51,7 +57,11 @@ impl TokenContractEvm {
 
         let contract: TOKEN_CONTRACT<Signer> = TOKEN_CONTRACT::new(token_contract, client);
 
-        Ok(Self { contract })
+        Ok(Self {
+            contract,
+            commitment: options.commitment,
+            fees: options.fees,
+        })
     }
 }
 
@@ -66,24 +76,19 @@ impl TokenContract for TokenContractEvm {
             .parse()
             .map_err(|e| ClientError::EthAddressParse(format!("`to`: {e}")))?;
 
-        self.contract
-            .transfer(to, amount.into())
-            .send()
-            .await
-            .map_err(|e| ClientError::Ethers(format!("`transfer` failed: {e}")))?
-            .await?
-            .map_or_else(
-                || {
-                    Err(ClientError::Ethers(
-                        "`transfer` failed: no receipt".to_string(),
-                    ))
-                },
-                |receipt| {
-                    Ok(Tx {
-                        hash: format!("{:?}", receipt.transaction_hash),
-                    })
-                },
-            )
+        let client = self.contract.client();
+
+        let receipt = send_transaction(
+            &client,
+            self.contract.transfer(to, amount.into()).tx,
+            &self.fees,
+            get_confirmations(self.commitment),
+            "transfer",
+        )
+        .await?;
+
+        wait_for_finality(client.provider(), &receipt, self.commitment).await?;
+        build_tx(client.provider(), receipt, vec![]).await
     }
 
     async fn approve(&self, spender: &str, amount: Amount) -> Result<Tx> {
@@ -91,24 +96,19 @@ impl TokenContract for TokenContractEvm {
             .parse()
             .map_err(|e| ClientError::EthAddressParse(format!("`spender`: {e}")))?;
 
-        self.contract
-            .approve(spender, amount.into())
-            .send()
-            .await
-            .map_err(|e| ClientError::Ethers(format!("`approve` failed: {e}")))?
-            .await?
-            .map_or_else(
-                || {
-                    Err(ClientError::Ethers(
-                        "`approve` failed: no receipt".to_string(),
-                    ))
-                },
-                |receipt| {
-                    Ok(Tx {
-                        hash: format!("{:?}", receipt.transaction_hash),
-                    })
-                },
-            )
+        let client = self.contract.client();
+
+        let receipt = send_transaction(
+            &client,
+            self.contract.approve(spender, amount.into()).tx,
+            &self.fees,
+            get_confirmations(self.commitment),
+            "approve",
+        )
+        .await?;
+
+        wait_for_finality(client.provider(), &receipt, self.commitment).await?;
+        build_tx(client.provider(), receipt, vec![]).await
     }
 
     async fn balance(&self, addr: &str) -> Result<Amount> {
@@ -116,11 +116,22 @@ impl TokenContract for TokenContractEvm {
             .parse()
             .map_err(|e| ClientError::EthAddressParse(format!("`addr`: {e}")))?;
 
+        let block = get_read_block(self.contract.client().provider(), self.commitment).await?;
+
         self.contract
             .balance_of(addr)
+            .block(block)
             .call()
             .await
             .map_err(|e| map_ethers_error("balance", e))
             .map(|a| Amount::from_str(&a.to_string()).unwrap_or_default())
     }
+
+    async fn decimals(&self) -> Result<u8> {
+        self.contract
+            .decimals()
+            .call()
+            .await
+            .map_err(|e| map_ethers_error("decimals", e))
+    }
 }
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use ethers::providers::{Http, HttpClientError, JsonRpcClient, Provider as EthersProvider};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt::Debug, sync::Arc};
+
+use crate::client::{
+    interface::HapiCoreOptions,
+    result::{ClientError, Result},
+    transport::{is_transient_status, Endpoints},
+};
+
+// Server busy codes of the public JSON-RPC providers
+const RATE_LIMIT_RPC_CODES: [i64; 2] = [429, -32005];
+
+/// HTTP transport over multiple endpoints with retries and failover
+#[derive(Debug, Clone)]
+pub struct FailoverHttp {
+    endpoints: Arc<Endpoints<Http>>,
+}
+
+impl FailoverHttp {
+    pub fn new(options: &HapiCoreOptions) -> Result<Self> {
+        let clients = options
+            .provider_urls
+            .iter()
+            .map(|url| {
+                url.parse::<Http>()
+                    .map_err(|e| ClientError::UrlParseError(format!("`provider-url`: {e}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            endpoints: Arc::new(Endpoints::new(clients, options.transport.clone())?),
+        })
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for FailoverHttp {
+    type Error = HttpClientError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> std::result::Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        // Params are serialized once to be resent on retries
+        let params = serde_json::to_value(params).map_err(|err| HttpClientError::SerdeJson {
+            err,
+            text: format!("failed to serialize `{method}` params"),
+        })?;
+
+        self.endpoints
+            .request(
+                |client| {
+                    let params = params.clone();
+                    async move { client.request(method, params).await }
+                },
+                is_transient,
+            )
+            .await
+    }
+}
+
+fn is_transient(e: &HttpClientError) -> bool {
+    match e {
+        HttpClientError::ReqwestError(e) => {
+            e.is_timeout()
+                || e.is_connect()
+                || e.status()
+                    .map_or(false, |status| is_transient_status(status.as_u16()))
+        }
+        HttpClientError::JsonRpcError(e) => RATE_LIMIT_RPC_CODES.contains(&e.code),
+        // Rate limit and gateway errors come as plain text or HTML pages instead of JSON
+        HttpClientError::SerdeJson { text, .. } => !text.trim_start().starts_with('{'),
+    }
+}
+
+pub(super) fn connect(options: &HapiCoreOptions) -> Result<EthersProvider<FailoverHttp>> {
+    Ok(EthersProvider::new(FailoverHttp::new(options)?))
+}
@@ -0,0 +1,696 @@
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use async_trait::async_trait;
+use ethers::types::Address as EthAddress;
+use near_primitives::types::AccountId;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::{
+    client::{
+        amount::Amount,
+        batch::{Batch, BatchResult},
+        commitment::Commitment,
+        configuration::{RewardConfiguration, StakeConfiguration},
+        entities::{
+            address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
+            asset::{Asset, AssetId, ConfirmAssetInput, CreateAssetInput, UpdateAssetInput},
+            case::{Case, CreateCaseInput, UpdateCaseInput},
+            reporter::{CreateReporterInput, Reporter, UpdateReporterInput},
+        },
+        events::EventName,
+        result::{ClientError, Result, Tx},
+        token::TokenContract,
+    },
+    HapiCore,
+};
+
+use super::state::{MockFixture, MockState};
+
+/// In-memory HAPI Core backend that enforces the rules of the contracts
+///
+/// Clients made with [`HapiCoreMock::with_account`] share the state, so a test can act as the
+/// authority and several reporters at once. The same client serves as the token contract,
+/// of the configured stake token unless another one is set with [`HapiCoreMock::with_token`].
+///
+/// A batch is sent in one transaction and fails as a whole, like on EVM and NEAR. Solana packs
+/// a large batch into several transactions, and a failed one leaves the previous ones applied,
+/// which can be reproduced with [`HapiCoreMock::with_batch_limit`].
+#[derive(Clone)]
+pub struct HapiCoreMock {
+    state: Arc<Mutex<MockState>>,
+    account: String,
+    token: Option<String>,
+    batch_limit: Option<usize>,
+}
+
+impl HapiCoreMock {
+    /// Empty contract owned by the authority, with the authority as the caller
+    pub fn new(authority: &str) -> Self {
+        let fixture = MockFixture {
+            authority: authority.to_string(),
+            ..Default::default()
+        };
+
+        Self::from_fixture(fixture, authority)
+    }
+
+    /// Contract seeded with the fixture, with the given account as the caller
+    pub fn from_fixture(fixture: MockFixture, account: &str) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(fixture.into())),
+            account: account.to_string(),
+            token: None,
+            batch_limit: None,
+        }
+    }
+
+    /// Client of the same contract that sends transactions as another account
+    pub fn with_account(&self, account: &str) -> Self {
+        Self {
+            account: account.to_string(),
+            ..self.clone()
+        }
+    }
+
+    /// Client that serves as the token contract of the given token
+    pub fn with_token(&self, token: &str) -> Self {
+        Self {
+            token: Some(token.to_string()),
+            ..self.clone()
+        }
+    }
+
+    /// Client that sends at most the given number of batch operations in one transaction
+    pub fn with_batch_limit(&self, operations: usize) -> Self {
+        Self {
+            batch_limit: Some(operations.max(1)),
+            ..self.clone()
+        }
+    }
+
+    /// Account that sends the transactions
+    pub fn account(&self) -> &str {
+        &self.account
+    }
+
+    /// Snapshot of the current state, can be used as a fixture of another mock
+    pub fn fixture(&self) -> MockFixture {
+        self.state().fixture.clone()
+    }
+
+    /// Credits the account with tokens of the client
+    pub fn mint(&self, account: &str, amount: Amount) -> Result<()> {
+        let mut state = self.state();
+        let token = self.token(&state)?;
+
+        state.mint(&token, account, amount)
+    }
+
+    /// Moves the clock of the contract forward
+    pub fn advance_time(&self, seconds: u64) {
+        self.state().fixture.timestamp += seconds;
+    }
+
+    pub fn set_timestamp(&self, timestamp: u64) {
+        self.state().fixture.timestamp = timestamp;
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn token(&self, state: &MockState) -> Result<String> {
+        match &self.token {
+            Some(token) => Ok(token.clone()),
+            None => Ok(state.get_stake_configuration()?.token),
+        }
+    }
+
+    /// Runs the method as a transaction, the state is left intact if it fails
+    fn execute<F>(&self, method: F) -> Result<Tx>
+    where
+        F: FnOnce(&mut MockState, &str) -> Result<Vec<EventName>>,
+    {
+        let mut state = self.state();
+        let mut next = state.clone();
+
+        let events = method(&mut next, &self.account)?;
+
+        next.block += 1;
+        *state = next;
+
+        Ok(Tx {
+            hash: format!("0x{:064x}", state.block),
+            block: state.block,
+            timestamp: state.fixture.timestamp,
+            success: true,
+            events,
+            ..Default::default()
+        })
+    }
+}
+
+// Pagination of entities in the order they were created
+fn page<T: Clone>(items: &[T], skip: u64, take: u64) -> Vec<T> {
+    items
+        .iter()
+        .skip(skip as usize)
+        .take(take as usize)
+        .cloned()
+        .collect()
+}
+
+#[async_trait]
+impl HapiCore for HapiCoreMock {
+    // The mock stands in for any backend, so addresses of all of them are accepted
+    fn is_valid_address(&self, address: &str) -> Result<()> {
+        if address.parse::<EthAddress>().is_ok()
+            || address.parse::<Pubkey>().is_ok()
+            || AccountId::try_from(address.to_string()).is_ok()
+        {
+            return Ok(());
+        }
+
+        Err(ClientError::InvalidData(format!(
+            "{address} is not an EVM, Solana or NEAR address"
+        )))
+    }
+
+    fn with_commitment(&self, _commitment: Commitment) -> Box<dyn HapiCore> {
+        Box::new(self.clone())
+    }
+
+    async fn set_authority(&self, address: &str) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.set_authority(caller, address)?]))
+    }
+
+    async fn get_authority(&self) -> Result<String> {
+        Ok(self.state().fixture.authority.clone())
+    }
+
+    async fn update_stake_configuration(&self, configuration: StakeConfiguration) -> Result<Tx> {
+        self.execute(|state, caller| {
+            Ok(vec![
+                state.update_stake_configuration(caller, configuration)?
+            ])
+        })
+    }
+
+    async fn get_stake_configuration(&self) -> Result<StakeConfiguration> {
+        self.state().get_stake_configuration()
+    }
+
+    async fn update_reward_configuration(&self, configuration: RewardConfiguration) -> Result<Tx> {
+        self.execute(|state, caller| {
+            Ok(vec![
+                state.update_reward_configuration(caller, configuration)?
+            ])
+        })
+    }
+
+    async fn get_reward_configuration(&self) -> Result<RewardConfiguration> {
+        self.state().get_reward_configuration()
+    }
+
+    async fn create_reporter(&self, input: CreateReporterInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.create_reporter(caller, input)?]))
+    }
+
+    async fn update_reporter(&self, input: UpdateReporterInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.update_reporter(caller, input)?]))
+    }
+
+    async fn get_reporter(&self, id: &str) -> Result<Reporter> {
+        self.state().get_reporter(&id.parse()?)
+    }
+
+    async fn get_reporter_count(&self) -> Result<u64> {
+        Ok(self.state().fixture.reporters.len() as u64)
+    }
+
+    async fn get_reporters(&self, skip: u64, take: u64) -> Result<Vec<Reporter>> {
+        Ok(page(&self.state().fixture.reporters, skip, take))
+    }
+
+    async fn activate_reporter(&self) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.activate_reporter(caller)?]))
+    }
+
+    async fn deactivate_reporter(&self) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.deactivate_reporter(caller)?]))
+    }
+
+    async fn unstake_reporter(&self) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.unstake_reporter(caller)?]))
+    }
+
+    async fn create_case(&self, input: CreateCaseInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.create_case(caller, input)?]))
+    }
+
+    async fn update_case(&self, input: UpdateCaseInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.update_case(caller, input)?]))
+    }
+
+    async fn get_case(&self, id: &str) -> Result<Case> {
+        self.state().get_case(&id.parse()?)
+    }
+
+    async fn get_case_count(&self) -> Result<u64> {
+        Ok(self.state().fixture.cases.len() as u64)
+    }
+
+    async fn get_cases(&self, skip: u64, take: u64) -> Result<Vec<Case>> {
+        Ok(page(&self.state().fixture.cases, skip, take))
+    }
+
+    async fn create_address(&self, input: CreateAddressInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.create_address(caller, input)?]))
+    }
+
+    async fn update_address(&self, input: UpdateAddressInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.update_address(caller, input)?]))
+    }
+
+    async fn confirm_address(&self, input: ConfirmAddressInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.confirm_address(caller, input)?]))
+    }
+
+    async fn get_address(&self, addr: &str) -> Result<Address> {
+        self.state().get_address(addr)
+    }
+
+    async fn get_address_count(&self) -> Result<u64> {
+        Ok(self.state().fixture.addresses.len() as u64)
+    }
+
+    async fn get_addresses(&self, skip: u64, take: u64) -> Result<Vec<Address>> {
+        Ok(page(&self.state().fixture.addresses, skip, take))
+    }
+
+    async fn create_asset(&self, input: CreateAssetInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.create_asset(caller, input)?]))
+    }
+
+    async fn update_asset(&self, input: UpdateAssetInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.update_asset(caller, input)?]))
+    }
+
+    async fn confirm_asset(&self, input: ConfirmAssetInput) -> Result<Tx> {
+        self.execute(|state, caller| Ok(vec![state.confirm_asset(caller, input)?]))
+    }
+
+    async fn get_asset(&self, addr: &str, id: &AssetId) -> Result<Asset> {
+        self.state().get_asset(addr, id)
+    }
+
+    async fn get_asset_count(&self) -> Result<u64> {
+        Ok(self.state().fixture.assets.len() as u64)
+    }
+
+    async fn get_assets(&self, skip: u64, take: u64) -> Result<Vec<Asset>> {
+        Ok(page(&self.state().fixture.assets, skip, take))
+    }
+
+    async fn send_batch(&self, batch: Batch) -> Result<BatchResult> {
+        let operation_count = batch.len();
+        let mut result = BatchResult::new(operation_count);
+
+        // Without a limit all operations land in one transaction, like on EVM and NEAR
+        let limit = self.batch_limit.unwrap_or(operation_count);
+        let mut operations = batch.into_operations().into_iter();
+        let mut start = 0;
+
+        while start < operation_count {
+            let end = operation_count.min(start + limit);
+            let chunk: Vec<_> = operations.by_ref().take(end - start).collect();
+
+            let tx = self.execute(|state, caller| {
+                chunk
+                    .into_iter()
+                    .map(|operation| state.apply(caller, operation))
+                    .collect()
+            });
+
+            match tx {
+                Ok(tx) => result.push(tx, start..end),
+                Err(e) => {
+                    result.fail(e);
+                    break;
+                }
+            }
+
+            start = end;
+        }
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl TokenContract for HapiCoreMock {
+    fn is_approve_needed(&self) -> bool {
+        false
+    }
+
+    async fn transfer(&self, to: &str, amount: Amount) -> Result<Tx> {
+        self.execute(|state, caller| {
+            let token = self.token(state)?;
+            state.transfer(&token, caller, to, amount)?;
+            Ok(vec![])
+        })
+    }
+
+    async fn approve(&self, _spender: &str, _amount: Amount) -> Result<Tx> {
+        self.execute(|_, _| Ok(vec![]))
+    }
+
+    async fn balance(&self, addr: &str) -> Result<Amount> {
+        let state = self.state();
+
+        Ok(state.balance(&self.token(&state)?, addr))
+    }
+
+    async fn decimals(&self) -> Result<u8> {
+        Ok(self.state().fixture.decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        client::entities::{
+            case::CaseStatus,
+            category::Category,
+            reporter::{ReporterRole, ReporterStatus},
+        },
+        HapiContractError,
+    };
+
+    const AUTHORITY: &str = "authority";
+    const PUBLISHER: &str = "publisher";
+
+    async fn setup() -> (HapiCoreMock, HapiCoreMock, Uuid) {
+        let authority = HapiCoreMock::new(AUTHORITY);
+        let publisher = authority.with_account(PUBLISHER);
+
+        authority
+            .update_stake_configuration(StakeConfiguration {
+                token: "token".to_string(),
+                unlock_duration: 60,
+                validator_stake: 10u64.into(),
+                tracer_stake: 20u64.into(),
+                publisher_stake: 30u64.into(),
+                authority_stake: 40u64.into(),
+            })
+            .await
+            .unwrap();
+
+        authority
+            .create_reporter(CreateReporterInput {
+                id: Uuid::new_v4(),
+                account: PUBLISHER.to_string(),
+                role: ReporterRole::Publisher,
+                name: "publisher".to_string(),
+                url: "https://publisher.blockchain".to_string(),
+            })
+            .await
+            .unwrap();
+
+        authority.mint(PUBLISHER, 100u64.into()).unwrap();
+        publisher.activate_reporter().await.unwrap();
+
+        let case_id = Uuid::new_v4();
+        publisher
+            .create_case(CreateCaseInput {
+                id: case_id,
+                name: "case".to_string(),
+                url: "https://case.blockchain".to_string(),
+            })
+            .await
+            .unwrap();
+
+        (authority, publisher, case_id)
+    }
+
+    fn contract_error(e: ClientError) -> HapiContractError {
+        match e {
+            ClientError::Contract(e) => e,
+            e => panic!("unexpected error: {e}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reporter_lifecycle() {
+        let (authority, publisher, _) = setup().await;
+
+        assert_eq!(
+            publisher.balance(PUBLISHER).await.unwrap(),
+            Amount::from(70u64)
+        );
+
+        let e = authority.activate_reporter().await.unwrap_err();
+        assert_eq!(contract_error(e), HapiContractError::InvalidReporter);
+
+        publisher.deactivate_reporter().await.unwrap();
+
+        let e = publisher.unstake_reporter().await.unwrap_err();
+        assert_eq!(contract_error(e), HapiContractError::ReporterLocked);
+
+        publisher.advance_time(60);
+        publisher.unstake_reporter().await.unwrap();
+
+        assert_eq!(
+            publisher.balance(PUBLISHER).await.unwrap(),
+            Amount::from(100u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_address_rules() {
+        let (authority, publisher, case_id) = setup().await;
+
+        let input = CreateAddressInput {
+            address: "0x1".to_string(),
+            case_id,
+            risk: 11,
+            category: Category::Scam,
+        };
+
+        let e = publisher.create_address(input.clone()).await.unwrap_err();
+        assert_eq!(contract_error(e), HapiContractError::RiskOutOfRange);
+
+        let e = authority
+            .create_address(CreateAddressInput {
+                risk: 5,
+                ..input.clone()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(contract_error(e), HapiContractError::InvalidReporter);
+
+        publisher
+            .create_address(CreateAddressInput { risk: 5, ..input })
+            .await
+            .unwrap();
+
+        let e = publisher
+            .confirm_address(ConfirmAddressInput {
+                address: "0x1".to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(contract_error(e), HapiContractError::CannotConfirmOwnReport);
+    }
+
+    #[tokio::test]
+    async fn test_batch_is_atomic() {
+        let (_, publisher, case_id) = setup().await;
+
+        let address = CreateAddressInput {
+            address: "0x1".to_string(),
+            case_id,
+            risk: 5,
+            category: Category::Scam,
+        };
+
+        let batch = Batch::new()
+            .create_address(address.clone())
+            .create_address(address);
+
+        let result = publisher.send_batch(batch).await.unwrap();
+
+        assert!(result.txs.is_empty());
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Contract error: Address already exists")
+        );
+        assert_eq!(publisher.get_address_count().await.unwrap(), 0);
+
+        let fixture: MockFixture =
+            serde_json::from_value(serde_json::to_value(publisher.fixture()).unwrap()).unwrap();
+        let restored = HapiCoreMock::from_fixture(fixture, PUBLISHER);
+
+        assert_eq!(restored.get_case_count().await.unwrap(), 1);
+        assert_eq!(
+            restored.balance(PUBLISHER).await.unwrap(),
+            Amount::from(70u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stake_token() {
+        let (authority, publisher, _) = setup().await;
+
+        let validator_id = Uuid::new_v4();
+        authority
+            .create_reporter(CreateReporterInput {
+                id: validator_id,
+                account: "validator".to_string(),
+                role: ReporterRole::Validator,
+                name: "validator".to_string(),
+                url: "https://validator.blockchain".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let validator = authority.with_account("validator");
+        validator
+            .with_token("other")
+            .mint("validator", 100u64.into())
+            .unwrap();
+
+        let e = validator.activate_reporter().await.unwrap_err();
+        assert_eq!(contract_error(e), HapiContractError::InsufficientTokens);
+
+        assert_eq!(
+            publisher.balance("hapi-core").await.unwrap(),
+            Amount::from(30u64)
+        );
+        assert_eq!(
+            publisher
+                .with_token("other")
+                .balance("validator")
+                .await
+                .unwrap(),
+            Amount::from(100u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_without_configuration() {
+        let reporter = Reporter {
+            id: Uuid::new_v4(),
+            account: PUBLISHER.to_string(),
+            role: ReporterRole::Publisher,
+            status: ReporterStatus::Active,
+            name: "publisher".to_string(),
+            url: "https://publisher.blockchain".to_string(),
+            stake: 30u64.into(),
+            unlock_timestamp: 0,
+        };
+        let publisher = HapiCoreMock::from_fixture(
+            MockFixture {
+                authority: AUTHORITY.to_string(),
+                reporters: vec![reporter],
+                ..Default::default()
+            },
+            PUBLISHER,
+        );
+
+        let e = publisher.deactivate_reporter().await.unwrap_err();
+        assert_eq!(contract_error(e), HapiContractError::ConfigurationNotSet);
+    }
+
+    #[tokio::test]
+    async fn test_update_in_closed_case() {
+        let (_, publisher, case_id) = setup().await;
+
+        publisher
+            .create_address(CreateAddressInput {
+                address: "0x1".to_string(),
+                case_id,
+                risk: 5,
+                category: Category::Scam,
+            })
+            .await
+            .unwrap();
+
+        let closed_case_id = Uuid::new_v4();
+        publisher
+            .create_case(CreateCaseInput {
+                id: closed_case_id,
+                name: "closed case".to_string(),
+                url: "https://closed.blockchain".to_string(),
+            })
+            .await
+            .unwrap();
+        publisher
+            .update_case(UpdateCaseInput {
+                id: closed_case_id,
+                name: "closed case".to_string(),
+                url: "https://closed.blockchain".to_string(),
+                status: CaseStatus::Closed,
+            })
+            .await
+            .unwrap();
+
+        let e = publisher
+            .update_address(UpdateAddressInput {
+                address: "0x1".to_string(),
+                case_id: closed_case_id,
+                risk: 6,
+                category: Category::Scam,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(contract_error(e), HapiContractError::CaseClosed);
+    }
+
+    #[test]
+    fn test_is_valid_address() {
+        let mock = HapiCoreMock::new(AUTHORITY);
+
+        assert!(mock
+            .is_valid_address("0x5FbDB2315678afecb367f032d93F642f64180aa3")
+            .is_ok());
+        assert!(mock
+            .is_valid_address("11111111111111111111111111111111")
+            .is_ok());
+        assert!(mock.is_valid_address("hapi.testnet").is_ok());
+
+        assert!(mock.is_valid_address("").is_err());
+        assert!(mock.is_valid_address("Not An Address").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_batch_limit() {
+        let (_, publisher, case_id) = setup().await;
+
+        let address = CreateAddressInput {
+            address: "0x1".to_string(),
+            case_id,
+            risk: 5,
+            category: Category::Scam,
+        };
+
+        let batch = Batch::new()
+            .create_address(address.clone())
+            .create_address(address);
+
+        let result = publisher
+            .with_batch_limit(1)
+            .send_batch(batch)
+            .await
+            .unwrap();
+
+        assert_eq!(result.txs.len(), 1);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Contract error: Address already exists")
+        );
+        assert_eq!(publisher.get_address_count().await.unwrap(), 1);
+    }
+}
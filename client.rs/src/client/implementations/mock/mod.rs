@@ -0,0 +1,5 @@
+mod client;
+mod state;
+
+pub use client::HapiCoreMock;
+pub use state::MockFixture;
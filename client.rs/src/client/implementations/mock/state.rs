@@ -0,0 +1,628 @@
+use ethers::types::U256;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use uuid::Uuid;
+
+use crate::client::{
+    amount::Amount,
+    batch::BatchOperation,
+    configuration::{RewardConfiguration, StakeConfiguration},
+    contract_error::HapiContractError,
+    entities::{
+        address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
+        asset::{Asset, AssetId, ConfirmAssetInput, CreateAssetInput, UpdateAssetInput},
+        case::{Case, CaseStatus, CreateCaseInput, UpdateCaseInput},
+        reporter::{
+            CreateReporterInput, Reporter, ReporterRole, ReporterStatus, UpdateReporterInput,
+        },
+    },
+    events::EventName,
+    result::{ClientError, Result},
+};
+
+// Account that holds the stakes of active reporters
+const CONTRACT_ACCOUNT: &str = "hapi-core";
+
+// Highest risk score accepted by the contracts
+const MAX_RISK: u8 = 10;
+
+/// Initial state of the mock, can be deserialized from JSON fixtures
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MockFixture {
+    pub authority: String,
+    pub stake_configuration: Option<StakeConfiguration>,
+    pub reward_configuration: Option<RewardConfiguration>,
+    pub reporters: Vec<Reporter>,
+    pub cases: Vec<Case>,
+    pub addresses: Vec<Address>,
+    pub assets: Vec<Asset>,
+    /// Balances by token and account, stakes are paid in the configured stake token
+    pub balances: BTreeMap<String, BTreeMap<String, Amount>>,
+    pub decimals: u8,
+    /// Current time in seconds
+    pub timestamp: u64,
+}
+
+/// Contract state with the rules of the HAPI Core contracts
+#[derive(Clone, Debug)]
+pub(super) struct MockState {
+    pub fixture: MockFixture,
+    pub address_confirmations: HashSet<(String, Uuid)>,
+    pub asset_confirmations: HashSet<(String, String, Uuid)>,
+    pub block: u64,
+}
+
+impl From<MockFixture> for MockState {
+    fn from(fixture: MockFixture) -> Self {
+        Self {
+            fixture,
+            address_confirmations: HashSet::new(),
+            asset_confirmations: HashSet::new(),
+            block: 0,
+        }
+    }
+}
+
+impl MockState {
+    pub fn set_authority(&mut self, caller: &str, address: &str) -> Result<EventName> {
+        self.check_authority(caller)?;
+        self.fixture.authority = address.to_string();
+
+        Ok(EventName::SetAuthority)
+    }
+
+    pub fn update_stake_configuration(
+        &mut self,
+        caller: &str,
+        configuration: StakeConfiguration,
+    ) -> Result<EventName> {
+        self.check_authority(caller)?;
+        self.fixture.stake_configuration = Some(configuration);
+
+        Ok(EventName::UpdateStakeConfiguration)
+    }
+
+    pub fn get_stake_configuration(&self) -> Result<StakeConfiguration> {
+        self.fixture
+            .stake_configuration
+            .clone()
+            .ok_or_else(|| HapiContractError::ConfigurationNotSet.into())
+    }
+
+    pub fn update_reward_configuration(
+        &mut self,
+        caller: &str,
+        configuration: RewardConfiguration,
+    ) -> Result<EventName> {
+        self.check_authority(caller)?;
+        self.fixture.reward_configuration = Some(configuration);
+
+        Ok(EventName::UpdateRewardConfiguration)
+    }
+
+    pub fn get_reward_configuration(&self) -> Result<RewardConfiguration> {
+        self.fixture
+            .reward_configuration
+            .clone()
+            .ok_or_else(|| HapiContractError::ConfigurationNotSet.into())
+    }
+
+    pub fn create_reporter(
+        &mut self,
+        caller: &str,
+        input: CreateReporterInput,
+    ) -> Result<EventName> {
+        self.check_authority(caller)?;
+
+        if self.find_reporter(&input.id).is_some() {
+            return Err(HapiContractError::ReporterExists.into());
+        }
+
+        self.fixture.reporters.push(Reporter {
+            id: input.id,
+            account: input.account,
+            role: input.role,
+            status: ReporterStatus::Inactive,
+            name: input.name,
+            url: input.url,
+            stake: Amount::default(),
+            unlock_timestamp: 0,
+        });
+
+        Ok(EventName::CreateReporter)
+    }
+
+    pub fn update_reporter(
+        &mut self,
+        caller: &str,
+        input: UpdateReporterInput,
+    ) -> Result<EventName> {
+        self.check_authority(caller)?;
+
+        let reporter = self
+            .find_reporter_mut(&input.id)
+            .ok_or(HapiContractError::ReporterNotFound)?;
+
+        reporter.account = input.account;
+        reporter.role = input.role;
+        reporter.name = input.name;
+        reporter.url = input.url;
+
+        Ok(EventName::UpdateReporter)
+    }
+
+    pub fn get_reporter(&self, id: &Uuid) -> Result<Reporter> {
+        self.find_reporter(id)
+            .cloned()
+            .ok_or_else(|| HapiContractError::ReporterNotFound.into())
+    }
+
+    pub fn activate_reporter(&mut self, caller: &str) -> Result<EventName> {
+        let reporter = self.get_caller_reporter(caller)?;
+
+        if reporter.status != ReporterStatus::Inactive {
+            return Err(HapiContractError::InvalidReporterStatus.into());
+        }
+
+        let configuration = self.get_stake_configuration()?;
+        let stake = match reporter.role {
+            ReporterRole::Validator => configuration.validator_stake,
+            ReporterRole::Tracer => configuration.tracer_stake,
+            ReporterRole::Publisher => configuration.publisher_stake,
+            ReporterRole::Authority => configuration.authority_stake,
+        };
+
+        if stake == Amount::default() {
+            return Err(HapiContractError::ConfigurationNotSet.into());
+        }
+
+        self.transfer(
+            &configuration.token,
+            caller,
+            CONTRACT_ACCOUNT,
+            stake.clone(),
+        )?;
+
+        let reporter = self.get_caller_reporter_mut(caller)?;
+        reporter.status = ReporterStatus::Active;
+        reporter.stake = stake;
+
+        Ok(EventName::ActivateReporter)
+    }
+
+    pub fn deactivate_reporter(&mut self, caller: &str) -> Result<EventName> {
+        let unlock_timestamp =
+            self.fixture.timestamp + self.get_stake_configuration()?.unlock_duration;
+
+        let reporter = self.get_caller_reporter_mut(caller)?;
+
+        if reporter.status != ReporterStatus::Active {
+            return Err(HapiContractError::InvalidReporterStatus.into());
+        }
+
+        reporter.status = ReporterStatus::Unstaking;
+        reporter.unlock_timestamp = unlock_timestamp;
+
+        Ok(EventName::DeactivateReporter)
+    }
+
+    pub fn unstake_reporter(&mut self, caller: &str) -> Result<EventName> {
+        let reporter = self.get_caller_reporter(caller)?;
+
+        if reporter.status != ReporterStatus::Unstaking {
+            return Err(HapiContractError::InvalidReporterStatus.into());
+        }
+
+        if reporter.unlock_timestamp > self.fixture.timestamp {
+            return Err(HapiContractError::ReporterLocked.into());
+        }
+
+        let token = self.get_stake_configuration()?.token;
+        self.transfer(&token, CONTRACT_ACCOUNT, caller, reporter.stake)?;
+
+        let reporter = self.get_caller_reporter_mut(caller)?;
+        reporter.status = ReporterStatus::Inactive;
+        reporter.stake = Amount::default();
+        reporter.unlock_timestamp = 0;
+
+        Ok(EventName::Unstake)
+    }
+
+    pub fn create_case(&mut self, caller: &str, input: CreateCaseInput) -> Result<EventName> {
+        let reporter = self.get_active_reporter(caller)?;
+        check_role(
+            &reporter,
+            &[ReporterRole::Publisher, ReporterRole::Authority],
+        )?;
+
+        if self.find_case(&input.id).is_some() {
+            return Err(HapiContractError::CaseExists.into());
+        }
+
+        self.fixture.cases.push(Case {
+            id: input.id,
+            name: input.name,
+            url: input.url,
+            status: CaseStatus::Open,
+            reporter_id: reporter.id,
+        });
+
+        Ok(EventName::CreateCase)
+    }
+
+    pub fn update_case(&mut self, caller: &str, input: UpdateCaseInput) -> Result<EventName> {
+        let reporter = self.get_active_reporter(caller)?;
+        check_role(
+            &reporter,
+            &[ReporterRole::Publisher, ReporterRole::Authority],
+        )?;
+
+        let case = self
+            .find_case_mut(&input.id)
+            .ok_or(HapiContractError::CaseNotFound)?;
+
+        if case.reporter_id != reporter.id && reporter.role != ReporterRole::Authority {
+            return Err(HapiContractError::Unauthorized.into());
+        }
+
+        case.name = input.name;
+        case.url = input.url;
+        case.status = input.status;
+
+        Ok(EventName::UpdateCase)
+    }
+
+    pub fn get_case(&self, id: &Uuid) -> Result<Case> {
+        self.find_case(id)
+            .cloned()
+            .ok_or_else(|| HapiContractError::CaseNotFound.into())
+    }
+
+    pub fn create_address(&mut self, caller: &str, input: CreateAddressInput) -> Result<EventName> {
+        self.check_open_case(&input.case_id)?;
+
+        if self.find_address(&input.address).is_some() {
+            return Err(HapiContractError::AddressExists.into());
+        }
+
+        check_risk(input.risk)?;
+
+        let reporter = self.get_active_reporter(caller)?;
+        check_role(
+            &reporter,
+            &[
+                ReporterRole::Publisher,
+                ReporterRole::Authority,
+                ReporterRole::Tracer,
+            ],
+        )?;
+
+        self.fixture.addresses.push(Address {
+            address: input.address,
+            case_id: input.case_id,
+            reporter_id: reporter.id,
+            risk: input.risk,
+            category: input.category,
+            confirmations: 0,
+        });
+
+        Ok(EventName::CreateAddress)
+    }
+
+    pub fn update_address(&mut self, caller: &str, input: UpdateAddressInput) -> Result<EventName> {
+        let address = self.get_address(&input.address)?;
+
+        check_risk(input.risk)?;
+        self.check_open_case(&input.case_id)?;
+
+        let reporter = self.get_active_reporter(caller)?;
+        check_report_update(
+            &reporter,
+            address.reporter_id,
+            address.case_id,
+            input.case_id,
+        )?;
+
+        let address = self
+            .find_address_mut(&input.address)
+            .ok_or(HapiContractError::AddressNotFound)?;
+
+        address.case_id = input.case_id;
+        address.risk = input.risk;
+        address.category = input.category;
+
+        Ok(EventName::UpdateAddress)
+    }
+
+    pub fn confirm_address(
+        &mut self,
+        caller: &str,
+        input: ConfirmAddressInput,
+    ) -> Result<EventName> {
+        let address = self.get_address(&input.address)?;
+
+        let reporter = self.get_active_reporter(caller)?;
+        check_role(
+            &reporter,
+            &[ReporterRole::Publisher, ReporterRole::Validator],
+        )?;
+
+        if reporter.id == address.reporter_id {
+            return Err(HapiContractError::CannotConfirmOwnReport.into());
+        }
+
+        if !self
+            .address_confirmations
+            .insert((input.address.clone(), reporter.id))
+        {
+            return Err(HapiContractError::AlreadyConfirmed.into());
+        }
+
+        if let Some(address) = self.find_address_mut(&input.address) {
+            address.confirmations += 1;
+        }
+
+        Ok(EventName::ConfirmAddress)
+    }
+
+    pub fn get_address(&self, address: &str) -> Result<Address> {
+        self.find_address(address)
+            .cloned()
+            .ok_or_else(|| HapiContractError::AddressNotFound.into())
+    }
+
+    pub fn create_asset(&mut self, caller: &str, input: CreateAssetInput) -> Result<EventName> {
+        self.check_open_case(&input.case_id)?;
+
+        if self.find_asset(&input.address, &input.asset_id).is_some() {
+            return Err(HapiContractError::AssetExists.into());
+        }
+
+        check_risk(input.risk)?;
+
+        let reporter = self.get_active_reporter(caller)?;
+        check_role(
+            &reporter,
+            &[
+                ReporterRole::Publisher,
+                ReporterRole::Authority,
+                ReporterRole::Tracer,
+            ],
+        )?;
+
+        self.fixture.assets.push(Asset {
+            address: input.address,
+            asset_id: input.asset_id,
+            case_id: input.case_id,
+            reporter_id: reporter.id,
+            risk: input.risk,
+            category: input.category,
+            confirmations: 0,
+        });
+
+        Ok(EventName::CreateAsset)
+    }
+
+    pub fn update_asset(&mut self, caller: &str, input: UpdateAssetInput) -> Result<EventName> {
+        let asset = self.get_asset(&input.address, &input.asset_id)?;
+
+        check_risk(input.risk)?;
+        self.check_open_case(&input.case_id)?;
+
+        let reporter = self.get_active_reporter(caller)?;
+        check_report_update(&reporter, asset.reporter_id, asset.case_id, input.case_id)?;
+
+        let asset = self
+            .find_asset_mut(&input.address, &input.asset_id)
+            .ok_or(HapiContractError::AssetNotFound)?;
+
+        asset.case_id = input.case_id;
+        asset.risk = input.risk;
+        asset.category = input.category;
+
+        Ok(EventName::UpdateAsset)
+    }
+
+    pub fn confirm_asset(&mut self, caller: &str, input: ConfirmAssetInput) -> Result<EventName> {
+        let asset = self.get_asset(&input.address, &input.asset_id)?;
+
+        let reporter = self.get_active_reporter(caller)?;
+        check_role(
+            &reporter,
+            &[ReporterRole::Publisher, ReporterRole::Validator],
+        )?;
+
+        if reporter.id == asset.reporter_id {
+            return Err(HapiContractError::CannotConfirmOwnReport.into());
+        }
+
+        if !self.asset_confirmations.insert((
+            input.address.clone(),
+            input.asset_id.to_string(),
+            reporter.id,
+        )) {
+            return Err(HapiContractError::AlreadyConfirmed.into());
+        }
+
+        if let Some(asset) = self.find_asset_mut(&input.address, &input.asset_id) {
+            asset.confirmations += 1;
+        }
+
+        Ok(EventName::ConfirmAsset)
+    }
+
+    pub fn get_asset(&self, address: &str, id: &AssetId) -> Result<Asset> {
+        self.find_asset(address, id)
+            .cloned()
+            .ok_or_else(|| HapiContractError::AssetNotFound.into())
+    }
+
+    /// Applies the batch operation with the rules of its method
+    pub fn apply(&mut self, caller: &str, operation: BatchOperation) -> Result<EventName> {
+        match operation {
+            BatchOperation::CreateCase(input) => self.create_case(caller, input),
+            BatchOperation::UpdateCase(input) => self.update_case(caller, input),
+            BatchOperation::CreateAddress(input) => self.create_address(caller, input),
+            BatchOperation::UpdateAddress(input) => self.update_address(caller, input),
+            BatchOperation::CreateAsset(input) => self.create_asset(caller, input),
+            BatchOperation::UpdateAsset(input) => self.update_asset(caller, input),
+        }
+    }
+
+    pub fn balance(&self, token: &str, account: &str) -> Amount {
+        self.fixture
+            .balances
+            .get(token)
+            .and_then(|balances| balances.get(account))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn mint(&mut self, token: &str, account: &str, amount: Amount) -> Result<()> {
+        let balance = U256::from(self.balance(token, account))
+            .checked_add(amount.into())
+            .ok_or_else(|| ClientError::InvalidAmount("balance overflow".to_string()))?;
+
+        self.set_balance(token, account, balance.into());
+
+        Ok(())
+    }
+
+    pub fn transfer(&mut self, token: &str, from: &str, to: &str, amount: Amount) -> Result<()> {
+        let balance = U256::from(self.balance(token, from))
+            .checked_sub(amount.clone().into())
+            .ok_or(HapiContractError::InsufficientTokens)?;
+
+        self.set_balance(token, from, balance.into());
+
+        self.mint(token, to, amount)
+    }
+
+    fn set_balance(&mut self, token: &str, account: &str, balance: Amount) {
+        self.fixture
+            .balances
+            .entry(token.to_string())
+            .or_default()
+            .insert(account.to_string(), balance);
+    }
+
+    fn check_authority(&self, caller: &str) -> Result<()> {
+        if caller != self.fixture.authority {
+            return Err(HapiContractError::Unauthorized.into());
+        }
+
+        Ok(())
+    }
+
+    fn check_open_case(&self, id: &Uuid) -> Result<()> {
+        if self.get_case(id)?.status != CaseStatus::Open {
+            return Err(HapiContractError::CaseClosed.into());
+        }
+
+        Ok(())
+    }
+
+    fn get_caller_reporter(&self, caller: &str) -> Result<Reporter> {
+        self.fixture
+            .reporters
+            .iter()
+            .find(|reporter| reporter.account == caller)
+            .cloned()
+            .ok_or_else(|| HapiContractError::InvalidReporter.into())
+    }
+
+    fn get_caller_reporter_mut(&mut self, caller: &str) -> Result<&mut Reporter> {
+        self.fixture
+            .reporters
+            .iter_mut()
+            .find(|reporter| reporter.account == caller)
+            .ok_or_else(|| HapiContractError::InvalidReporter.into())
+    }
+
+    fn get_active_reporter(&self, caller: &str) -> Result<Reporter> {
+        let reporter = self.get_caller_reporter(caller)?;
+
+        if reporter.status != ReporterStatus::Active {
+            return Err(HapiContractError::ReporterInactive.into());
+        }
+
+        Ok(reporter)
+    }
+
+    fn find_reporter(&self, id: &Uuid) -> Option<&Reporter> {
+        self.fixture.reporters.iter().find(|r| r.id == *id)
+    }
+
+    fn find_reporter_mut(&mut self, id: &Uuid) -> Option<&mut Reporter> {
+        self.fixture.reporters.iter_mut().find(|r| r.id == *id)
+    }
+
+    fn find_case(&self, id: &Uuid) -> Option<&Case> {
+        self.fixture.cases.iter().find(|c| c.id == *id)
+    }
+
+    fn find_case_mut(&mut self, id: &Uuid) -> Option<&mut Case> {
+        self.fixture.cases.iter_mut().find(|c| c.id == *id)
+    }
+
+    fn find_address(&self, address: &str) -> Option<&Address> {
+        self.fixture.addresses.iter().find(|a| a.address == address)
+    }
+
+    fn find_address_mut(&mut self, address: &str) -> Option<&mut Address> {
+        self.fixture
+            .addresses
+            .iter_mut()
+            .find(|a| a.address == address)
+    }
+
+    fn find_asset(&self, address: &str, id: &AssetId) -> Option<&Asset> {
+        self.fixture
+            .assets
+            .iter()
+            .find(|a| a.address == address && a.asset_id == *id)
+    }
+
+    fn find_asset_mut(&mut self, address: &str, id: &AssetId) -> Option<&mut Asset> {
+        self.fixture
+            .assets
+            .iter_mut()
+            .find(|a| a.address == address && a.asset_id == *id)
+    }
+}
+
+fn check_role(reporter: &Reporter, roles: &[ReporterRole]) -> Result<()> {
+    if !roles.contains(&reporter.role) {
+        return Err(HapiContractError::Unauthorized.into());
+    }
+
+    Ok(())
+}
+
+fn check_risk(risk: u8) -> Result<()> {
+    if risk > MAX_RISK {
+        return Err(HapiContractError::RiskOutOfRange.into());
+    }
+
+    Ok(())
+}
+
+/// Only the reporter of an address or asset, or the authority, can update it, and tracers
+/// can't move it to another case
+fn check_report_update(
+    reporter: &Reporter,
+    reporter_id: Uuid,
+    case_id: Uuid,
+    new_case_id: Uuid,
+) -> Result<()> {
+    if reporter_id != reporter.id && reporter.role != ReporterRole::Authority {
+        return Err(HapiContractError::Unauthorized.into());
+    }
+
+    if case_id != new_case_id && reporter.role == ReporterRole::Tracer {
+        return Err(HapiContractError::Unauthorized.into());
+    }
+
+    Ok(())
+}
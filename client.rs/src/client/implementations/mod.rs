@@ -1,7 +1,11 @@
 pub mod evm;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod near;
 pub mod solana;
 
 pub use evm::{token::TokenContractEvm, HapiCoreEvm};
+#[cfg(feature = "mock")]
+pub use mock::{HapiCoreMock, MockFixture};
 pub use near::{HapiCoreNear, TokenContractNear};
 pub use solana::{HapiCoreSolana, TokenContractSolana};
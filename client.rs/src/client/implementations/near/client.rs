@@ -1,20 +1,25 @@
 use async_trait::async_trait;
 use near_crypto::{InMemorySigner, SecretKey};
-use near_jsonrpc_client::{
-    methods::{self, broadcast_tx_async::RpcBroadcastTxAsyncRequest, query::RpcQueryRequest},
-    JsonRpcClient,
+use near_jsonrpc_client::methods::{
+    self, broadcast_tx_async::RpcBroadcastTxAsyncRequest, query::RpcQueryRequest,
 };
 use near_jsonrpc_primitives::types::{
     query::{QueryResponseKind, RpcQueryResponse},
     transactions::TransactionInfo,
 };
 use near_primitives::{
+    hash::CryptoHash,
     transaction::{Action, FunctionCallAction, Transaction},
-    types::{AccountId, BlockReference, Finality, FunctionArgs},
-    views::{FinalExecutionStatus, QueryRequest},
+    types::{AccountId, BlockId, BlockReference, Finality, FunctionArgs},
+    views::{
+        ActionView, BlockHeaderView, ExecutionStatusView, FinalExecutionOutcomeView,
+        FinalExecutionStatus, QueryRequest,
+    },
 };
+use near_sdk::json_types::U128;
 use serde::Deserialize;
 use serde_json::{from_slice, json, Value};
+use std::collections::HashSet;
 use tokio::{time, time::Duration};
 use uuid::Uuid;
 
@@ -28,6 +33,8 @@ pub const DELAY_AFTER_TX_EXECUTION: Duration = Duration::from_secs(1);
 
 use crate::{
     client::{
+        batch::{Batch, BatchOperation, BatchResult},
+        commitment::Commitment,
         configuration::{RewardConfiguration, StakeConfiguration},
         entities::{
             address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
@@ -35,22 +42,27 @@ use crate::{
             case::{Case, CreateCaseInput, UpdateCaseInput},
             reporter::{CreateReporterInput, Reporter, ReporterRole, UpdateReporterInput},
         },
-        near::GAS_FOR_TX,
+        events::EventName,
+        near::{GAS_FOR_TX, MAX_GAS_PER_TX},
         result::{ClientError, Result, Tx},
     },
     HapiCore, HapiCoreOptions,
 };
 
+use super::{error::map_execution_error, transport::NearRpcClient};
+
+#[derive(Clone)]
 pub struct HapiCoreNear {
-    pub client: JsonRpcClient,
+    pub client: NearRpcClient,
     pub contract_address: AccountId,
     pub signer: Option<String>,
     pub account_id: Option<String>,
+    commitment: Commitment,
 }
 
 impl HapiCoreNear {
     pub fn new(options: HapiCoreOptions) -> Result<Self> {
-        let client = JsonRpcClient::connect(options.provider_url.as_str());
+        let client = NearRpcClient::new(&options)?;
         let signer = options.private_key;
         let account_id = options.account_id;
 
@@ -59,10 +71,20 @@ impl HapiCoreNear {
             contract_address: options.contract_address.try_into()?,
             signer,
             account_id,
+            commitment: options.commitment,
         })
     }
 }
 
+/// Finality that views are made at for the commitment
+pub(super) fn get_finality(commitment: Commitment) -> Finality {
+    match commitment {
+        Commitment::Processed => Finality::None,
+        Commitment::Confirmed => Finality::DoomSlug,
+        Commitment::Finalized | Commitment::Confirmations(_) => Finality::Final,
+    }
+}
+
 #[macro_export]
 macro_rules! uuid_to_u128 {
     ($id:expr) => {
@@ -70,28 +92,122 @@ macro_rules! uuid_to_u128 {
     };
 }
 
+// Least gas attached to a function call of a batch
+const MIN_GAS_FOR_BATCH_ACTION: u64 = 10_000_000_000_000; // 10 TeraGas
+
+// Function calls of a batch that fit into the gas limit of a transaction
+const MAX_BATCH_ACTIONS: usize = (MAX_GAS_PER_TX / MIN_GAS_FOR_BATCH_ACTION) as usize;
+
 macro_rules! build_tx {
     ($self:expr, $signer:expr, $access_key:expr, $method:expr, $args:expr) => {
+        build_tx!(
+            $self,
+            $signer,
+            $access_key,
+            vec![function_call($method, $args)]
+        )
+    };
+    ($self:expr, $signer:expr, $access_key:expr, $actions:expr) => {
         Transaction {
             signer_id: $signer.account_id.clone(),
             public_key: $signer.public_key.clone(),
             nonce: $self.get_nonce(&$access_key)? + 1,
             receiver_id: $self.contract_address.clone(),
             block_hash: $access_key.block_hash,
-            actions: vec![Action::FunctionCall(Box::new(FunctionCallAction {
-                method_name: $method.to_string(),
-                args: $args.to_string().into_bytes(),
-                gas: GAS_FOR_TX,
-                deposit: 0,
-            }))],
+            actions: $actions,
         }
     };
 }
 
+fn function_call(method: &str, args: impl ToString) -> Action {
+    function_call_with_gas(method, args, GAS_FOR_TX)
+}
+
+fn function_call_with_gas(method: &str, args: impl ToString, gas: u64) -> Action {
+    Action::FunctionCall(Box::new(FunctionCallAction {
+        method_name: method.to_string(),
+        args: args.to_string().into_bytes(),
+        gas,
+        deposit: 0,
+    }))
+}
+
+/// Function calls of the batch operations in the order they are added,
+/// the gas limit of the transaction is split between them
+fn get_batch_actions(batch: Batch) -> Result<Vec<Action>> {
+    if batch.len() > MAX_BATCH_ACTIONS {
+        return Err(ClientError::BatchTooLarge(batch.len(), MAX_BATCH_ACTIONS));
+    }
+
+    let gas = GAS_FOR_TX.min(MAX_GAS_PER_TX / batch.len().max(1) as u64);
+
+    batch
+        .into_operations()
+        .into_iter()
+        .map(|operation| {
+            let (method, args) = get_operation_call(operation)?;
+            Ok(function_call_with_gas(method, args, gas))
+        })
+        .collect()
+}
+
+/// Contract method and arguments of the operation
+fn get_operation_call(operation: BatchOperation) -> Result<(&'static str, Value)> {
+    let method = operation.method();
+
+    let args = match operation {
+        BatchOperation::CreateCase(input) => json!({
+            "id": uuid_to_u128!(input.id),
+            "name": input.name,
+            "url": input.url,
+        }),
+        BatchOperation::UpdateCase(input) => json!({
+            "id": uuid_to_u128!(input.id),
+            "name": input.name,
+            "status": input.status,
+            "url": input.url,
+        }),
+        BatchOperation::CreateAddress(input) => json!({
+            "address": input.address,
+            "category": input.category,
+            "case_id": uuid_to_u128!(input.case_id),
+            "risk_score": input.risk,
+        }),
+        BatchOperation::UpdateAddress(input) => json!({
+            "address": input.address,
+            "category": input.category,
+            "case_id": uuid_to_u128!(input.case_id),
+            "risk_score": input.risk,
+        }),
+        BatchOperation::CreateAsset(input) => json!({
+            "address": input.address,
+            "id": input.asset_id,
+            "category": input.category,
+            "case_id": uuid_to_u128!(input.case_id),
+            "risk_score": input.risk,
+        }),
+        BatchOperation::UpdateAsset(input) => json!({
+            "address": input.address,
+            "id": input.asset_id,
+            "category": input.category,
+            "case_id": uuid_to_u128!(input.case_id),
+            "risk_score": input.risk,
+        }),
+    };
+
+    Ok((method, args))
+}
+
+/// Sends the transaction and waits for its execution
+///
+/// HAPI events are reported for the calls executed by `hapi_contract`. With the finalized
+/// commitment the execution is awaited until all blocks of its receipts are final.
 pub(crate) async fn execute_transaction(
     transaction: Transaction,
     signer: InMemorySigner,
-    client: &JsonRpcClient,
+    client: &NearRpcClient,
+    hapi_contract: Option<&AccountId>,
+    commitment: Commitment,
 ) -> Result<Tx> {
     let request = RpcBroadcastTxAsyncRequest {
         signed_transaction: transaction.sign(&signer),
@@ -99,7 +215,7 @@ pub(crate) async fn execute_transaction(
     let sent_at = time::Instant::now();
     let tx_hash = client.call(request).await?;
 
-    loop {
+    let outcome = loop {
         if time::Instant::now() > sent_at + TRANSACTION_TIMEOUT {
             return Err(ClientError::TimeoutError("Transaction timeout".to_string()));
         }
@@ -124,23 +240,124 @@ pub(crate) async fn execute_transaction(
             Ok(response) => match response.status {
                 FinalExecutionStatus::SuccessValue(_) => {
                     time::sleep(DELAY_AFTER_TX_EXECUTION).await;
-                    break;
+                    break response;
                 }
-                FinalExecutionStatus::Failure(err) => Err(ClientError::InvalidResponse(format!(
-                    "Call method failed with {err}"
-                )))?,
+                FinalExecutionStatus::Failure(err) => Err(map_execution_error(err))?,
                 _ => {
                     continue;
                 }
             },
         }
+    };
+
+    if get_finality(commitment) == Finality::Final {
+        wait_for_finality(client, &outcome, sent_at + TRANSACTION_TIMEOUT).await?;
     }
 
+    let header = get_block_header(client, outcome.transaction_outcome.block_hash).await?;
+
+    let outcomes =
+        std::iter::once(&outcome.transaction_outcome).chain(outcome.receipts_outcome.iter());
+
+    let (gas_used, fee) = outcomes.fold((0u64, 0u128), |(gas, fee), receipt| {
+        (
+            gas + receipt.outcome.gas_burnt,
+            fee + receipt.outcome.tokens_burnt,
+        )
+    });
+
+    let events = hapi_contract
+        .map(|contract| get_hapi_events(&outcome, contract))
+        .unwrap_or_default();
+
     Ok(Tx {
         hash: tx_hash.to_string(),
+        block: header.height,
+        timestamp: header.timestamp_nanosec / 1_000_000_000,
+        fee: U128(fee).into(),
+        gas_used,
+        success: true,
+        events,
     })
 }
 
+async fn get_block_header(client: &NearRpcClient, hash: CryptoHash) -> Result<BlockHeaderView> {
+    Ok(client
+        .call(methods::block::RpcBlockRequest {
+            block_reference: BlockReference::BlockId(BlockId::Hash(hash)),
+        })
+        .await?
+        .header)
+}
+
+async fn wait_for_finality(
+    client: &NearRpcClient,
+    outcome: &FinalExecutionOutcomeView,
+    deadline: time::Instant,
+) -> Result<()> {
+    let mut height = 0;
+
+    for hash in outcome
+        .receipts_outcome
+        .iter()
+        .map(|receipt| receipt.block_hash)
+        .collect::<HashSet<_>>()
+    {
+        height = height.max(get_block_header(client, hash).await?.height);
+    }
+
+    loop {
+        let final_height = client
+            .call(methods::block::RpcBlockRequest {
+                block_reference: BlockReference::Finality(Finality::Final),
+            })
+            .await?
+            .header
+            .height;
+
+        if final_height >= height {
+            return Ok(());
+        }
+
+        if time::Instant::now() > deadline {
+            return Err(ClientError::TimeoutError(
+                "Transaction finality timeout".to_string(),
+            ));
+        }
+
+        time::sleep(PERIOD_CHECK_TX_STATUS).await;
+    }
+}
+
+fn get_hapi_events(outcome: &FinalExecutionOutcomeView, contract: &AccountId) -> Vec<EventName> {
+    let executed = outcome.receipts_outcome.iter().any(|receipt| {
+        &receipt.outcome.executor_id == contract
+            && matches!(
+                receipt.outcome.status,
+                ExecutionStatusView::SuccessValue(_) | ExecutionStatusView::SuccessReceiptId(_)
+            )
+    });
+
+    if !executed {
+        return vec![];
+    }
+
+    if &outcome.transaction.receiver_id == contract {
+        outcome
+            .transaction
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                ActionView::FunctionCall { method_name, .. } => method_name.parse().ok(),
+                _ => None,
+            })
+            .collect()
+    } else {
+        // Reporter activation is executed by `ft_on_transfer` of the token transfer
+        vec![EventName::ActivateReporter]
+    }
+}
+
 #[async_trait]
 impl HapiCore for HapiCoreNear {
     fn is_valid_address(&self, address: &str) -> Result<()> {
@@ -148,6 +365,13 @@ impl HapiCore for HapiCoreNear {
         Ok(())
     }
 
+    fn with_commitment(&self, commitment: Commitment) -> Box<dyn HapiCore> {
+        Box::new(Self {
+            commitment,
+            ..self.clone()
+        })
+    }
+
     async fn set_authority(&self, address: &str) -> Result<Tx> {
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
@@ -162,7 +386,14 @@ impl HapiCore for HapiCoreNear {
             })
         );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn get_authority(&self) -> Result<String> {
@@ -185,7 +416,14 @@ impl HapiCore for HapiCoreNear {
             })
         );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn get_stake_configuration(&self) -> Result<StakeConfiguration> {
@@ -208,7 +446,14 @@ impl HapiCore for HapiCoreNear {
             })
         );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn get_reward_configuration(&self) -> Result<RewardConfiguration> {
@@ -235,7 +480,14 @@ impl HapiCore for HapiCoreNear {
             })
         );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn update_reporter(&self, input: UpdateReporterInput) -> Result<Tx> {
@@ -256,7 +508,14 @@ impl HapiCore for HapiCoreNear {
             })
         );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn get_reporter(&self, id: &str) -> Result<Reporter> {
@@ -332,7 +591,14 @@ impl HapiCore for HapiCoreNear {
             }))],
         };
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn deactivate_reporter(&self) -> Result<Tx> {
@@ -347,7 +613,14 @@ impl HapiCore for HapiCoreNear {
             ""
         );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn unstake_reporter(&self) -> Result<Tx> {
@@ -356,46 +629,50 @@ impl HapiCore for HapiCoreNear {
 
         let transaction = build_tx!(self, signer, access_key_query_response, "unstake", "");
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn create_case(&self, input: CreateCaseInput) -> Result<Tx> {
+        let (method, args) = get_operation_call(BatchOperation::CreateCase(input))?;
+
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
 
-        let transaction = build_tx!(
-            self,
-            signer,
-            access_key_query_response,
-            "create_case",
-            json!({
-                "id": uuid_to_u128!(input.id),
-                "name": input.name,
-                "url": input.url,
-            })
-        );
+        let transaction = build_tx!(self, signer, access_key_query_response, method, args);
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn update_case(&self, input: UpdateCaseInput) -> Result<Tx> {
+        let (method, args) = get_operation_call(BatchOperation::UpdateCase(input))?;
+
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
 
-        let transaction = build_tx!(
-            self,
-            signer,
-            access_key_query_response,
-            "update_case",
-            json!({
-                "id": uuid_to_u128!(input.id),
-                "name": input.name,
-                "status": input.status,
-                "url": input.url,
-            })
-        );
+        let transaction = build_tx!(self, signer, access_key_query_response, method, args);
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn get_case(&self, id: &str) -> Result<Case> {
@@ -422,42 +699,39 @@ impl HapiCore for HapiCoreNear {
     }
 
     async fn create_address(&self, input: CreateAddressInput) -> Result<Tx> {
+        let (method, args) = get_operation_call(BatchOperation::CreateAddress(input))?;
+
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
 
-        let transaction = build_tx!(
-            self,
-            signer,
-            access_key_query_response,
-            "create_address",
-            json!({
-                "address": input.address,
-                "category": input.category,
-                "case_id": uuid_to_u128!(input.case_id),
-                "risk_score": input.risk,
-            })
-        );
+        let transaction = build_tx!(self, signer, access_key_query_response, method, args);
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn update_address(&self, input: UpdateAddressInput) -> Result<Tx> {
+        let (method, args) = get_operation_call(BatchOperation::UpdateAddress(input))?;
+
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
-        let transaction = build_tx!(
-            self,
-            signer,
-            access_key_query_response,
-            "update_address",
-            json!({
-                "address": input.address,
-                "category": input.category,
-                "case_id": uuid_to_u128!(input.case_id),
-                "risk_score": input.risk,
-            })
-        );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        let transaction = build_tx!(self, signer, access_key_query_response, method, args);
+
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn confirm_address(&self, input: ConfirmAddressInput) -> Result<Tx> {
@@ -474,7 +748,14 @@ impl HapiCore for HapiCoreNear {
             })
         );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn get_address(&self, addr: &str) -> Result<Address> {
@@ -505,44 +786,39 @@ impl HapiCore for HapiCoreNear {
     }
 
     async fn create_asset(&self, input: CreateAssetInput) -> Result<Tx> {
+        let (method, args) = get_operation_call(BatchOperation::CreateAsset(input))?;
+
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
 
-        let transaction = build_tx!(
-            self,
-            signer,
-            access_key_query_response,
-            "create_asset",
-            json!({
-                "address": input.address,
-                "id": input.asset_id,
-                "category": input.category,
-                "case_id": uuid_to_u128!(input.case_id),
-                "risk_score": input.risk,
-            })
-        );
+        let transaction = build_tx!(self, signer, access_key_query_response, method, args);
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn update_asset(&self, input: UpdateAssetInput) -> Result<Tx> {
+        let (method, args) = get_operation_call(BatchOperation::UpdateAsset(input))?;
+
         let signer = self.get_signer()?;
         let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
-        let transaction = build_tx!(
-            self,
-            signer,
-            access_key_query_response,
-            "update_asset",
-            json!({
-                "address": input.address,
-                "id": input.asset_id,
-                "category": input.category,
-                "case_id": uuid_to_u128!(input.case_id),
-                "risk_score": input.risk,
-            })
-        );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        let transaction = build_tx!(self, signer, access_key_query_response, method, args);
+
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn confirm_asset(&self, input: ConfirmAssetInput) -> Result<Tx> {
@@ -560,7 +836,14 @@ impl HapiCore for HapiCoreNear {
             })
         );
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(
+            transaction,
+            signer,
+            &self.client,
+            Some(&self.contract_address),
+            self.commitment,
+        )
+        .await?)
     }
 
     async fn get_asset(&self, address: &str, id: &AssetId) -> Result<Asset> {
@@ -585,12 +868,75 @@ impl HapiCore for HapiCoreNear {
             .map(|asset| asset.try_into())
             .collect::<Result<Vec<Asset>>>()?)
     }
+
+    async fn send_batch(&self, batch: Batch) -> Result<BatchResult> {
+        let mut result = BatchResult::new(batch.len());
+
+        if batch.is_empty() {
+            return Ok(result);
+        }
+
+        let actions = get_batch_actions(batch)?;
+        let count = actions.len();
+
+        // Function calls of a transaction are executed atomically in a single receipt
+        let tx = async {
+            let signer = self.get_signer()?;
+            let access_key_query_response: RpcQueryResponse = self.get_access_key(&signer).await?;
+
+            let transaction = build_tx!(self, signer, access_key_query_response, actions);
+
+            execute_transaction(
+                transaction,
+                signer,
+                &self.client,
+                Some(&self.contract_address),
+                self.commitment,
+            )
+            .await
+        }
+        .await;
+
+        match tx {
+            Ok(tx) => result.push(tx, 0..count),
+            Err(e) => result.fail(e),
+        }
+
+        Ok(result)
+    }
 }
 
 impl HapiCoreNear {
     pub fn view_request(&self, method: &str, args: Option<Value>) -> RpcQueryRequest {
+        self.view_request_with_reference(
+            method,
+            args,
+            BlockReference::Finality(get_finality(self.commitment)),
+        )
+    }
+
+    /// View request to the contract state at the given block height
+    pub fn view_request_at_block(
+        &self,
+        method: &str,
+        args: Option<Value>,
+        block_height: u64,
+    ) -> RpcQueryRequest {
+        self.view_request_with_reference(
+            method,
+            args,
+            BlockReference::BlockId(BlockId::Height(block_height)),
+        )
+    }
+
+    fn view_request_with_reference(
+        &self,
+        method: &str,
+        args: Option<Value>,
+        block_reference: BlockReference,
+    ) -> RpcQueryRequest {
         RpcQueryRequest {
-            block_reference: BlockReference::Finality(Finality::Final),
+            block_reference,
             request: QueryRequest::CallFunction {
                 account_id: self.contract_address.clone(),
                 method_name: method.to_string(),
@@ -662,4 +1008,169 @@ impl HapiCoreNear {
 
         reporter.try_into()
     }
+
+    /// Get reporter state at the given block height
+    pub async fn get_reporter_at_block(&self, id: &str, block_height: u64) -> Result<Reporter> {
+        let request = self.view_request_at_block(
+            "get_reporter",
+            Some(json!({ "id": uuid_to_u128!(id) })),
+            block_height,
+        );
+
+        self.get_response::<NearReporter>(request).await?.try_into()
+    }
+
+    /// Get reporter state by account at the given block height
+    pub async fn get_reporter_by_account_at_block(
+        &self,
+        account_id: &str,
+        block_height: u64,
+    ) -> Result<Reporter> {
+        let request = self.view_request_at_block(
+            "get_reporter_by_account",
+            Some(json!({ "account_id": account_id })),
+            block_height,
+        );
+
+        self.get_response::<NearReporter>(request).await?.try_into()
+    }
+
+    /// Get case state at the given block height
+    pub async fn get_case_at_block(&self, id: &str, block_height: u64) -> Result<Case> {
+        let request = self.view_request_at_block(
+            "get_case",
+            Some(json!({ "id": uuid_to_u128!(id) })),
+            block_height,
+        );
+
+        Ok(self.get_response::<NearCase>(request).await?.try_into()?)
+    }
+
+    /// Get address state at the given block height
+    pub async fn get_address_at_block(&self, addr: &str, block_height: u64) -> Result<Address> {
+        let request = self.view_request_at_block(
+            "get_address",
+            Some(json!({ "address": addr })),
+            block_height,
+        );
+
+        Ok(self
+            .get_response::<NearAddress>(request)
+            .await?
+            .try_into()?)
+    }
+
+    /// Get asset state at the given block height
+    pub async fn get_asset_at_block(
+        &self,
+        address: &str,
+        id: &AssetId,
+        block_height: u64,
+    ) -> Result<Asset> {
+        let request = self.view_request_at_block(
+            "get_asset",
+            Some(json!({ "address": address, "id": id })),
+            block_height,
+        );
+
+        Ok(self.get_response::<NearAsset>(request).await?.try_into()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::entities::category::Category;
+
+    use super::*;
+
+    fn get_method(action: &Action) -> (&str, Value) {
+        let Action::FunctionCall(call) = action else {
+            panic!("Unexpected action: {action:?}");
+        };
+
+        (
+            call.method_name.as_str(),
+            serde_json::from_slice(&call.args).expect("Invalid function call args"),
+        )
+    }
+
+    #[test]
+    fn test_get_batch_actions() {
+        let case_id = Uuid::new_v4();
+
+        let batch = Batch::new()
+            .create_case(CreateCaseInput {
+                id: case_id,
+                name: "case".to_string(),
+                url: "https://hapi.one".to_string(),
+            })
+            .create_address(CreateAddressInput {
+                address: "address.near".to_string(),
+                case_id,
+                risk: 5,
+                category: Category::Scam,
+            });
+
+        let actions = get_batch_actions(batch).unwrap();
+
+        assert_eq!(actions.len(), 2);
+
+        let (method, args) = get_method(&actions[0]);
+        assert_eq!(method, "create_case");
+        assert_eq!(args["id"], json!(case_id.as_u128().to_string()));
+
+        let (method, args) = get_method(&actions[1]);
+        assert_eq!(method, "create_address");
+        assert_eq!(args["address"], json!("address.near"));
+        assert_eq!(args["case_id"], json!(case_id.as_u128().to_string()));
+        assert_eq!(args["risk_score"], json!(5));
+    }
+
+    fn address_batch(count: usize) -> Batch {
+        let case_id = Uuid::new_v4();
+
+        (0..count).fold(Batch::new(), |batch, index| {
+            batch.create_address(CreateAddressInput {
+                address: format!("address{index}.near"),
+                case_id,
+                risk: 5,
+                category: Category::Scam,
+            })
+        })
+    }
+
+    fn get_gas(action: &Action) -> u64 {
+        let Action::FunctionCall(call) = action else {
+            panic!("Unexpected action: {action:?}");
+        };
+
+        call.gas
+    }
+
+    #[test]
+    fn test_batch_gas() {
+        let actions = get_batch_actions(address_batch(2)).unwrap();
+        assert!(actions.iter().all(|action| get_gas(action) == GAS_FOR_TX));
+
+        // A case with its addresses fits into a single transaction
+        for count in [7, MAX_BATCH_ACTIONS] {
+            let actions = get_batch_actions(address_batch(count)).unwrap();
+
+            assert_eq!(actions.len(), count);
+            assert!(actions.iter().map(get_gas).sum::<u64>() <= MAX_GAS_PER_TX);
+            assert!(actions
+                .iter()
+                .all(|action| get_gas(action) >= MIN_GAS_FOR_BATCH_ACTION));
+        }
+    }
+
+    #[test]
+    fn test_batch_too_large() {
+        let error = get_batch_actions(address_batch(MAX_BATCH_ACTIONS + 1)).unwrap_err();
+
+        assert!(matches!(
+            error,
+            ClientError::BatchTooLarge(count, MAX_BATCH_ACTIONS) if count == MAX_BATCH_ACTIONS + 1
+        ));
+    }
 }
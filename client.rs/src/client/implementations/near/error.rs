@@ -0,0 +1,117 @@
+use near_primitives::errors::{ActionErrorKind, FunctionCallError, TxExecutionError};
+
+use crate::client::{contract_error::HapiContractError, result::ClientError};
+
+// Prefix of the execution error of a contract that called `env::panic_str`
+const PANIC_PREFIX: &str = "Smart contract panicked: ";
+
+// Panic messages of the HAPI Core contract, as defined in its `errors` module
+const CONTRACT_ERRORS: &[(&str, HapiContractError)] = &[
+    (
+        "Only authority can call this method",
+        HapiContractError::Unauthorized,
+    ),
+    ("Reporter not found", HapiContractError::ReporterNotFound),
+    ("Reporter already exists", HapiContractError::ReporterExists),
+    ("Invalid stake token", HapiContractError::InvalidToken),
+    ("Invalid reward token", HapiContractError::InvalidToken),
+    (
+        "Invalid stake amount",
+        HapiContractError::InsufficientTokens,
+    ),
+    (
+        "Reporter is active",
+        HapiContractError::InvalidReporterStatus,
+    ),
+    ("Reporter is inactive", HapiContractError::ReporterInactive),
+    (
+        "Unlock duration not passed",
+        HapiContractError::ReporterLocked,
+    ),
+    ("Name is too long", HapiContractError::InvalidData),
+    ("Token cannot be changed", HapiContractError::InvalidToken),
+    (
+        "Reporter can't confirm report reported by himself",
+        HapiContractError::CannotConfirmOwnReport,
+    ),
+    ("Invalid role", HapiContractError::Unauthorized),
+    ("Case not found", HapiContractError::CaseNotFound),
+    ("Case already exists", HapiContractError::CaseExists),
+    ("Invalid risk score", HapiContractError::RiskOutOfRange),
+    ("Address not found", HapiContractError::AddressNotFound),
+    ("Already confirmed", HapiContractError::AlreadyConfirmed),
+    ("Address already exists", HapiContractError::AddressExists),
+    (
+        "Stake configuration is not set",
+        HapiContractError::ConfigurationNotSet,
+    ),
+    (
+        "Reward configuration is not set",
+        HapiContractError::ConfigurationNotSet,
+    ),
+    ("Asset not found", HapiContractError::AssetNotFound),
+    ("Asset already exists", HapiContractError::AssetExists),
+];
+
+/// Error of a failed transaction, with the contract error if the contract panicked
+pub(super) fn map_execution_error(e: TxExecutionError) -> ClientError {
+    match decode_execution_error(&e) {
+        Some(error) => error.into(),
+        None => ClientError::InvalidResponse(format!("Call method failed with {e}")),
+    }
+}
+
+fn decode_execution_error(e: &TxExecutionError) -> Option<HapiContractError> {
+    let TxExecutionError::ActionError(e) = e else {
+        return None;
+    };
+
+    let ActionErrorKind::FunctionCallError(FunctionCallError::ExecutionError(message)) = &e.kind
+    else {
+        return None;
+    };
+
+    let message = message.strip_prefix(PANIC_PREFIX)?;
+
+    Some(
+        CONTRACT_ERRORS
+            .iter()
+            .find(|(error_message, _)| *error_message == message)
+            .map_or_else(
+                || HapiContractError::Other(message.to_string()),
+                |(_, error)| error.clone(),
+            ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use near_primitives::errors::ActionError;
+
+    use super::*;
+
+    fn panic_error(message: &str) -> TxExecutionError {
+        TxExecutionError::ActionError(ActionError {
+            index: Some(0),
+            kind: ActionErrorKind::FunctionCallError(FunctionCallError::ExecutionError(format!(
+                "{PANIC_PREFIX}{message}"
+            ))),
+        })
+    }
+
+    #[test]
+    fn test_decode_execution_error() {
+        assert_eq!(
+            decode_execution_error(&panic_error("Case not found")),
+            Some(HapiContractError::CaseNotFound)
+        );
+        assert_eq!(
+            decode_execution_error(&panic_error(
+                "Requires attached deposit of exactly 1 yoctoNEAR"
+            )),
+            Some(HapiContractError::Other(
+                "Requires attached deposit of exactly 1 yoctoNEAR".to_string()
+            ))
+        );
+    }
+}
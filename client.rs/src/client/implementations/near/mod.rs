@@ -1,10 +1,14 @@
 mod client;
 mod conversion;
+mod error;
 mod token;
+mod transport;
 
 pub use client::{
     HapiCoreNear, DELAY_AFTER_TX_EXECUTION, PERIOD_CHECK_TX_STATUS, TRANSACTION_TIMEOUT,
 };
 pub use token::TokenContractNear;
+pub use transport::NearRpcClient;
 
 pub const GAS_FOR_TX: u64 = 50_000_000_000_000; // 50 TeraGas
+pub const MAX_GAS_PER_TX: u64 = 300_000_000_000_000; // 300 TeraGas
@@ -1,21 +1,23 @@
 use async_trait::async_trait;
 use near_crypto::SecretKey;
-use near_jsonrpc_client::{
-    methods::{self, query::RpcQueryRequest},
-    JsonRpcClient,
-};
+use near_jsonrpc_client::methods::{self, query::RpcQueryRequest};
 use near_jsonrpc_primitives::types::query::QueryResponseKind;
 use near_primitives::{
     transaction::Transaction,
     transaction::{Action, FunctionCallAction},
-    types::{AccountId, BlockReference, Finality, FunctionArgs},
+    types::{AccountId, BlockReference, FunctionArgs},
     views::QueryRequest,
 };
+use serde::Deserialize;
 use serde_json::{from_slice, json};
 
-use super::client::execute_transaction;
+use super::{
+    client::{execute_transaction, get_finality},
+    transport::NearRpcClient,
+};
 use crate::{
     client::{
+        commitment::Commitment,
         near::GAS_FOR_TX,
         result::{ClientError, Result, Tx},
         token::TokenContract,
@@ -23,18 +25,26 @@ use crate::{
     Amount, HapiCoreOptions,
 };
 
+// NEP-148 metadata fields that the client uses
+#[derive(Deserialize)]
+struct FungibleTokenMetadata {
+    decimals: u8,
+}
+
 pub struct TokenContractNear {
-    client: JsonRpcClient,
+    client: NearRpcClient,
     contract_address: AccountId,
     signer: Option<String>,
+    commitment: Commitment,
 }
 
 impl TokenContractNear {
     pub fn new(options: HapiCoreOptions) -> Result<Self> {
         Ok(Self {
-            client: JsonRpcClient::connect(options.provider_url),
+            client: NearRpcClient::new(&options)?,
             contract_address: options.contract_address.try_into()?,
             signer: options.private_key,
+            commitment: options.commitment,
         })
     }
 }
@@ -91,7 +101,7 @@ impl TokenContract for TokenContractNear {
             }))],
         };
 
-        Ok(execute_transaction(transaction, signer, &self.client).await?)
+        Ok(execute_transaction(transaction, signer, &self.client, None, self.commitment).await?)
     }
 
     async fn approve(&self, _spender: &str, _amount: Amount) -> Result<Tx> {
@@ -100,7 +110,7 @@ impl TokenContract for TokenContractNear {
 
     async fn balance(&self, addr: &str) -> Result<Amount> {
         let request = RpcQueryRequest {
-            block_reference: BlockReference::Finality(Finality::Final),
+            block_reference: BlockReference::Finality(get_finality(self.commitment)),
             request: QueryRequest::CallFunction {
                 account_id: self.contract_address.to_owned(),
                 method_name: "ft_balance_of".to_string(),
@@ -117,4 +127,24 @@ impl TokenContract for TokenContractNear {
             ))
         }
     }
+
+    async fn decimals(&self) -> Result<u8> {
+        let request = RpcQueryRequest {
+            block_reference: BlockReference::Finality(get_finality(self.commitment)),
+            request: QueryRequest::CallFunction {
+                account_id: self.contract_address.to_owned(),
+                method_name: "ft_metadata".to_string(),
+                args: FunctionArgs::from(json!({}).to_string().into_bytes()),
+            },
+        };
+
+        let result = self.client.call(request).await?;
+        if let QueryResponseKind::CallResult(result) = result.kind {
+            Ok(from_slice::<FungibleTokenMetadata>(&result.result)?.decimals)
+        } else {
+            Err(ClientError::InvalidResponse(
+                "failed to receive call result".into(),
+            ))
+        }
+    }
 }
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use near_jsonrpc_client::{
+    errors::{JsonRpcError, JsonRpcServerError, JsonRpcServerResponseStatusError},
+    methods, JsonRpcClient, MethodCallResult,
+};
+
+use crate::client::{
+    interface::HapiCoreOptions,
+    result::Result,
+    transport::{is_transient_status, Endpoints},
+};
+
+/// NEAR JSON-RPC client over multiple endpoints with retries and failover
+#[derive(Clone)]
+pub struct NearRpcClient {
+    endpoints: Arc<Endpoints<JsonRpcClient>>,
+}
+
+impl NearRpcClient {
+    pub fn new(options: &HapiCoreOptions) -> Result<Self> {
+        let clients = options
+            .provider_urls
+            .iter()
+            .map(|url| JsonRpcClient::connect(url))
+            .collect();
+
+        Ok(Self {
+            endpoints: Arc::new(Endpoints::new(clients, options.transport.clone())?),
+        })
+    }
+
+    /// Calls the RPC method, retrying transient errors on the next endpoint
+    pub async fn call<M: methods::RpcMethod>(
+        &self,
+        method: M,
+    ) -> MethodCallResult<M::Response, M::Error> {
+        self.endpoints
+            .request(
+                |client| {
+                    let method = &method;
+                    async move { client.call(method).await }
+                },
+                is_transient::<M::Error>,
+            )
+            .await
+    }
+}
+
+fn is_transient<E>(e: &JsonRpcError<E>) -> bool {
+    match e {
+        JsonRpcError::TransportError(_) => true,
+        JsonRpcError::ServerError(e) => match e {
+            JsonRpcServerError::InternalError { .. } => true,
+            JsonRpcServerError::ResponseStatusError(e) => match e {
+                JsonRpcServerResponseStatusError::TooManyRequests
+                | JsonRpcServerResponseStatusError::TimeoutError
+                | JsonRpcServerResponseStatusError::ServiceUnavailable => true,
+                JsonRpcServerResponseStatusError::Unexpected { status } => {
+                    is_transient_status(status.as_u16())
+                }
+                _ => false,
+            },
+            _ => false,
+        },
+    }
+}
@@ -0,0 +1,124 @@
+use {
+    anchor_client::solana_sdk::pubkey::Pubkey,
+    std::{
+        collections::HashMap,
+        hash::Hash,
+        sync::{Mutex, PoisonError},
+    },
+};
+
+/// Value fetched at a slot
+struct Entry<T> {
+    slot: u64,
+    value: T,
+}
+
+/// Local cache of program accounts, entries are reused for a number of slots after they are fetched
+pub(crate) struct AccountCache {
+    max_age: u64,
+    keys: Mutex<HashMap<[u8; 8], Entry<Vec<Pubkey>>>>,
+    data: Mutex<HashMap<Pubkey, Entry<Vec<u8>>>>,
+}
+
+impl AccountCache {
+    pub fn new(max_age: u64) -> Self {
+        Self {
+            max_age,
+            keys: Mutex::new(HashMap::new()),
+            data: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the account addresses of the type with the discriminator
+    pub fn get_keys(&self, discriminator: &[u8; 8], slot: u64) -> Option<Vec<Pubkey>> {
+        self.get(&self.keys, discriminator, slot)
+    }
+
+    pub fn set_keys(&self, discriminator: [u8; 8], slot: u64, keys: Vec<Pubkey>) {
+        Self::set(&self.keys, discriminator, slot, keys)
+    }
+
+    /// Returns the account data
+    pub fn get_data(&self, address: &Pubkey, slot: u64) -> Option<Vec<u8>> {
+        self.get(&self.data, address, slot)
+    }
+
+    pub fn set_data(&self, address: Pubkey, slot: u64, data: Vec<u8>) {
+        Self::set(&self.data, address, slot, data)
+    }
+
+    /// Drops all entries, used after the client's own writes make them stale
+    pub fn clear(&self) {
+        self.keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        self.data
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    fn get<K: Eq + Hash, T: Clone>(
+        &self,
+        entries: &Mutex<HashMap<K, Entry<T>>>,
+        key: &K,
+        slot: u64,
+    ) -> Option<T> {
+        let entries = entries.lock().unwrap_or_else(PoisonError::into_inner);
+
+        entries
+            .get(key)
+            .filter(|entry| slot.saturating_sub(entry.slot) <= self.max_age)
+            .map(|entry| entry.value.clone())
+    }
+
+    fn set<K: Eq + Hash, T>(entries: &Mutex<HashMap<K, Entry<T>>>, key: K, slot: u64, value: T) {
+        entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, Entry { slot, value });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DISCRIMINATOR: [u8; 8] = [1; 8];
+
+    #[test]
+    fn test_entry_age() {
+        let cache = AccountCache::new(10);
+        let keys = vec![Pubkey::new_unique()];
+        let address = Pubkey::new_unique();
+
+        cache.set_keys(DISCRIMINATOR, 100, keys.clone());
+        cache.set_data(address, 100, vec![1, 2, 3]);
+
+        assert_eq!(cache.get_keys(&DISCRIMINATOR, 110), Some(keys));
+        assert_eq!(cache.get_data(&address, 110), Some(vec![1, 2, 3]));
+
+        assert_eq!(cache.get_keys(&DISCRIMINATOR, 111), None);
+        assert_eq!(cache.get_data(&address, 111), None);
+        assert_eq!(cache.get_keys(&[2; 8], 100), None);
+    }
+
+    #[test]
+    fn test_clear() {
+        let cache = AccountCache::new(10);
+        let address = Pubkey::new_unique();
+
+        cache.set_keys(DISCRIMINATOR, 100, vec![address]);
+        cache.set_data(address, 100, vec![1, 2, 3]);
+
+        cache.clear();
+
+        assert_eq!(cache.get_keys(&DISCRIMINATOR, 100), None);
+        assert_eq!(cache.get_data(&address, 100), None);
+
+        // The cache is filled again after the next listing
+        cache.set_keys(DISCRIMINATOR, 101, vec![address]);
+        assert_eq!(cache.get_keys(&DISCRIMINATOR, 101), Some(vec![address]));
+    }
+}
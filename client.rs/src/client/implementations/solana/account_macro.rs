@@ -11,17 +11,19 @@ macro_rules! get_solana_account {
 
 #[macro_export]
 macro_rules! get_solana_accounts {
-    ($self:expr, $account:ident) => {{
+    ($self:expr, $account:ident, $skip:expr, $take:expr) => {{
         let data = $self
-            .get_accounts::<hapi_core_solana::$account>(hapi_core_solana::$account::LEN)
+            .get_network_accounts::<hapi_core_solana::$account>(
+                hapi_core_solana::$account::LEN,
+                $skip,
+                $take,
+            )
             .await?;
 
         let mut result: Vec<$account> = vec![];
 
         for (_, acc) in data {
-            if acc.network == $self.network {
-                result.push(<$account>::try_from(acc)?);
-            }
+            result.push(<$account>::try_from(acc)?);
         }
 
         Ok(result)
@@ -30,8 +32,11 @@ macro_rules! get_solana_accounts {
 
 #[macro_export]
 macro_rules! get_solana_account_count {
-    ($self:expr, $account:ident) => {{
-        let accounts: Result<Vec<$account>> = get_solana_accounts!($self, $account);
-        Ok(accounts?.len() as u64)
-    }};
+    ($self:expr, $account:ident) => {
+        $self
+            .get_network_account_count::<hapi_core_solana::$account>(
+                hapi_core_solana::$account::LEN,
+            )
+            .await
+    };
 }
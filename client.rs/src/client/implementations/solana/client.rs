@@ -11,23 +11,24 @@ use {
             pubkey::Pubkey,
             signature::{Keypair, Signer},
             system_program,
-            transaction::Transaction,
         },
         RequestBuilder,
     },
     async_trait::async_trait,
     hapi_core_solana::{accounts, instruction},
-    solana_account_decoder::UiAccountEncoding,
+    solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig},
     spl_associated_token_account::{
         get_associated_token_address, instruction::create_associated_token_account,
     },
     spl_token::solana_program::instruction::Instruction,
-    std::{str::FromStr, sync::Arc, time::Duration},
+    std::{str::FromStr, sync::Arc},
     uuid::Uuid,
 };
 
 use crate::{
     client::{
+        batch::{Batch, BatchOperation, BatchResult},
+        commitment::Commitment,
         configuration::{RewardConfiguration, StakeConfiguration},
         entities::{
             address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
@@ -35,6 +36,7 @@ use crate::{
             case::{Case, CreateCaseInput, UpdateCaseInput},
             reporter::{CreateReporterInput, Reporter, UpdateReporterInput},
         },
+        fees::FeeOptions,
         interface::HapiCoreOptions,
         result::{ClientError, Result, Tx},
     },
@@ -42,22 +44,33 @@ use crate::{
 };
 
 use super::{
+    account_cache::AccountCache,
+    error::map_program_error,
     instruction_data::get_hapi_sighashes,
+    transport::{connect, DEFAULT_TIMEOUT},
     utils::{
-        byte_array_from_str, get_address_address, get_asset_address, get_case_address,
-        get_confirmation_address, get_network_address, get_program_data_address,
-        get_reporter_address, get_signer,
+        build_transaction, build_tx, byte_array_from_str, get_address_address, get_asset_address,
+        get_case_address, get_commitment_config, get_confirmation_address,
+        get_confirmed_transaction, get_fixed_instructions, get_network_account_filters,
+        get_network_address, get_program_data_address, get_reporter_address, get_signer,
+        pack_instructions, send_and_confirm_transaction, NetworkAccount,
     },
 };
 
-const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+// Largest number of accounts in a single getMultipleAccounts request
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
 
+#[derive(Clone)]
 pub struct HapiCoreSolana {
-    pub rpc_client: RpcClient,
+    pub rpc_client: Arc<RpcClient>,
     pub program_id: Pubkey,
     network: Pubkey,
     signer: Arc<Keypair>,
     pub(crate) hashes: Vec<[u8; 8]>,
+    cache: Option<Arc<AccountCache>>,
+    commitment: Commitment,
+    fees: FeeOptions,
+    nonce_account: Option<Pubkey>,
 }
 
 impl HapiCoreSolana {
@@ -66,11 +79,17 @@ impl HapiCoreSolana {
             ClientError::SolanaAddressParseError(format!("`contract-address`: {e}"))
         })?;
 
+        let rpc_client = Arc::new(connect(&options, DEFAULT_TIMEOUT)?);
+
         let signer = Arc::new(get_signer(options.private_key)?);
 
-        let (network, _) = get_network_address(&options.network.to_string(), &program_id)?;
+        let nonce_account = options
+            .nonce_account
+            .map(|nonce_account| nonce_account.parse::<Pubkey>())
+            .transpose()
+            .map_err(|e| ClientError::SolanaAddressParseError(format!("`nonce-account`: {e}")))?;
 
-        let rpc_client = RpcClient::new_with_timeout(options.provider_url.clone(), DEFAULT_TIMEOUT);
+        let (network, _) = get_network_address(&options.network.to_string(), &program_id)?;
 
         let hashes = get_hapi_sighashes();
 
@@ -80,32 +99,68 @@ impl HapiCoreSolana {
             network,
             signer,
             hashes,
+            cache: None,
+            commitment: options.commitment,
+            fees: options.fees,
+            nonce_account,
         })
     }
 
-    async fn send_transaction(&self, instructions: &[Instruction]) -> Result<Tx> {
-        let latest_hash = self.rpc_client.get_latest_blockhash().await?;
+    /// Enables the local account cache, listed accounts are reused for `max_age` slots.
+    /// The cache is dropped after each transaction sent by the client
+    pub fn with_account_cache(mut self, max_age: u64) -> Self {
+        self.cache = Some(Arc::new(AccountCache::new(max_age)));
+        self
+    }
+
+    fn commitment_config(&self) -> CommitmentConfig {
+        get_commitment_config(self.commitment)
+    }
 
-        let tx = Transaction::new_signed_with_payer(
+    async fn send_transaction(&self, instructions: &[Instruction]) -> Result<Tx> {
+        let tx = build_transaction(
+            &self.rpc_client,
             instructions,
-            Some(&self.signer.pubkey()),
-            &vec![&self.signer.clone()],
-            latest_hash,
-        );
+            &self.signer,
+            &self.fees,
+            self.nonce_account.as_ref(),
+            self.commitment,
+        )
+        .await?;
 
-        let hash = self
-            .rpc_client
-            .send_and_confirm_transaction(&tx)
-            .await?
-            .to_string();
+        let result = send_and_confirm_transaction(
+            &self.rpc_client,
+            &tx,
+            self.commitment,
+            self.nonce_account.as_ref(),
+        )
+        .await;
 
-        Ok(Tx { hash })
+        // Listed accounts may be changed by the transaction, even if its confirmation failed
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+
+        let signature = result.map_err(map_program_error)?;
+        let tx = get_confirmed_transaction(&self.rpc_client, &signature, self.commitment).await?;
+
+        let events = match &tx.transaction.meta {
+            Some(meta) if meta.err.is_none() => self
+                .decode_transaction(tx.clone())
+                .map_err(|e| ClientError::InstructionDecodingError(e.to_string()))?
+                .into_iter()
+                .map(|instruction| instruction.name)
+                .collect(),
+            _ => vec![],
+        };
+
+        Ok(build_tx(&signature, &tx, events))
     }
 
     pub async fn get_account_data<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
         let mut data: &[u8] = &self
             .rpc_client
-            .get_account_with_commitment(address, CommitmentConfig::processed())
+            .get_account_with_commitment(address, self.commitment_config())
             .await?
             .value
             .ok_or(ClientError::AccountNotFound)?
@@ -129,6 +184,7 @@ impl HapiCoreSolana {
             filters: Some(vec![account_type_filter, account_size_filter]),
             account_config: RpcAccountInfoConfig {
                 encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.commitment_config()),
                 ..RpcAccountInfoConfig::default()
             },
             ..RpcProgramAccountsConfig::default()
@@ -151,12 +207,162 @@ impl HapiCoreSolana {
         Ok(result)
     }
 
+    // Current slot, requested only for the cache
+    async fn get_cache_slot(&self) -> Result<Option<u64>> {
+        match self.cache {
+            Some(_) => Ok(Some(
+                self.rpc_client
+                    .get_slot_with_commitment(self.commitment_config())
+                    .await?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns addresses of the network accounts of the type, sorted to make pages stable
+    async fn get_network_account_keys<T>(&self, data_size: usize) -> Result<Vec<Pubkey>>
+    where
+        T: Discriminator + NetworkAccount,
+    {
+        let slot = self.get_cache_slot().await?;
+
+        if let (Some(cache), Some(slot)) = (&self.cache, slot) {
+            if let Some(keys) = cache.get_keys(&T::discriminator(), slot) {
+                return Ok(keys);
+            }
+        }
+
+        let config = RpcProgramAccountsConfig {
+            filters: Some(get_network_account_filters::<T>(data_size, &self.network)),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                // Only addresses are listed, data is fetched for the requested page
+                data_slice: Some(UiDataSliceConfig {
+                    offset: 0,
+                    length: 0,
+                }),
+                commitment: Some(self.commitment_config()),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let mut keys: Vec<Pubkey> = self
+            .rpc_client
+            .get_program_accounts_with_config(&self.program_id, config)
+            .await?
+            .into_iter()
+            .map(|(pubkey, _)| pubkey)
+            .collect();
+
+        keys.sort();
+
+        if let (Some(cache), Some(slot)) = (&self.cache, slot) {
+            cache.set_keys(T::discriminator(), slot, keys.clone());
+        }
+
+        Ok(keys)
+    }
+
+    async fn get_network_account_count<T>(&self, data_size: usize) -> Result<u64>
+    where
+        T: Discriminator + NetworkAccount,
+    {
+        Ok(self.get_network_account_keys::<T>(data_size).await?.len() as u64)
+    }
+
+    /// Returns a page of the network accounts of the type, ordered by address
+    async fn get_network_accounts<T>(
+        &self,
+        data_size: usize,
+        skip: u64,
+        take: u64,
+    ) -> Result<Vec<(Pubkey, T)>>
+    where
+        T: AccountDeserialize + Discriminator + NetworkAccount,
+    {
+        let keys: Vec<Pubkey> = self
+            .get_network_account_keys::<T>(data_size)
+            .await?
+            .into_iter()
+            .skip(skip as usize)
+            .take(take as usize)
+            .collect();
+
+        let mut result = vec![];
+
+        for chunk in keys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let data = self.get_multiple_account_data(chunk).await?;
+
+            // Accounts closed after listing are skipped
+            for (pubkey, data) in chunk.iter().zip(data) {
+                if let Some(data) = data {
+                    let account = T::try_deserialize(&mut data.as_slice())
+                        .map_err(|e| ClientError::AccountDeserializationError(e.to_string()))?;
+
+                    result.push((*pubkey, account));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn get_multiple_account_data(&self, keys: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>> {
+        let slot = self.get_cache_slot().await?;
+
+        let mut result: Vec<Option<Vec<u8>>> = match (&self.cache, slot) {
+            (Some(cache), Some(slot)) => keys.iter().map(|key| cache.get_data(key, slot)).collect(),
+            _ => vec![None; keys.len()],
+        };
+
+        let missing: Vec<(usize, Pubkey)> = keys
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| result[*index].is_none())
+            .map(|(index, key)| (index, *key))
+            .collect();
+
+        if missing.is_empty() {
+            return Ok(result);
+        }
+
+        let missing_keys: Vec<Pubkey> = missing.iter().map(|(_, key)| *key).collect();
+        let accounts = self
+            .rpc_client
+            .get_multiple_accounts_with_commitment(&missing_keys, self.commitment_config())
+            .await?
+            .value;
+
+        for ((index, key), account) in missing.into_iter().zip(accounts) {
+            if let Some(account) = account {
+                if let (Some(cache), Some(slot)) = (&self.cache, slot) {
+                    cache.set_data(key, slot, account.data.clone());
+                }
+
+                result[index] = Some(account.data);
+            }
+        }
+
+        Ok(result)
+    }
+
     async fn call_contract(
         &self,
         accounts: impl ToAccountMetas,
         args: impl InstructionData,
     ) -> Result<Tx> {
-        let instructions = RequestBuilder::from(
+        let instructions = self.get_instructions(accounts, args)?;
+
+        self.send_transaction(&instructions).await
+    }
+
+    fn get_instructions(
+        &self,
+        accounts: impl ToAccountMetas,
+        args: impl InstructionData,
+    ) -> Result<Vec<Instruction>> {
+        Ok(RequestBuilder::from(
             self.program_id,
             &self.rpc_client.url(),
             self.signer.clone(),
@@ -164,7 +370,160 @@ impl HapiCoreSolana {
         )
         .accounts(accounts)
         .args(args)
-        .instructions()?;
+        .instructions()?)
+    }
+
+    /// Program instructions of the operation sent by the reporter
+    fn get_operation_instructions(
+        &self,
+        operation: BatchOperation,
+        reporter: Pubkey,
+    ) -> Result<Vec<Instruction>> {
+        match operation {
+            BatchOperation::CreateCase(input) => {
+                let (case, bump) = get_case_address(input.id, &self.network, &self.program_id)?;
+
+                self.get_instructions(
+                    accounts::CreateCase {
+                        sender: self.signer.pubkey(),
+                        case,
+                        network: self.network,
+                        reporter,
+                        system_program: system_program::id(),
+                    },
+                    instruction::CreateCase {
+                        case_id: input.id.as_u128(),
+                        name: input.name,
+                        url: input.url,
+                        bump,
+                    },
+                )
+            }
+            BatchOperation::UpdateCase(input) => {
+                let (case, _) = get_case_address(input.id, &self.network, &self.program_id)?;
+
+                self.get_instructions(
+                    accounts::UpdateCase {
+                        sender: self.signer.pubkey(),
+                        case,
+                        network: self.network,
+                        reporter,
+                        system_program: system_program::id(),
+                    },
+                    instruction::UpdateCase {
+                        name: input.name,
+                        url: input.url,
+                        status: input.status.into(),
+                    },
+                )
+            }
+            BatchOperation::CreateAddress(input) => {
+                let mut addr = [0u8; 64];
+                byte_array_from_str(&input.address, &mut addr)?;
+
+                let (address, bump) = get_address_address(&addr, &self.network, &self.program_id)?;
+                let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+
+                self.get_instructions(
+                    accounts::CreateAddress {
+                        sender: self.signer.pubkey(),
+                        network: self.network,
+                        reporter,
+                        case,
+                        address,
+                        system_program: system_program::id(),
+                    },
+                    instruction::CreateAddress {
+                        addr,
+                        category: input.category.into(),
+                        risk_score: input.risk,
+                        bump,
+                    },
+                )
+            }
+            BatchOperation::UpdateAddress(input) => {
+                let mut addr = [0u8; 64];
+                byte_array_from_str(&input.address, &mut addr)?;
+
+                let (address, _) = get_address_address(&addr, &self.network, &self.program_id)?;
+                let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+
+                self.get_instructions(
+                    accounts::UpdateAddress {
+                        sender: self.signer.pubkey(),
+                        network: self.network,
+                        reporter,
+                        case,
+                        address,
+                        system_program: system_program::id(),
+                    },
+                    instruction::UpdateAddress {
+                        category: input.category.into(),
+                        risk_score: input.risk,
+                    },
+                )
+            }
+            BatchOperation::CreateAsset(input) => {
+                let mut addr = [0u8; 64];
+                byte_array_from_str(&input.address, &mut addr)?;
+
+                let mut asset_id = [0u8; 32];
+                byte_array_from_str(&input.asset_id.to_string(), &mut asset_id)?;
+
+                let (asset, bump) =
+                    get_asset_address(&addr, &asset_id, &self.network, &self.program_id)?;
+                let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+
+                self.get_instructions(
+                    accounts::CreateAsset {
+                        sender: self.signer.pubkey(),
+                        network: self.network,
+                        reporter,
+                        case,
+                        asset,
+                        system_program: system_program::id(),
+                    },
+                    instruction::CreateAsset {
+                        addr,
+                        asset_id,
+                        category: input.category.into(),
+                        risk_score: input.risk,
+                        bump,
+                    },
+                )
+            }
+            BatchOperation::UpdateAsset(input) => {
+                let mut addr = [0u8; 64];
+                byte_array_from_str(&input.address, &mut addr)?;
+
+                let mut asset_id = [0u8; 32];
+                byte_array_from_str(&input.asset_id.to_string(), &mut asset_id)?;
+
+                let (asset, _) =
+                    get_asset_address(&addr, &asset_id, &self.network, &self.program_id)?;
+                let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
+
+                self.get_instructions(
+                    accounts::UpdateAsset {
+                        sender: self.signer.pubkey(),
+                        network: self.network,
+                        reporter,
+                        case,
+                        asset,
+                        system_program: system_program::id(),
+                    },
+                    instruction::UpdateAsset {
+                        category: input.category.into(),
+                        risk_score: input.risk,
+                    },
+                )
+            }
+        }
+    }
+
+    async fn send_operation(&self, operation: BatchOperation) -> Result<Tx> {
+        let (reporter, _) = self.get_reporter().await?;
+        let instructions = self.get_operation_instructions(operation, reporter)?;
 
         self.send_transaction(&instructions).await
     }
@@ -206,6 +565,13 @@ impl HapiCore for HapiCoreSolana {
         Ok(())
     }
 
+    fn with_commitment(&self, commitment: Commitment) -> Box<dyn HapiCore> {
+        Box::new(Self {
+            commitment,
+            ..self.clone()
+        })
+    }
+
     async fn set_authority(&self, address: &str) -> Result<Tx> {
         let new_authority = Pubkey::from_str(address)
             .map_err(|e| ClientError::SolanaAddressParseError(format!("`new-authority`: {e}")))?;
@@ -249,7 +615,7 @@ impl HapiCore for HapiCoreSolana {
                     stake_mint,
                 },
                 instruction::UpdateStakeConfiguration {
-                    stake_configuration: configuration.into(),
+                    stake_configuration: configuration.try_into()?,
                 },
             )
             .await;
@@ -283,7 +649,7 @@ impl HapiCore for HapiCoreSolana {
                     reward_mint,
                 },
                 instruction::UpdateRewardConfiguration {
-                    reward_configuration: configuration.into(),
+                    reward_configuration: configuration.try_into()?,
                 },
             )
             .await;
@@ -356,8 +722,8 @@ impl HapiCore for HapiCoreSolana {
         get_solana_account_count!(self, Reporter)
     }
 
-    async fn get_reporters(&self, _skip: u64, _take: u64) -> Result<Vec<Reporter>> {
-        get_solana_accounts!(self, Reporter)
+    async fn get_reporters(&self, skip: u64, take: u64) -> Result<Vec<Reporter>> {
+        get_solana_accounts!(self, Reporter, skip, take)
     }
 
     async fn activate_reporter(&self) -> Result<Tx> {
@@ -425,46 +791,11 @@ impl HapiCore for HapiCoreSolana {
     }
 
     async fn create_case(&self, input: CreateCaseInput) -> Result<Tx> {
-        let (reporter, _) = self.get_reporter().await?;
-        let (case, bump) = get_case_address(input.id, &self.network, &self.program_id)?;
-
-        self.call_contract(
-            accounts::CreateCase {
-                sender: self.signer.pubkey(),
-                case,
-                network: self.network,
-                reporter,
-                system_program: system_program::id(),
-            },
-            instruction::CreateCase {
-                case_id: input.id.as_u128(),
-                name: input.name,
-                url: input.url,
-                bump,
-            },
-        )
-        .await
+        self.send_operation(BatchOperation::CreateCase(input)).await
     }
 
     async fn update_case(&self, input: UpdateCaseInput) -> Result<Tx> {
-        let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.id, &self.network, &self.program_id)?;
-
-        self.call_contract(
-            accounts::UpdateCase {
-                sender: self.signer.pubkey(),
-                case,
-                network: self.network,
-                reporter,
-                system_program: system_program::id(),
-            },
-            instruction::UpdateCase {
-                name: input.name,
-                url: input.url,
-                status: input.status.into(),
-            },
-        )
-        .await
+        self.send_operation(BatchOperation::UpdateCase(input)).await
     }
 
     async fn get_case(&self, id: &str) -> Result<Case> {
@@ -477,60 +808,18 @@ impl HapiCore for HapiCoreSolana {
         get_solana_account_count!(self, Case)
     }
 
-    async fn get_cases(&self, _skip: u64, _take: u64) -> Result<Vec<Case>> {
-        get_solana_accounts!(self, Case)
+    async fn get_cases(&self, skip: u64, take: u64) -> Result<Vec<Case>> {
+        get_solana_accounts!(self, Case, skip, take)
     }
 
     async fn create_address(&self, input: CreateAddressInput) -> Result<Tx> {
-        let mut addr = [0u8; 64];
-        byte_array_from_str(&input.address, &mut addr)?;
-
-        let (address, bump) = get_address_address(&addr, &self.network, &self.program_id)?;
-        let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
-
-        self.call_contract(
-            accounts::CreateAddress {
-                sender: self.signer.pubkey(),
-                network: self.network,
-                reporter,
-                case,
-                address,
-                system_program: system_program::id(),
-            },
-            instruction::CreateAddress {
-                addr,
-                category: input.category.into(),
-                risk_score: input.risk,
-                bump,
-            },
-        )
-        .await
+        self.send_operation(BatchOperation::CreateAddress(input))
+            .await
     }
 
     async fn update_address(&self, input: UpdateAddressInput) -> Result<Tx> {
-        let mut addr = [0u8; 64];
-        byte_array_from_str(&input.address, &mut addr)?;
-
-        let (address, _) = get_address_address(&addr, &self.network, &self.program_id)?;
-        let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
-
-        self.call_contract(
-            accounts::UpdateAddress {
-                sender: self.signer.pubkey(),
-                network: self.network,
-                reporter,
-                case,
-                address,
-                system_program: system_program::id(),
-            },
-            instruction::UpdateAddress {
-                category: input.category.into(),
-                risk_score: input.risk,
-            },
-        )
-        .await
+        self.send_operation(BatchOperation::UpdateAddress(input))
+            .await
     }
 
     async fn confirm_address(&self, input: ConfirmAddressInput) -> Result<Tx> {
@@ -575,67 +864,18 @@ impl HapiCore for HapiCoreSolana {
         get_solana_account_count!(self, Address)
     }
 
-    async fn get_addresses(&self, _skip: u64, _take: u64) -> Result<Vec<Address>> {
-        get_solana_accounts!(self, Address)
+    async fn get_addresses(&self, skip: u64, take: u64) -> Result<Vec<Address>> {
+        get_solana_accounts!(self, Address, skip, take)
     }
 
     async fn create_asset(&self, input: CreateAssetInput) -> Result<Tx> {
-        let mut addr = [0u8; 64];
-        byte_array_from_str(&input.address, &mut addr)?;
-
-        let mut asset_id = [0u8; 32];
-        byte_array_from_str(&input.asset_id.to_string(), &mut asset_id)?;
-
-        let (asset, bump) = get_asset_address(&addr, &asset_id, &self.network, &self.program_id)?;
-        let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
-
-        self.call_contract(
-            accounts::CreateAsset {
-                sender: self.signer.pubkey(),
-                network: self.network,
-                reporter,
-                case,
-                asset,
-                system_program: system_program::id(),
-            },
-            instruction::CreateAsset {
-                addr,
-                asset_id,
-                category: input.category.into(),
-                risk_score: input.risk,
-                bump,
-            },
-        )
-        .await
+        self.send_operation(BatchOperation::CreateAsset(input))
+            .await
     }
 
     async fn update_asset(&self, input: UpdateAssetInput) -> Result<Tx> {
-        let mut addr = [0u8; 64];
-        byte_array_from_str(&input.address, &mut addr)?;
-
-        let mut asset_id = [0u8; 32];
-        byte_array_from_str(&input.asset_id.to_string(), &mut asset_id)?;
-
-        let (asset, _) = get_asset_address(&addr, &asset_id, &self.network, &self.program_id)?;
-        let (reporter, _) = self.get_reporter().await?;
-        let (case, _) = get_case_address(input.case_id, &self.network, &self.program_id)?;
-
-        self.call_contract(
-            accounts::UpdateAsset {
-                sender: self.signer.pubkey(),
-                network: self.network,
-                reporter,
-                case,
-                asset,
-                system_program: system_program::id(),
-            },
-            instruction::UpdateAsset {
-                category: input.category.into(),
-                risk_score: input.risk,
-            },
-        )
-        .await
+        self.send_operation(BatchOperation::UpdateAsset(input))
+            .await
     }
 
     async fn confirm_asset(&self, input: ConfirmAssetInput) -> Result<Tx> {
@@ -685,7 +925,41 @@ impl HapiCore for HapiCoreSolana {
     async fn get_asset_count(&self) -> Result<u64> {
         get_solana_account_count!(self, Asset)
     }
-    async fn get_assets(&self, _skip: u64, _take: u64) -> Result<Vec<Asset>> {
-        get_solana_accounts!(self, Asset)
+    async fn get_assets(&self, skip: u64, take: u64) -> Result<Vec<Asset>> {
+        get_solana_accounts!(self, Asset, skip, take)
+    }
+
+    async fn send_batch(&self, batch: Batch) -> Result<BatchResult> {
+        let mut result = BatchResult::new(batch.len());
+
+        if batch.is_empty() {
+            return Ok(result);
+        }
+
+        let (reporter, _) = self.get_reporter().await?;
+
+        let operations = batch
+            .into_operations()
+            .into_iter()
+            .map(|operation| self.get_operation_instructions(operation, reporter))
+            .collect::<Result<Vec<_>>>()?;
+
+        let payer = self.signer.pubkey();
+        let fixed_instructions =
+            get_fixed_instructions(&payer, &self.fees, self.nonce_account.as_ref());
+
+        for range in pack_instructions(&operations, &payer, &fixed_instructions) {
+            let instructions = operations[range.clone()].concat();
+
+            match self.send_transaction(&instructions).await {
+                Ok(tx) => result.push(tx, range),
+                Err(e) => {
+                    result.fail(e);
+                    break;
+                }
+            }
+        }
+
+        Ok(result)
     }
 }
@@ -22,28 +22,32 @@ use {
     uuid::Uuid,
 };
 
-impl From<StakeConfiguration> for SolanaStakeConfiguration {
-    fn from(configuration: StakeConfiguration) -> Self {
-        Self {
+impl TryFrom<StakeConfiguration> for SolanaStakeConfiguration {
+    type Error = ClientError;
+
+    fn try_from(configuration: StakeConfiguration) -> Result<Self> {
+        Ok(Self {
             unlock_duration: configuration.unlock_duration,
-            validator_stake: configuration.validator_stake.into(),
-            tracer_stake: configuration.tracer_stake.into(),
-            publisher_stake: configuration.publisher_stake.into(),
-            authority_stake: configuration.authority_stake.into(),
+            validator_stake: configuration.validator_stake.try_into()?,
+            tracer_stake: configuration.tracer_stake.try_into()?,
+            publisher_stake: configuration.publisher_stake.try_into()?,
+            authority_stake: configuration.authority_stake.try_into()?,
             // TODO: add appraiser stake
             appraiser_stake: 0u64,
-        }
+        })
     }
 }
 
-impl From<RewardConfiguration> for SolanaRewardConfiguration {
-    fn from(configuration: RewardConfiguration) -> Self {
-        Self {
-            address_confirmation_reward: configuration.address_confirmation_reward.into(),
-            address_tracer_reward: configuration.address_tracer_reward.into(),
-            asset_confirmation_reward: configuration.asset_confirmation_reward.into(),
-            asset_tracer_reward: configuration.asset_tracer_reward.into(),
-        }
+impl TryFrom<RewardConfiguration> for SolanaRewardConfiguration {
+    type Error = ClientError;
+
+    fn try_from(configuration: RewardConfiguration) -> Result<Self> {
+        Ok(Self {
+            address_confirmation_reward: configuration.address_confirmation_reward.try_into()?,
+            address_tracer_reward: configuration.address_tracer_reward.try_into()?,
+            asset_confirmation_reward: configuration.asset_confirmation_reward.try_into()?,
+            asset_tracer_reward: configuration.asset_tracer_reward.try_into()?,
+        })
     }
 }
 
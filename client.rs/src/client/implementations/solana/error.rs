@@ -0,0 +1,98 @@
+use anchor_client::solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+use hapi_core_solana::ErrorCode;
+
+use crate::client::{contract_error::HapiContractError, result::ClientError};
+
+// Anchor error codes of the HAPI Core program
+const PROGRAM_ERRORS: &[(ErrorCode, HapiContractError)] = &[
+    (ErrorCode::InvalidToken, HapiContractError::InvalidToken),
+    (
+        ErrorCode::AuthorityMismatch,
+        HapiContractError::Unauthorized,
+    ),
+    (ErrorCode::IllegalOwner, HapiContractError::InvalidData),
+    (
+        ErrorCode::InvalidProgramData,
+        HapiContractError::InvalidData,
+    ),
+    (
+        ErrorCode::InvalidProgramAccount,
+        HapiContractError::InvalidData,
+    ),
+    (
+        ErrorCode::InvalidReporter,
+        HapiContractError::InvalidReporter,
+    ),
+    (
+        ErrorCode::InvalidReporterStatus,
+        HapiContractError::InvalidReporterStatus,
+    ),
+    (
+        ErrorCode::InactiveReporter,
+        HapiContractError::ReporterInactive,
+    ),
+    (ErrorCode::FrozenReporter, HapiContractError::ReporterFrozen),
+    (
+        ErrorCode::ReleaseEpochInFuture,
+        HapiContractError::ReporterLocked,
+    ),
+    (ErrorCode::UpdatedMint, HapiContractError::InvalidToken),
+    (ErrorCode::Unauthorized, HapiContractError::Unauthorized),
+    (ErrorCode::InvalidUUID, HapiContractError::InvalidData),
+    (ErrorCode::InvalidData, HapiContractError::InvalidData),
+    (ErrorCode::CaseClosed, HapiContractError::CaseClosed),
+    (ErrorCode::CaseMismatch, HapiContractError::CaseMismatch),
+    (ErrorCode::RiskOutOfRange, HapiContractError::RiskOutOfRange),
+];
+
+/// Replaces a failed transaction error with the program error it carries, if any
+pub(super) fn map_program_error(e: ClientError) -> ClientError {
+    let transaction_error = match &e {
+        ClientError::SolanaRpcError(e) => e.get_transaction_error(),
+        _ => None,
+    };
+
+    match transaction_error
+        .as_ref()
+        .and_then(decode_transaction_error)
+    {
+        Some(error) => error.into(),
+        None => e,
+    }
+}
+
+fn decode_transaction_error(e: &TransactionError) -> Option<HapiContractError> {
+    match e {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => PROGRAM_ERRORS
+            .iter()
+            .find(|(error, _)| u32::from(*error) == *code)
+            .map(|(_, error)| error.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_transaction_error() {
+        let e = TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(ErrorCode::CaseClosed.into()),
+        );
+
+        assert_eq!(
+            decode_transaction_error(&e),
+            Some(HapiContractError::CaseClosed)
+        );
+
+        let e = TransactionError::InstructionError(0, InstructionError::Custom(0));
+        assert_eq!(decode_transaction_error(&e), None);
+
+        assert_eq!(
+            decode_transaction_error(&TransactionError::AccountNotFound),
+            None
+        );
+    }
+}
@@ -56,7 +56,7 @@ impl HapiCoreSolana {
             .map_err(|e| ClientError::InstructionDecodingError(e.to_string()))?)
     }
 
-    fn decode_transaction(
+    pub(super) fn decode_transaction(
         &self,
         tx: EncodedConfirmedTransactionWithStatusMeta,
     ) -> Result<Vec<DecodedInstruction>> {
@@ -213,18 +213,25 @@ fn decode_instruction_data(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{client::solana::test_helpers::*, HapiCoreNetwork, HapiCoreOptions};
+    use crate::{
+        client::solana::test_helpers::*, Commitment, FeeOptions, HapiCoreNetwork, HapiCoreOptions,
+        TransportOptions,
+    };
 
     const PROGRAM_ID: &str = "39WzZqJgkK2QuQxV9jeguKRgHE65Q3HywqPwBzdrKn2B";
 
     fn get_cli(program_id: Option<String>) -> HapiCoreSolana {
         HapiCoreSolana::new(HapiCoreOptions {
-            provider_url: String::default(),
+            provider_urls: vec![String::default()],
             contract_address: program_id.unwrap_or(PROGRAM_ID.to_string()),
             private_key: None,
             chain_id: None,
             account_id: None,
             network: HapiCoreNetwork::Solana,
+            transport: TransportOptions::default(),
+            commitment: Commitment::default(),
+            fees: FeeOptions::default(),
+            nonce_account: None,
         })
         .expect("Failed to initialize client")
     }
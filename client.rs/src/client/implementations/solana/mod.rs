@@ -1,9 +1,12 @@
+mod account_cache;
 pub mod account_macro;
 mod client;
 mod conversion;
-mod instruction_data;
+mod error;
+pub mod instruction_data;
 pub mod instruction_decoder;
 pub mod token;
+mod transport;
 mod utils;
 
 pub mod test_helpers;
@@ -11,6 +14,7 @@ pub use test_helpers::create_test_tx;
 
 pub use client::HapiCoreSolana;
 pub use token::TokenContractSolana;
+pub use transport::FailoverSender;
 
 pub use instruction_data::{DecodedInstructionData, InstructionData};
 pub use instruction_decoder::DecodedInstruction;
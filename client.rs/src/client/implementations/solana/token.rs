@@ -3,7 +3,6 @@ use anchor_client::{
     solana_sdk::{
         pubkey::Pubkey,
         signature::{Keypair, Signer},
-        transaction::Transaction,
     },
 };
 
@@ -14,6 +13,8 @@ use std::str::FromStr;
 
 use crate::{
     client::{
+        commitment::Commitment,
+        fees::FeeOptions,
         interface::HapiCoreOptions,
         result::{ClientError, Result, Tx},
         token::TokenContract,
@@ -21,22 +22,43 @@ use crate::{
     Amount,
 };
 
-use super::utils::get_signer;
+use super::{
+    transport::{connect, DEFAULT_TIMEOUT},
+    utils::{
+        build_transaction, build_tx, get_commitment_config, get_confirmed_transaction, get_signer,
+        send_and_confirm_transaction,
+    },
+};
 
 pub struct TokenContractSolana {
     cli: RpcClient,
     signer: Keypair,
     mint: Pubkey,
+    commitment: Commitment,
+    fees: FeeOptions,
+    nonce_account: Option<Pubkey>,
 }
 
 impl TokenContractSolana {
     pub fn new(options: HapiCoreOptions) -> Result<Self> {
+        let cli = connect(&options, DEFAULT_TIMEOUT)?;
         let signer = get_signer(options.private_key)?;
-        let cli = RpcClient::new(options.provider_url);
         let mint = Pubkey::from_str(&options.contract_address)
             .map_err(|e| ClientError::SolanaAddressParseError(format!("`addr`: {e}")))?;
-
-        Ok(Self { cli, signer, mint })
+        let nonce_account = options
+            .nonce_account
+            .map(|nonce_account| nonce_account.parse::<Pubkey>())
+            .transpose()
+            .map_err(|e| ClientError::SolanaAddressParseError(format!("`nonce-account`: {e}")))?;
+
+        Ok(Self {
+            cli,
+            signer,
+            mint,
+            commitment: options.commitment,
+            fees: options.fees,
+            nonce_account,
+        })
     }
 }
 
@@ -60,25 +82,29 @@ impl TokenContract for TokenContractSolana {
             &to_ata,
             &from_pubkey,
             &[&from_pubkey],
-            amount.into(),
+            amount.try_into()?,
         )?;
 
-        let recent_blockhash = self.cli.get_latest_blockhash().await?;
-
-        let transfer_tx = Transaction::new_signed_with_payer(
+        let transfer_tx = build_transaction(
+            &self.cli,
             &[transfer_instruction],
-            Some(&from_pubkey),
-            &[&self.signer],
-            recent_blockhash,
-        );
-
-        let hash = self
-            .cli
-            .send_and_confirm_transaction_with_spinner(&transfer_tx)
-            .await?
-            .to_string();
-
-        Ok(Tx { hash })
+            &self.signer,
+            &self.fees,
+            self.nonce_account.as_ref(),
+            self.commitment,
+        )
+        .await?;
+
+        let signature = send_and_confirm_transaction(
+            &self.cli,
+            &transfer_tx,
+            self.commitment,
+            self.nonce_account.as_ref(),
+        )
+        .await?;
+        let tx = get_confirmed_transaction(&self.cli, &signature, self.commitment).await?;
+
+        Ok(build_tx(&signature, &tx, vec![]))
     }
 
     async fn approve(&self, _spender: &str, _amount: Amount) -> Result<Tx> {
@@ -90,11 +116,25 @@ impl TokenContract for TokenContractSolana {
             .map_err(|e| ClientError::SolanaAddressParseError(format!("`addr`: {e}")))?;
         let ata = get_associated_token_address(&owner, &self.mint);
 
-        let balance = self.cli.get_token_account_balance(&ata).await?.amount;
+        let balance = self
+            .cli
+            .get_token_account_balance_with_commitment(&ata, get_commitment_config(self.commitment))
+            .await?
+            .value
+            .amount;
 
         let res = Amount::from_str(balance.as_str())
             .map_err(|e| ClientError::FailedToParseBalance(e.to_string()))?;
 
         Ok(res)
     }
+
+    async fn decimals(&self) -> Result<u8> {
+        Ok(self
+            .cli
+            .get_token_supply_with_commitment(&self.mint, get_commitment_config(self.commitment))
+            .await?
+            .value
+            .decimals)
+    }
 }
@@ -0,0 +1,95 @@
+use {
+    anchor_client::{
+        solana_client::{
+            client_error::{ClientError as SolanaClientError, ClientErrorKind},
+            http_sender::HttpSender,
+            nonblocking::rpc_client::RpcClient,
+            rpc_client::RpcClientConfig,
+            rpc_request::{RpcError, RpcRequest},
+            rpc_sender::{RpcSender, RpcTransportStats},
+        },
+        solana_sdk::commitment_config::CommitmentConfig,
+    },
+    async_trait::async_trait,
+    std::{sync::Arc, time::Duration},
+};
+
+use crate::client::{
+    interface::HapiCoreOptions,
+    result::Result,
+    transport::{is_transient_status, Endpoints},
+};
+
+pub(super) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Returned by RPC nodes that are behind the cluster
+const NODE_UNHEALTHY_RPC_CODE: i64 = -32005;
+
+/// RPC sender over multiple endpoints with retries and failover
+pub struct FailoverSender {
+    endpoints: Endpoints<Arc<HttpSender>>,
+}
+
+impl FailoverSender {
+    pub fn new(options: &HapiCoreOptions, timeout: Duration) -> Result<Self> {
+        let senders = options
+            .provider_urls
+            .iter()
+            .map(|url| Arc::new(HttpSender::new_with_timeout(url, timeout)))
+            .collect();
+
+        Ok(Self {
+            endpoints: Endpoints::new(senders, options.transport.clone())?,
+        })
+    }
+}
+
+#[async_trait]
+impl RpcSender for FailoverSender {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> std::result::Result<serde_json::Value, SolanaClientError> {
+        self.endpoints
+            .request(
+                |sender| {
+                    let params = params.clone();
+                    async move { sender.send(request, params).await }
+                },
+                is_transient,
+            )
+            .await
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.endpoints.current().get_transport_stats()
+    }
+
+    fn url(&self) -> String {
+        self.endpoints.current().url()
+    }
+}
+
+fn is_transient(e: &SolanaClientError) -> bool {
+    match e.kind() {
+        ClientErrorKind::Io(_) => true,
+        ClientErrorKind::Reqwest(e) => {
+            e.is_timeout()
+                || e.is_connect()
+                || e.status()
+                    .map_or(false, |status| is_transient_status(status.as_u16()))
+        }
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => {
+            *code == NODE_UNHEALTHY_RPC_CODE
+        }
+        _ => false,
+    }
+}
+
+pub(super) fn connect(options: &HapiCoreOptions, timeout: Duration) -> Result<RpcClient> {
+    Ok(RpcClient::new_sender(
+        FailoverSender::new(options, timeout)?,
+        RpcClientConfig::with_commitment(CommitmentConfig::default()),
+    ))
+}
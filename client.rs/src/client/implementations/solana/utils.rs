@@ -1,16 +1,94 @@
-use std::{io::Write, str::FromStr};
+use std::{io::Write, ops::Range, str::FromStr, time::Duration};
+use tokio::time::{sleep, Instant};
 use uuid::Uuid;
 
 use anchor_client::{
-    anchor_lang::solana_program::bpf_loader_upgradeable,
+    anchor_lang::{solana_program::bpf_loader_upgradeable, Discriminator},
+    solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_config::{RpcSendTransactionConfig, RpcTransactionConfig},
+        rpc_filter::{Memcmp, RpcFilterType},
+    },
     solana_sdk::{
+        account_utils::StateMut,
+        commitment_config::CommitmentConfig,
+        compute_budget::ComputeBudgetInstruction,
+        hash::Hash,
+        instruction::Instruction,
+        message::Message,
+        nonce::state::{State as NonceState, Versions as NonceVersions},
+        packet::PACKET_DATA_SIZE,
         pubkey::Pubkey,
-        signature::{read_keypair_file, Keypair},
+        signature::{read_keypair_file, Keypair, Signature, Signer},
+        system_instruction,
+        transaction::Transaction,
     },
 };
 use solana_cli_config::{Config, CONFIG_FILE};
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding};
+
+use crate::client::{
+    commitment::Commitment,
+    events::EventName,
+    fees::{ComputeUnitPrice, FeeOptions},
+    result::{ClientError, Result, Tx},
+};
+
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Pending transactions are resent, as RPC nodes drop them under load
+const RESEND_INTERVAL: Duration = Duration::from_secs(2);
+
+// Percentile of the recent prioritization fees that the compute unit price is estimated at
+const PRIORITIZATION_FEE_PERCENTILE: usize = 75;
 
-use crate::client::result::{ClientError, Result};
+// Largest number of accounts in a getRecentPrioritizationFees request
+const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+// Account data starts with the discriminator, version and bump
+const ACCOUNT_HEADER_LEN: usize = 8 + 2 + 1;
+
+/// Program account that belongs to a network
+pub trait NetworkAccount {
+    /// Offset of the network public key in the account data
+    const NETWORK_OFFSET: usize;
+}
+
+// Network follows the reporter UUID
+impl NetworkAccount for hapi_core_solana::Reporter {
+    const NETWORK_OFFSET: usize = ACCOUNT_HEADER_LEN + 16;
+}
+
+// Network follows the case UUID
+impl NetworkAccount for hapi_core_solana::Case {
+    const NETWORK_OFFSET: usize = ACCOUNT_HEADER_LEN + 16;
+}
+
+impl NetworkAccount for hapi_core_solana::Address {
+    const NETWORK_OFFSET: usize = ACCOUNT_HEADER_LEN;
+}
+
+impl NetworkAccount for hapi_core_solana::Asset {
+    const NETWORK_OFFSET: usize = ACCOUNT_HEADER_LEN;
+}
+
+/// Filters of the program accounts of the type that belong to the network
+pub(super) fn get_network_account_filters<T>(
+    data_size: usize,
+    network: &Pubkey,
+) -> Vec<RpcFilterType>
+where
+    T: Discriminator + NetworkAccount,
+{
+    vec![
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &T::discriminator())),
+        RpcFilterType::DataSize((data_size + hapi_core_solana::ACCOUNT_RESERVE_SPACE) as u64),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            T::NETWORK_OFFSET,
+            network.as_ref(),
+        )),
+    ]
+}
 
 pub fn get_signer(private_key: Option<String>) -> Result<Keypair> {
     if let Some(pk) = private_key {
@@ -134,3 +212,771 @@ pub fn byte_array_from_str(data: &str, bytes: &mut [u8]) -> Result<()> {
 
     Ok(())
 }
+
+/// Commitment that accounts are read at
+pub(super) fn get_commitment_config(commitment: Commitment) -> CommitmentConfig {
+    match commitment {
+        Commitment::Processed => CommitmentConfig::processed(),
+        Commitment::Confirmed => CommitmentConfig::confirmed(),
+        Commitment::Finalized | Commitment::Confirmations(_) => CommitmentConfig::finalized(),
+    }
+}
+
+// Transactions can't be fetched before they are confirmed, so writes wait for at least that
+fn get_confirmation_config(commitment: Commitment) -> CommitmentConfig {
+    match get_commitment_config(commitment) {
+        config if config.is_at_least_confirmed() => config,
+        _ => CommitmentConfig::confirmed(),
+    }
+}
+
+/// Signs the instructions with the latest blockhash, or with the durable nonce if the nonce
+/// account is set, and adds the compute budget instructions of the fee options
+pub(super) async fn build_transaction(
+    rpc_client: &RpcClient,
+    instructions: &[Instruction],
+    signer: &Keypair,
+    fees: &FeeOptions,
+    nonce_account: Option<&Pubkey>,
+    commitment: Commitment,
+) -> Result<Transaction> {
+    let payer = signer.pubkey();
+    let mut tx_instructions = vec![];
+
+    // Nonce advance has to be the first instruction of the transaction
+    let blockhash = match nonce_account {
+        Some(nonce_account) => {
+            tx_instructions.push(system_instruction::advance_nonce_account(
+                nonce_account,
+                &payer,
+            ));
+
+            get_durable_nonce(rpc_client, nonce_account, commitment).await?
+        }
+        None => {
+            rpc_client
+                .get_latest_blockhash_with_commitment(get_commitment_config(commitment))
+                .await?
+                .0
+        }
+    };
+
+    tx_instructions.extend(get_compute_budget_instructions(rpc_client, instructions, fees).await?);
+    tx_instructions.extend_from_slice(instructions);
+
+    Ok(Transaction::new_signed_with_payer(
+        &tx_instructions,
+        Some(&payer),
+        &[signer],
+        blockhash,
+    ))
+}
+
+/// Compute unit limit and price instructions of the fee options
+pub(super) async fn get_compute_budget_instructions(
+    rpc_client: &RpcClient,
+    instructions: &[Instruction],
+    fees: &FeeOptions,
+) -> Result<Vec<Instruction>> {
+    let mut budget_instructions = vec![];
+
+    if let Some(limit) = fees.compute_unit_limit {
+        budget_instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(limit));
+    }
+
+    let price = match fees.compute_unit_price {
+        Some(ComputeUnitPrice::Fixed(price)) => Some(price),
+        Some(ComputeUnitPrice::Auto) => {
+            let price = estimate_compute_unit_price(rpc_client, instructions).await?;
+
+            Some(
+                fees.max_compute_unit_price
+                    .map_or(price, |cap| price.min(cap)),
+            )
+        }
+        None => None,
+    };
+
+    if let Some(price) = price.filter(|price| *price > 0) {
+        budget_instructions.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+    }
+
+    Ok(budget_instructions)
+}
+
+/// Instructions that `build_transaction` adds besides the program ones, used for sizing
+pub(super) fn get_fixed_instructions(
+    payer: &Pubkey,
+    fees: &FeeOptions,
+    nonce_account: Option<&Pubkey>,
+) -> Vec<Instruction> {
+    let mut instructions = vec![];
+
+    if let Some(nonce_account) = nonce_account {
+        instructions.push(system_instruction::advance_nonce_account(
+            nonce_account,
+            payer,
+        ));
+    }
+
+    if let Some(limit) = fees.compute_unit_limit {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(limit));
+    }
+
+    // Instruction size doesn't depend on the price
+    if fees.compute_unit_price.is_some() {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(1));
+    }
+
+    instructions
+}
+
+/// Size of the signed transaction with the instructions in bytes
+fn get_transaction_size(instructions: &[Instruction], payer: &Pubkey) -> usize {
+    let message = Message::new(instructions, Some(payer));
+    let signatures = message.header.num_required_signatures as usize;
+
+    // Signature count is a compact-u16, which takes one byte below 128 signatures
+    1 + signatures * 64 + message.serialize().len()
+}
+
+/// Groups consecutive operations into transactions that fit into a packet
+///
+/// Returns the range of operations of each transaction. An operation that doesn't fit even
+/// alone is sent in a transaction of its own, which the cluster rejects.
+pub(super) fn pack_instructions(
+    operations: &[Vec<Instruction>],
+    payer: &Pubkey,
+    fixed_instructions: &[Instruction],
+) -> Vec<Range<usize>> {
+    let mut groups = vec![];
+    let mut start = 0;
+    let mut instructions = fixed_instructions.to_vec();
+
+    for (index, operation) in operations.iter().enumerate() {
+        instructions.extend_from_slice(operation);
+
+        if index > start && get_transaction_size(&instructions, payer) > PACKET_DATA_SIZE {
+            groups.push(start..index);
+            start = index;
+
+            instructions.truncate(fixed_instructions.len());
+            instructions.extend_from_slice(operation);
+        }
+    }
+
+    if start < operations.len() {
+        groups.push(start..operations.len());
+    }
+
+    groups
+}
+
+// Prioritization fees are local to the written accounts, so only those are requested
+async fn estimate_compute_unit_price(
+    rpc_client: &RpcClient,
+    instructions: &[Instruction],
+) -> Result<u64> {
+    let mut accounts: Vec<Pubkey> = instructions
+        .iter()
+        .flat_map(|instruction| &instruction.accounts)
+        .filter(|meta| meta.is_writable)
+        .map(|meta| meta.pubkey)
+        .collect();
+
+    accounts.sort_unstable();
+    accounts.dedup();
+    accounts.truncate(MAX_PRIORITIZATION_FEE_ACCOUNTS);
+
+    let mut fees: Vec<u64> = rpc_client
+        .get_recent_prioritization_fees(&accounts)
+        .await?
+        .into_iter()
+        .map(|fee| fee.prioritization_fee)
+        .collect();
+
+    fees.sort_unstable();
+
+    Ok(fees
+        .get(fees.len() * PRIORITIZATION_FEE_PERCENTILE / 100)
+        .copied()
+        .unwrap_or_default())
+}
+
+/// Blockhash stored in the durable nonce account
+async fn get_durable_nonce(
+    rpc_client: &RpcClient,
+    nonce_account: &Pubkey,
+    commitment: Commitment,
+) -> Result<Hash> {
+    let account = rpc_client
+        .get_account_with_commitment(nonce_account, get_commitment_config(commitment))
+        .await?
+        .value
+        .ok_or_else(|| {
+            ClientError::InvalidData(format!("Nonce account {nonce_account} not found"))
+        })?;
+
+    let versions: NonceVersions = account.state().map_err(|e| {
+        ClientError::InvalidData(format!("Invalid nonce account {nonce_account}: {e}"))
+    })?;
+
+    match versions.state() {
+        NonceState::Initialized(data) => Ok(data.blockhash()),
+        NonceState::Uninitialized => Err(ClientError::InvalidData(format!(
+            "Nonce account {nonce_account} is not initialized"
+        ))),
+    }
+}
+
+// Transactions expire with their blockhash, or once the durable nonce has been advanced
+async fn is_expired(
+    rpc_client: &RpcClient,
+    tx: &Transaction,
+    nonce_account: Option<&Pubkey>,
+) -> Result<bool> {
+    match nonce_account {
+        Some(nonce_account) => {
+            Ok(
+                get_durable_nonce(rpc_client, nonce_account, Commitment::Processed).await?
+                    != tx.message.recent_blockhash,
+            )
+        }
+        None => Ok(!rpc_client
+            .is_blockhash_valid(&tx.message.recent_blockhash, CommitmentConfig::processed())
+            .await?),
+    }
+}
+
+/// Sends the transaction and waits until it reaches the commitment
+///
+/// The transaction is resent until it lands or expires.
+pub(super) async fn send_and_confirm_transaction(
+    rpc_client: &RpcClient,
+    tx: &Transaction,
+    commitment: Commitment,
+    nonce_account: Option<&Pubkey>,
+) -> Result<Signature> {
+    let config = RpcSendTransactionConfig {
+        preflight_commitment: Some(get_commitment_config(commitment).commitment),
+        ..RpcSendTransactionConfig::default()
+    };
+
+    let signature = rpc_client.send_transaction_with_config(tx, config).await?;
+
+    let confirmation = get_confirmation_config(commitment);
+    let mut sent_at = Instant::now();
+
+    loop {
+        match rpc_client
+            .get_signature_status_with_commitment(&signature, confirmation)
+            .await?
+        {
+            Some(Ok(())) => return Ok(signature),
+            Some(Err(e)) => return Err(ClientError::SolanaRpcError(e.into())),
+            None => {
+                if is_expired(rpc_client, tx, nonce_account).await? {
+                    // A landed transaction is awaited until it reaches the commitment
+                    if rpc_client
+                        .get_signature_status_with_commitment(
+                            &signature,
+                            CommitmentConfig::processed(),
+                        )
+                        .await?
+                        .is_none()
+                    {
+                        return Err(ClientError::TimeoutError(format!(
+                            "Transaction {signature} expired before it was {}",
+                            confirmation.commitment
+                        )));
+                    }
+                } else if sent_at.elapsed() >= RESEND_INTERVAL {
+                    // Resending errors are ignored, the transaction may have landed meanwhile
+                    let _ = rpc_client
+                        .send_transaction_with_config(
+                            tx,
+                            RpcSendTransactionConfig {
+                                skip_preflight: true,
+                                ..config
+                            },
+                        )
+                        .await;
+
+                    sent_at = Instant::now();
+                }
+
+                sleep(CONFIRMATION_POLL_INTERVAL).await;
+            }
+        }
+    }
+}
+
+/// Fetches a confirmed transaction in the encoding expected by the instruction decoder
+pub(super) async fn get_confirmed_transaction(
+    rpc_client: &RpcClient,
+    signature: &Signature,
+    commitment: Commitment,
+) -> Result<EncodedConfirmedTransactionWithStatusMeta> {
+    Ok(rpc_client
+        .get_transaction_with_config(
+            signature,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Json),
+                commitment: Some(get_confirmation_config(commitment)),
+                max_supported_transaction_version: Some(0),
+            },
+        )
+        .await?)
+}
+
+/// Builds the receipt of a confirmed transaction
+pub(super) fn build_tx(
+    signature: &Signature,
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    events: Vec<EventName>,
+) -> Tx {
+    let meta = tx.transaction.meta.as_ref();
+
+    Tx {
+        hash: signature.to_string(),
+        block: tx.slot,
+        timestamp: tx.block_time.unwrap_or_default() as u64,
+        fee: meta.map_or(0, |meta| meta.fee).into(),
+        gas_used: meta
+            .and_then(|meta| Option::from(meta.compute_units_consumed.clone()))
+            .unwrap_or_default(),
+        success: meta.map_or(false, |meta| meta.err.is_none()),
+        events,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::anchor_lang::AccountSerialize;
+    use anchor_client::{
+        solana_client::{
+            client_error::ClientError as SolanaClientError,
+            rpc_client::RpcClientConfig,
+            rpc_request::RpcRequest,
+            rpc_sender::{RpcSender, RpcTransportStats},
+        },
+        solana_sdk::{
+            account::{Account, AccountSharedData},
+            instruction::AccountMeta,
+            nonce::state::{Data as NonceData, DurableNonce},
+            system_program,
+        },
+    };
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use solana_account_decoder::{UiAccount, UiAccountEncoding};
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    type Handler = Box<dyn Fn(RpcRequest, &Value, usize) -> Value + Send + Sync>;
+    type RequestCounts = Arc<Mutex<HashMap<RpcRequest, usize>>>;
+
+    /// RPC sender with scripted responses, the handler gets the number of requests of the kind
+    struct TestSender {
+        handler: Handler,
+        counts: RequestCounts,
+    }
+
+    #[async_trait]
+    impl RpcSender for TestSender {
+        async fn send(
+            &self,
+            request: RpcRequest,
+            params: Value,
+        ) -> std::result::Result<Value, SolanaClientError> {
+            let count = {
+                let mut counts = self.counts.lock().unwrap();
+                let count = counts.entry(request).or_default();
+                *count += 1;
+                *count
+            };
+
+            Ok(match request {
+                RpcRequest::GetVersion => json!({ "solana-core": "1.17.0" }),
+                request => (self.handler)(request, &params, count),
+            })
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            RpcTransportStats::default()
+        }
+
+        fn url(&self) -> String {
+            "test".to_string()
+        }
+    }
+
+    fn get_test_client(
+        handler: impl Fn(RpcRequest, &Value, usize) -> Value + Send + Sync + 'static,
+    ) -> (RpcClient, RequestCounts) {
+        let counts = RequestCounts::default();
+        let sender = TestSender {
+            handler: Box::new(handler),
+            counts: counts.clone(),
+        };
+
+        (
+            RpcClient::new_sender(
+                sender,
+                RpcClientConfig::with_commitment(CommitmentConfig::confirmed()),
+            ),
+            counts,
+        )
+    }
+
+    fn request_count(counts: &RequestCounts, request: RpcRequest) -> usize {
+        counts
+            .lock()
+            .unwrap()
+            .get(&request)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn with_context(value: Value) -> Value {
+        json!({ "context": { "slot": 1 }, "value": value })
+    }
+
+    fn nonce_account(durable_nonce: DurableNonce, authority: Pubkey) -> Value {
+        let versions = NonceVersions::new(NonceState::Initialized(NonceData::new(
+            authority,
+            durable_nonce,
+            5000,
+        )));
+        let account = Account::new_data(1_000_000, &versions, &system_program::id()).unwrap();
+
+        with_context(json!(UiAccount::encode(
+            &Pubkey::new_unique(),
+            &account,
+            UiAccountEncoding::Base64,
+            None,
+            None,
+        )))
+    }
+
+    fn signature_status(landed: bool) -> Value {
+        let status = landed.then(|| {
+            json!({
+                "slot": 1,
+                "confirmations": null,
+                "status": { "Ok": null },
+                "err": null,
+                "confirmationStatus": "finalized",
+            })
+        });
+
+        with_context(json!([status]))
+    }
+
+    fn test_transaction(signer: &Keypair) -> Transaction {
+        Transaction::new_signed_with_payer(
+            &[Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[1],
+                vec![],
+            )],
+            Some(&signer.pubkey()),
+            &[signer],
+            Hash::new_unique(),
+        )
+    }
+
+    #[test]
+    fn test_pack_instructions() {
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+
+        let operation = |size: usize| {
+            vec![Instruction::new_with_bytes(
+                program_id,
+                &vec![0; size],
+                vec![],
+            )]
+        };
+
+        assert!(pack_instructions(&[], &payer, &[]).is_empty());
+
+        let operations = vec![
+            operation(500),
+            operation(500),
+            operation(500),
+            operation(2000),
+        ];
+
+        assert_eq!(
+            pack_instructions(&operations, &payer, &[]),
+            vec![0..2, 2..3, 3..4]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compute_budget_instructions() {
+        let (rpc_client, counts) = get_test_client(|request, params, _| match request {
+            RpcRequest::GetRecentPrioritizationFees => {
+                // Only the written accounts are requested
+                assert_eq!(params[0].as_array().unwrap().len(), 1);
+
+                json!([
+                    { "slot": 1, "prioritizationFee": 100 },
+                    { "slot": 2, "prioritizationFee": 400 },
+                    { "slot": 3, "prioritizationFee": 200 },
+                    { "slot": 4, "prioritizationFee": 300 },
+                ])
+            }
+            request => panic!("unexpected request: {request:?}"),
+        });
+
+        let instruction = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            vec![
+                AccountMeta::new(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+        );
+
+        let fees = FeeOptions {
+            compute_unit_limit: Some(200_000),
+            compute_unit_price: Some(ComputeUnitPrice::Auto),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_compute_budget_instructions(&rpc_client, &[instruction.clone()], &fees)
+                .await
+                .unwrap(),
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                ComputeBudgetInstruction::set_compute_unit_price(400),
+            ]
+        );
+
+        let capped = FeeOptions {
+            max_compute_unit_price: Some(350),
+            ..fees.clone()
+        };
+
+        assert_eq!(
+            get_compute_budget_instructions(&rpc_client, &[instruction.clone()], &capped)
+                .await
+                .unwrap()[1],
+            ComputeBudgetInstruction::set_compute_unit_price(350)
+        );
+
+        // Sizing accounts for the same instructions
+        assert_eq!(
+            get_fixed_instructions(&Pubkey::new_unique(), &fees, None).len(),
+            2
+        );
+
+        let free = FeeOptions {
+            compute_unit_price: Some(ComputeUnitPrice::Fixed(0)),
+            ..Default::default()
+        };
+
+        assert!(
+            get_compute_budget_instructions(&rpc_client, &[instruction], &free)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            request_count(&counts, RpcRequest::GetRecentPrioritizationFees),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_durable_nonce_transaction() {
+        let signer = Keypair::new();
+        let authority = signer.pubkey();
+        let nonce_address = Pubkey::new_unique();
+        let durable_nonce = DurableNonce::from_blockhash(&Hash::new_unique());
+
+        let (rpc_client, _) = get_test_client(move |request, _, count| match request {
+            // The nonce is advanced after the transaction is built and checked once
+            RpcRequest::GetAccountInfo if count <= 2 => nonce_account(durable_nonce, authority),
+            RpcRequest::GetAccountInfo => {
+                nonce_account(DurableNonce::from_blockhash(&Hash::new_unique()), authority)
+            }
+            request => panic!("unexpected request: {request:?}"),
+        });
+
+        let instruction = Instruction::new_with_bytes(Pubkey::new_unique(), &[1], vec![]);
+        let tx = build_transaction(
+            &rpc_client,
+            &[instruction],
+            &signer,
+            &FeeOptions::default(),
+            Some(&nonce_address),
+            Commitment::Confirmed,
+        )
+        .await
+        .unwrap();
+
+        assert!(tx.verify().is_ok());
+        assert_eq!(tx.message.recent_blockhash, *durable_nonce.as_hash());
+        assert_eq!(tx.message.instructions.len(), 2);
+
+        let advance = system_instruction::advance_nonce_account(&nonce_address, &authority);
+        let first = &tx.message.instructions[0];
+
+        assert_eq!(
+            tx.message.account_keys[first.program_id_index as usize],
+            advance.program_id
+        );
+        assert_eq!(first.data, advance.data);
+
+        assert!(!is_expired(&rpc_client, &tx, Some(&nonce_address))
+            .await
+            .unwrap());
+        assert!(is_expired(&rpc_client, &tx, Some(&nonce_address))
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_transaction_resend() {
+        let signer = Keypair::new();
+        let tx = test_transaction(&signer);
+        let signature = tx.signatures[0];
+
+        // The transaction lands only after it is resent
+        let (rpc_client, counts) = get_test_client(move |request, _, count| match request {
+            RpcRequest::SendTransaction => json!(signature.to_string()),
+            RpcRequest::GetSignatureStatuses => signature_status(count > 6),
+            RpcRequest::IsBlockhashValid => with_context(json!(true)),
+            request => panic!("unexpected request: {request:?}"),
+        });
+
+        assert_eq!(
+            send_and_confirm_transaction(&rpc_client, &tx, Commitment::Confirmed, None)
+                .await
+                .unwrap(),
+            signature
+        );
+        assert_eq!(request_count(&counts, RpcRequest::SendTransaction), 2);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_expired() {
+        let signer = Keypair::new();
+        let tx = test_transaction(&signer);
+        let signature = tx.signatures[0];
+
+        let (rpc_client, counts) = get_test_client(move |request, _, _| match request {
+            RpcRequest::SendTransaction => json!(signature.to_string()),
+            RpcRequest::GetSignatureStatuses => signature_status(false),
+            RpcRequest::IsBlockhashValid => with_context(json!(false)),
+            request => panic!("unexpected request: {request:?}"),
+        });
+
+        let e = send_and_confirm_transaction(&rpc_client, &tx, Commitment::Confirmed, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e, ClientError::TimeoutError(_)));
+        assert_eq!(request_count(&counts, RpcRequest::SendTransaction), 1);
+    }
+
+    // Serialized account of the allocated size
+    fn account_data<T: AccountSerialize>(account: &T, data_size: usize) -> AccountSharedData {
+        let mut data = vec![];
+        account.try_serialize(&mut data).unwrap();
+        data.resize(data_size + hapi_core_solana::ACCOUNT_RESERVE_SPACE, 0);
+
+        AccountSharedData::from(Account {
+            lamports: 1,
+            data,
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        })
+    }
+
+    fn check_network_filters<T>(data_size: usize, account: impl Fn(Pubkey) -> T)
+    where
+        T: AccountSerialize + Discriminator + NetworkAccount,
+    {
+        let network = Pubkey::new_unique();
+        let filters = get_network_account_filters::<T>(data_size, &network);
+
+        let allowed = |account: &AccountSharedData| filters.iter().all(|f| f.allows(account));
+
+        assert!(allowed(&account_data(&account(network), data_size)));
+        assert!(!allowed(&account_data(
+            &account(Pubkey::new_unique()),
+            data_size
+        )));
+        assert!(!allowed(&account_data(&account(network), data_size + 1)));
+    }
+
+    #[test]
+    fn test_network_account_filters() {
+        check_network_filters(hapi_core_solana::Reporter::LEN, |network| {
+            hapi_core_solana::Reporter {
+                version: 1,
+                bump: 255,
+                id: u128::MAX,
+                network,
+                account: Pubkey::new_unique(),
+                name: "reporter".to_string(),
+                role: Default::default(),
+                status: Default::default(),
+                stake: u64::MAX,
+                unlock_timestamp: 0,
+                url: "https://reporter.blockchain".to_string(),
+            }
+        });
+
+        check_network_filters(hapi_core_solana::Case::LEN, |network| {
+            hapi_core_solana::Case {
+                version: 1,
+                bump: 255,
+                id: u128::MAX,
+                network,
+                name: "case".to_string(),
+                reporter_id: u128::MAX,
+                status: Default::default(),
+                url: "https://case.blockchain".to_string(),
+            }
+        });
+
+        check_network_filters(hapi_core_solana::Address::LEN, |network| {
+            hapi_core_solana::Address {
+                version: 1,
+                bump: 255,
+                network,
+                address: [1; 64],
+                category: Default::default(),
+                risk_score: 10,
+                case_id: u128::MAX,
+                reporter_id: u128::MAX,
+                confirmations: 0,
+            }
+        });
+
+        check_network_filters(hapi_core_solana::Asset::LEN, |network| {
+            hapi_core_solana::Asset {
+                version: 1,
+                bump: 255,
+                network,
+                address: [1; 64],
+                id: [2; 32],
+                category: Default::default(),
+                risk_score: 10,
+                case_id: u128::MAX,
+                reporter_id: u128::MAX,
+                confirmations: 0,
+            }
+        });
+    }
+}
@@ -1,6 +1,8 @@
 use async_trait::async_trait;
 
 use super::{
+    batch::{Batch, BatchResult},
+    commitment::Commitment,
     configuration::{RewardConfiguration, StakeConfiguration},
     entities::{
         address::{Address, ConfirmAddressInput, CreateAddressInput, UpdateAddressInput},
@@ -9,13 +11,18 @@ use super::{
         network::HapiCoreNetwork,
         reporter::{CreateReporterInput, Reporter, UpdateReporterInput},
     },
+    fees::FeeOptions,
     result::{Result, Tx},
+    transport::TransportOptions,
 };
 
 #[async_trait]
 pub trait HapiCore {
     fn is_valid_address(&self, address: &str) -> Result<()>;
 
+    /// Returns a client that reads and waits for transactions at the given commitment
+    fn with_commitment(&self, commitment: Commitment) -> Box<dyn HapiCore>;
+
     async fn set_authority(&self, address: &str) -> Result<Tx>;
     async fn get_authority(&self) -> Result<String>;
 
@@ -54,14 +61,23 @@ pub trait HapiCore {
     async fn get_asset(&self, addr: &str, id: &AssetId) -> Result<Asset>;
     async fn get_asset_count(&self) -> Result<u64>;
     async fn get_assets(&self, skip: u64, take: u64) -> Result<Vec<Asset>>;
+
+    /// Sends the operations of the batch in as few transactions as possible
+    async fn send_batch(&self, batch: Batch) -> Result<BatchResult>;
 }
 
 #[derive(Clone)]
 pub struct HapiCoreOptions {
-    pub provider_url: String,
+    pub provider_urls: Vec<String>,
     pub contract_address: String,
     pub private_key: Option<String>,
     pub chain_id: Option<u64>,
     pub account_id: Option<String>,
     pub network: HapiCoreNetwork,
+    pub transport: TransportOptions,
+    pub commitment: Commitment,
+    pub fees: FeeOptions,
+    /// Durable nonce account that Solana transactions are signed with instead of a blockhash,
+    /// they are still sent right away as offline signing is not supported
+    pub nonce_account: Option<String>,
 }
@@ -1,10 +1,15 @@
 pub mod amount;
+pub mod batch;
+pub mod commitment;
 pub mod configuration;
+pub mod contract_error;
 pub mod entities;
 pub mod events;
+pub mod fees;
 pub mod implementations;
 pub mod interface;
 pub mod result;
 pub mod token;
+pub mod transport;
 
 pub use implementations::*;
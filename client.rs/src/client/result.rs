@@ -1,8 +1,11 @@
 use anchor_client::solana_sdk::signature::ParseSignatureError;
 use near_jsonrpc_client::methods::broadcast_tx_async::RpcBroadcastTxAsyncError;
 use near_jsonrpc_primitives::types::{query::RpcQueryError, transactions::RpcTransactionError};
+use serde::Serialize;
 use thiserror::Error;
 
+use super::{amount::Amount, contract_error::HapiContractError, events::EventName};
+
 #[derive(Error, Debug)]
 pub enum ClientError {
     #[error("URL parse error: {0}")]
@@ -11,10 +14,14 @@ pub enum ClientError {
     AssetIdParseError(String),
     #[error("Invalid data: {0}")]
     InvalidData(String),
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
     #[error("Failed to parse balance: {0}")]
     FailedToParseBalance(String),
     #[error("The reporter does not exist")]
     InvalidReporter,
+    #[error("Contract error: {0}")]
+    Contract(#[from] HapiContractError),
 
     // Ethereum client errors
     #[error("Invalid UUID: {0}")]
@@ -45,6 +52,8 @@ pub enum ClientError {
     DeserializationError(#[from] serde_json::Error),
     #[error("RpcTransactionError error: {0}")]
     RpcTransactionError(#[from] near_jsonrpc_client::errors::JsonRpcError<RpcTransactionError>),
+    #[error("Batch of {0} operations exceeds the limit of {1} for a transaction")]
+    BatchTooLarge(usize, usize),
 
     // Solana client errors
     #[error("Solana address parse error: {0}")]
@@ -75,7 +84,21 @@ pub enum ClientError {
 
 pub type Result<T> = std::result::Result<T, ClientError>;
 
-#[derive(Default, Clone, Debug)]
+/// Receipt of an executed transaction
+#[derive(Default, Clone, Debug, Serialize)]
 pub struct Tx {
+    /// Transaction hash, or signature on Solana
     pub hash: String,
+    /// Block number, or slot on Solana
+    pub block: u64,
+    /// Block timestamp in seconds
+    pub timestamp: u64,
+    /// Fee paid in the smallest units of the native token
+    pub fee: Amount,
+    /// Gas used, or compute units consumed on Solana
+    pub gas_used: u64,
+    /// Whether the transaction was executed successfully
+    pub success: bool,
+    /// HAPI events produced by the transaction
+    pub events: Vec<EventName>,
 }
@@ -18,4 +18,7 @@ pub trait TokenContract {
 
     /// Get the amount of tokens on this address
     async fn balance(&self, addr: &str) -> Result<Amount>;
+
+    /// Get the number of decimals of the token
+    async fn decimals(&self) -> Result<u8>;
 }
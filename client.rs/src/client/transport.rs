@@ -0,0 +1,251 @@
+use {
+    rand::Rng,
+    std::{
+        future::Future,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    },
+    tokio::{
+        sync::Mutex,
+        time::{sleep, sleep_until, Instant},
+    },
+};
+
+use super::result::{ClientError, Result};
+
+pub const DEFAULT_MAX_RETRIES: u32 = 5;
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Retry and rate limit settings of the provider endpoints
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportOptions {
+    /// Number of retries of a request after transient errors, over all endpoints
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled with every next retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the backoff
+    pub max_backoff: Duration,
+    /// Largest number of requests per second to a single endpoint, unlimited if not set
+    pub rate_limit: Option<u32>,
+}
+
+impl Default for TransportOptions {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            rate_limit: None,
+        }
+    }
+}
+
+/// Spaces requests to an endpoint evenly to keep within the rate limit
+#[derive(Debug)]
+struct RateLimiter {
+    interval: Duration,
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second.max(1),
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    async fn wait(&self) {
+        // The lock is held while sleeping, so waiting requests are served in order
+        let mut next = self.next.lock().await;
+
+        sleep_until(*next).await;
+        *next = Instant::now() + self.interval;
+    }
+}
+
+#[derive(Debug)]
+struct Endpoint<T> {
+    client: T,
+    limiter: Option<RateLimiter>,
+}
+
+/// Provider endpoints with retries, failover and rate limiting
+///
+/// Requests go to the current endpoint. A transient error moves the current endpoint to the next one
+/// and the request is retried there after a jittered exponential backoff.
+#[derive(Debug)]
+pub struct Endpoints<T> {
+    endpoints: Vec<Endpoint<T>>,
+    current: AtomicUsize,
+    options: TransportOptions,
+}
+
+impl<T: Clone> Endpoints<T> {
+    pub fn new(clients: Vec<T>, options: TransportOptions) -> Result<Self> {
+        if clients.is_empty() {
+            return Err(ClientError::UrlParseError(
+                "`provider-url`: at least one URL is required".to_string(),
+            ));
+        }
+
+        let endpoints = clients
+            .into_iter()
+            .map(|client| Endpoint {
+                client,
+                limiter: options.rate_limit.map(RateLimiter::new),
+            })
+            .collect();
+
+        Ok(Self {
+            endpoints,
+            current: AtomicUsize::new(0),
+            options,
+        })
+    }
+
+    /// Client of the current endpoint
+    pub fn current(&self) -> &T {
+        &self.endpoints[self.current_index()].client
+    }
+
+    /// Sends the request, retrying errors for which `is_transient` holds
+    pub async fn request<R, E, F, Fut>(
+        &self,
+        send: F,
+        is_transient: fn(&E) -> bool,
+    ) -> std::result::Result<R, E>
+    where
+        F: Fn(T) -> Fut,
+        Fut: Future<Output = std::result::Result<R, E>>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            let index = self.current_index();
+            let endpoint = &self.endpoints[index];
+
+            if let Some(limiter) = &endpoint.limiter {
+                limiter.wait().await;
+            }
+
+            match send(endpoint.client.clone()).await {
+                Err(e) if attempt < self.options.max_retries && is_transient(&e) => {
+                    // Concurrent requests failing on the same endpoint move it only once
+                    let next = (index + 1) % self.endpoints.len();
+                    let _ = self.current.compare_exchange(
+                        index,
+                        next,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    );
+
+                    sleep(self.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn current_index(&self) -> usize {
+        self.current.load(Ordering::Relaxed) % self.endpoints.len()
+    }
+
+    // Half of the exponential backoff is fixed and half is random
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .options
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.options.max_backoff);
+
+        let half = backoff / 2;
+        let jitter = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+
+        half + Duration::from_millis(jitter)
+    }
+}
+
+/// Whether the HTTP status is worth a retry
+pub(crate) fn is_transient_status(status: u16) -> bool {
+    status == 429 || (500..600).contains(&status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoints(max_retries: u32) -> Endpoints<&'static str> {
+        Endpoints::new(
+            vec!["first", "second"],
+            TransportOptions {
+                max_retries,
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(1),
+                rate_limit: None,
+            },
+        )
+        .expect("Failed to create endpoints")
+    }
+
+    #[tokio::test]
+    async fn test_failover_on_transient_error() {
+        let endpoints = endpoints(1);
+
+        let result = endpoints
+            .request(
+                |endpoint| async move {
+                    match endpoint {
+                        "first" => Err(503),
+                        endpoint => Ok(endpoint),
+                    }
+                },
+                |status: &u16| is_transient_status(*status),
+            )
+            .await;
+
+        assert_eq!(result, Ok("second"));
+        assert_eq!(*endpoints.current(), "second");
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_permanent_error() {
+        let endpoints = endpoints(1);
+
+        let result: std::result::Result<(), u16> = endpoints
+            .request(
+                |_| async { Err(400) },
+                |status| is_transient_status(*status),
+            )
+            .await;
+
+        assert_eq!(result, Err(400));
+        assert_eq!(*endpoints.current(), "first");
+    }
+
+    #[tokio::test]
+    async fn test_retries_are_limited() {
+        let endpoints = endpoints(3);
+        let attempts = AtomicUsize::new(0);
+
+        let result: std::result::Result<(), u16> = endpoints
+            .request(
+                |_| {
+                    attempts.fetch_add(1, Ordering::Relaxed);
+                    async { Err(429) }
+                },
+                |status| is_transient_status(*status),
+            )
+            .await;
+
+        assert_eq!(result, Err(429));
+        assert_eq!(attempts.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn test_empty_endpoints() {
+        assert!(Endpoints::<&str>::new(vec![], TransportOptions::default()).is_err());
+    }
+}
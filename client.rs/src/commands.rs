@@ -11,6 +11,7 @@ use hapi_core::{
             case::{CreateCaseInput, UpdateCaseInput},
             reporter::{CreateReporterInput, UpdateReporterInput},
         },
+        token::TokenContract,
     },
     Amount,
 };
@@ -18,6 +19,7 @@ use hapi_core::{
 mod context;
 mod matcher;
 
+use context::get_token_contract;
 pub(crate) use context::{CommandOutput, HapiCoreCommandContext, TokenCommandContext};
 pub(crate) use matcher::matcher;
 
@@ -69,35 +71,24 @@ pub async fn update_stake_configuration(args: &ArgMatches) -> anyhow::Result<()>
         .is_valid_address(&token)
         .map_err(|e| anyhow!("Invalid address in `token`: {e}"))?;
 
+    let decimals = match args.get_flag("token-units") {
+        true => Some(get_decimals(get_token_contract(args, &token)?.as_ref()).await?),
+        false => None,
+    };
+
     let unlock_duration = args
         .get_one::<String>("unlock-duration")
         .ok_or(anyhow!("`unlock-duration` is required"))?
         .parse()
         .map_err(|e| anyhow!("`unlock-duration`: {e}"))?;
 
-    let validator_stake = args
-        .get_one::<String>("validator-stake")
-        .ok_or(anyhow!("`validator-stake` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`validator-stake`: {e}"))?;
+    let validator_stake = parse_amount(args, "validator-stake", decimals)?;
 
-    let tracer_stake = args
-        .get_one::<String>("tracer-stake")
-        .ok_or(anyhow!("`tracer-stake` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`tracer-stake`: {e}"))?;
+    let tracer_stake = parse_amount(args, "tracer-stake", decimals)?;
 
-    let publisher_stake = args
-        .get_one::<String>("publisher-stake")
-        .ok_or(anyhow!("`publisher-stake` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`publisher-stake`: {e}"))?;
+    let publisher_stake = parse_amount(args, "publisher-stake", decimals)?;
 
-    let authority_stake = args
-        .get_one::<String>("authority-stake")
-        .ok_or(anyhow!("`authority-stake` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`authority-stake`: {e}"))?;
+    let authority_stake = parse_amount(args, "authority-stake", decimals)?;
 
     let cfg = StakeConfiguration {
         token,
@@ -146,29 +137,18 @@ pub async fn update_reward_configuration(args: &ArgMatches) -> anyhow::Result<()
         .is_valid_address(&token)
         .map_err(|e| anyhow!("Invalid address in `token`: {e}"))?;
 
-    let address_confirmation_reward = args
-        .get_one::<String>("address-confirmation-reward")
-        .ok_or(anyhow!("`address-confirmation-reward` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`address-confirmation-reward`: {e}"))?;
+    let decimals = match args.get_flag("token-units") {
+        true => Some(get_decimals(get_token_contract(args, &token)?.as_ref()).await?),
+        false => None,
+    };
 
-    let asset_tracer_reward = args
-        .get_one::<String>("asset-tracer-reward")
-        .ok_or(anyhow!("`asset-tracer-reward` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`asset-tracer-reward`: {e}"))?;
+    let address_confirmation_reward = parse_amount(args, "address-confirmation-reward", decimals)?;
 
-    let asset_confirmation_reward = args
-        .get_one::<String>("asset-confirmation-reward")
-        .ok_or(anyhow!("`asset-confirmation-reward` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`asset-confirmation-reward`: {e}"))?;
+    let asset_tracer_reward = parse_amount(args, "asset-tracer-reward", decimals)?;
 
-    let address_tracer_reward = args
-        .get_one::<String>("address-tracer-reward")
-        .ok_or(anyhow!("`address-tracer-reward` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`address-tracer-reward`: {e}"))?;
+    let asset_confirmation_reward = parse_amount(args, "asset-confirmation-reward", decimals)?;
+
+    let address_tracer_reward = parse_amount(args, "address-tracer-reward", decimals)?;
 
     let cfg = RewardConfiguration {
         token,
@@ -960,11 +940,12 @@ pub async fn transfer_token(args: &ArgMatches) -> anyhow::Result<()> {
         .get_one::<String>("to")
         .ok_or(anyhow!("`to` is required"))?;
 
-    let amount: Amount = args
-        .get_one::<String>("amount")
-        .ok_or(anyhow!("`amount` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`amount`: {}", e))?;
+    let decimals = match args.get_flag("token-units") {
+        true => Some(get_decimals(context.token.as_ref()).await?),
+        false => None,
+    };
+
+    let amount = parse_amount(args, "amount", decimals)?;
 
     let tx = context.token.transfer(to, amount).await?;
 
@@ -983,11 +964,12 @@ pub async fn approve_token(args: &ArgMatches) -> anyhow::Result<()> {
         .get_one::<String>("spender")
         .ok_or(anyhow!("`spender` is required"))?;
 
-    let amount: Amount = args
-        .get_one::<String>("amount")
-        .ok_or(anyhow!("`amount` is required"))?
-        .parse()
-        .map_err(|e| anyhow!("`amount`: {}", e))?;
+    let decimals = match args.get_flag("token-units") {
+        true => Some(get_decimals(context.token.as_ref()).await?),
+        false => None,
+    };
+
+    let amount = parse_amount(args, "amount", decimals)?;
 
     let tx = context.token.approve(spender, amount).await?;
 
@@ -1008,6 +990,11 @@ pub async fn balance_token(args: &ArgMatches) -> anyhow::Result<()> {
 
     let balance = context.token.balance(address).await?;
 
+    let balance = match args.get_flag("token-units") {
+        true => balance.to_decimal_string(get_decimals(context.token.as_ref()).await?)?,
+        false => balance.to_string(),
+    };
+
     match context.output {
         CommandOutput::Json => println!("{}", json!({ "balance": balance })),
         CommandOutput::Plain => println!("{}", balance),
@@ -1015,3 +1002,24 @@ pub async fn balance_token(args: &ArgMatches) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Parses the amount in base units, or in whole tokens of the decimals, like `1.5`
+fn parse_amount(args: &ArgMatches, name: &str, decimals: Option<u8>) -> anyhow::Result<Amount> {
+    let amount = args
+        .get_one::<String>(name)
+        .ok_or(anyhow!("`{name}` is required"))?;
+
+    match decimals {
+        Some(decimals) => {
+            Amount::from_decimal_str(amount, decimals).map_err(|e| anyhow!("`{name}`: {e}"))
+        }
+        None => amount.parse().map_err(|e| anyhow!("`{name}`: {e}")),
+    }
+}
+
+async fn get_decimals(token: &dyn TokenContract) -> anyhow::Result<u8> {
+    token
+        .decimals()
+        .await
+        .map_err(|e| anyhow!("Failed to get token decimals: {e}"))
+}
@@ -1,10 +1,10 @@
 use clap::ArgMatches;
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use hapi_core::{
     client::{implementations::TokenContractSolana, token::TokenContract},
-    HapiCore, HapiCoreEvm, HapiCoreNear, HapiCoreNetwork, HapiCoreOptions, HapiCoreSolana,
-    TokenContractEvm, TokenContractNear,
+    Commitment, HapiCore, HapiCoreEvm, HapiCoreNear, HapiCoreNetwork, HapiCoreOptions,
+    HapiCoreSolana, TokenContractEvm, TokenContractNear, TransportOptions,
 };
 
 #[derive(Default)]
@@ -40,23 +40,11 @@ impl TryFrom<&ArgMatches> for TokenCommandContext {
     type Error = anyhow::Error;
 
     fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
-        let network: HapiCoreNetwork = matches
-            .get_one::<String>("network")
-            .ok_or(anyhow::anyhow!("`network` is required"))?
-            .parse()
-            .map_err(|e| anyhow::anyhow!("Failed to parse `network`: {:?}", e))?;
-
-        let provider_url = matches
-            .get_one::<String>("provider-url")
-            .ok_or(anyhow::anyhow!("`provider-url` is required"))?
-            .to_owned();
-
         let contract_address = matches
             .get_one::<String>("token-contract")
-            .ok_or(anyhow::anyhow!("`token-contract` is required"))?
-            .to_owned();
+            .ok_or(anyhow::anyhow!("`token-contract` is required"))?;
 
-        let private_key: Option<String> = matches.get_one::<String>("private-key").cloned();
+        let token = get_token_contract(matches, contract_address)?;
 
         let output: CommandOutput = matches
             .get_one::<String>("output")
@@ -64,29 +52,59 @@ impl TryFrom<&ArgMatches> for TokenCommandContext {
             .parse()
             .map_err(|e| anyhow::anyhow!("Failed to parse `output`: {:?}", e))?;
 
-        let options = HapiCoreOptions {
-            provider_url,
-            contract_address,
-            private_key,
-            chain_id: None,
-            account_id: None,
-            network: network.clone(),
-        };
-
-        let token: Box<dyn TokenContract> = match network {
-            HapiCoreNetwork::Sepolia | HapiCoreNetwork::Ethereum | HapiCoreNetwork::Bsc => {
-                Box::new(TokenContractEvm::new(options)?)
-            }
-            HapiCoreNetwork::Solana | HapiCoreNetwork::Bitcoin => {
-                Box::new(TokenContractSolana::new(options)?)
-            }
-            HapiCoreNetwork::Near => Box::new(TokenContractNear::new(options)?),
-        };
-
         Ok(Self { token, output })
     }
 }
 
+/// Token contract at the address, connected with the network options of the command
+pub(crate) fn get_token_contract(
+    matches: &ArgMatches,
+    contract_address: &str,
+) -> anyhow::Result<Box<dyn TokenContract>> {
+    let network: HapiCoreNetwork = matches
+        .get_one::<String>("network")
+        .ok_or(anyhow::anyhow!("`network` is required"))?
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Failed to parse `network`: {:?}", e))?;
+
+    let provider_urls = get_provider_urls(matches)?;
+
+    let transport = get_transport_options(matches)?;
+
+    let commitment = get_commitment(matches)?;
+
+    let fees = get_fee_options(matches)?;
+
+    let nonce_account = matches.get_one::<String>("nonce-account").cloned();
+
+    let private_key: Option<String> = matches.get_one::<String>("private-key").cloned();
+
+    let options = HapiCoreOptions {
+        provider_urls,
+        contract_address: contract_address.to_owned(),
+        private_key,
+        chain_id: None,
+        account_id: None,
+        network: network.clone(),
+        transport,
+        commitment,
+        fees,
+        nonce_account,
+    };
+
+    let token: Box<dyn TokenContract> = match network {
+        HapiCoreNetwork::Sepolia | HapiCoreNetwork::Ethereum | HapiCoreNetwork::Bsc => {
+            Box::new(TokenContractEvm::new(options)?)
+        }
+        HapiCoreNetwork::Solana | HapiCoreNetwork::Bitcoin => {
+            Box::new(TokenContractSolana::new(options)?)
+        }
+        HapiCoreNetwork::Near => Box::new(TokenContractNear::new(options)?),
+    };
+
+    Ok(token)
+}
+
 impl TryFrom<&ArgMatches> for HapiCoreCommandContext {
     type Error = anyhow::Error;
 
@@ -97,10 +115,15 @@ impl TryFrom<&ArgMatches> for HapiCoreCommandContext {
             .parse()
             .map_err(|e| anyhow::anyhow!("Failed to parse `network`: {:?}", e))?;
 
-        let provider_url = matches
-            .get_one::<String>("provider-url")
-            .ok_or(anyhow::anyhow!("`provider-url` is required"))?
-            .to_owned();
+        let provider_urls = get_provider_urls(matches)?;
+
+        let transport = get_transport_options(matches)?;
+
+        let commitment = get_commitment(matches)?;
+
+        let fees = get_fee_options(matches)?;
+
+        let nonce_account = matches.get_one::<String>("nonce-account").cloned();
 
         let contract_address = matches
             .get_one::<String>("contract-address")
@@ -132,12 +155,16 @@ impl TryFrom<&ArgMatches> for HapiCoreCommandContext {
             .transpose()?;
 
         let options = HapiCoreOptions {
-            provider_url,
+            provider_urls,
             contract_address,
             private_key,
             chain_id,
             account_id,
             network: network.clone(),
+            transport,
+            commitment,
+            fees,
+            nonce_account,
         };
 
         let hapi_core: Box<dyn HapiCore> = match network {
@@ -153,3 +180,125 @@ impl TryFrom<&ArgMatches> for HapiCoreCommandContext {
         Ok(Self { hapi_core, output })
     }
 }
+
+fn get_provider_urls(matches: &ArgMatches) -> anyhow::Result<Vec<String>> {
+    let provider_urls: Vec<String> = matches
+        .get_many::<String>("provider-url")
+        .ok_or(anyhow::anyhow!("`provider-url` is required"))?
+        .map(|url| url.trim().to_owned())
+        .filter(|url| !url.is_empty())
+        .collect();
+
+    if provider_urls.is_empty() {
+        anyhow::bail!("`provider-url` is required");
+    }
+
+    Ok(provider_urls)
+}
+
+fn get_transport_options(matches: &ArgMatches) -> anyhow::Result<TransportOptions> {
+    let mut transport = TransportOptions::default();
+
+    if let Some(max_retries) = matches.get_one::<String>("max-retries") {
+        transport.max_retries = max_retries
+            .parse()
+            .map_err(|e| anyhow::anyhow!("`max-retries`: {e}"))?;
+    }
+
+    transport.rate_limit = matches
+        .get_one::<String>("rate-limit")
+        .map(|s| {
+            s.parse::<u32>()
+                .map_err(|e| anyhow::anyhow!("`rate-limit`: {e}"))
+        })
+        .transpose()?;
+
+    Ok(transport)
+}
+
+fn get_commitment(matches: &ArgMatches) -> anyhow::Result<Commitment> {
+    matches
+        .get_one::<String>("commitment")
+        .map(|s| s.parse().map_err(|e| anyhow::anyhow!("`commitment`: {e}")))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+fn get_fee_options(matches: &ArgMatches) -> anyhow::Result<FeeOptions> {
+    let mut fees = FeeOptions::default();
+
+    if let Some(strategy) = matches.get_one::<String>("fee-strategy") {
+        fees.strategy = strategy
+            .parse()
+            .map_err(|e| anyhow::anyhow!("`fee-strategy`: {e}"))?;
+    }
+
+    if let Some(multiplier) = get_multiplier(matches, "gas-multiplier")? {
+        fees.gas_multiplier = multiplier;
+    }
+
+    if let Some(multiplier) = get_multiplier(matches, "fee-multiplier")? {
+        fees.fee_multiplier = multiplier;
+    }
+
+    fees.max_fee_per_gas = get_gwei(matches, "max-fee-per-gas")?;
+    fees.max_priority_fee_per_gas = get_gwei(matches, "max-priority-fee-per-gas")?;
+
+    fees.replacement_timeout = matches
+        .get_one::<String>("replacement-timeout")
+        .map(|s| {
+            s.parse::<u64>()
+                .map(Duration::from_secs)
+                .map_err(|e| anyhow::anyhow!("`replacement-timeout`: {e}"))
+        })
+        .transpose()?;
+
+    fees.compute_unit_limit = matches
+        .get_one::<String>("compute-unit-limit")
+        .map(|s| {
+            s.parse::<u32>()
+                .map_err(|e| anyhow::anyhow!("`compute-unit-limit`: {e}"))
+        })
+        .transpose()?;
+
+    fees.compute_unit_price = matches
+        .get_one::<String>("compute-unit-price")
+        .map(|s| {
+            s.parse()
+                .map_err(|e| anyhow::anyhow!("`compute-unit-price`: {e}"))
+        })
+        .transpose()?;
+
+    fees.max_compute_unit_price = matches
+        .get_one::<String>("max-compute-unit-price")
+        .map(|s| {
+            s.parse::<u64>()
+                .map_err(|e| anyhow::anyhow!("`max-compute-unit-price`: {e}"))
+        })
+        .transpose()?;
+
+    Ok(fees)
+}
+
+fn get_multiplier(matches: &ArgMatches, name: &str) -> anyhow::Result<Option<f64>> {
+    matches
+        .get_one::<String>(name)
+        .map(|s| match s.parse::<f64>() {
+            Ok(multiplier) if multiplier.is_finite() && multiplier > 0.0 => Ok(multiplier),
+            Ok(_) => Err(anyhow::anyhow!("`{name}`: must be a positive number")),
+            Err(e) => Err(anyhow::anyhow!("`{name}`: {e}")),
+        })
+        .transpose()
+}
+
+// Fee caps are given in gwei and kept in wei
+fn get_gwei(matches: &ArgMatches, name: &str) -> anyhow::Result<Option<u128>> {
+    matches
+        .get_one::<String>(name)
+        .map(|s| match s.parse::<f64>() {
+            Ok(gwei) if gwei.is_finite() && gwei >= 0.0 => Ok((gwei * 1e9).round() as u128),
+            Ok(_) => Err(anyhow::anyhow!("`{name}`: must be a non-negative number")),
+            Err(e) => Err(anyhow::anyhow!("`{name}`: {e}")),
+        })
+        .transpose()
+}
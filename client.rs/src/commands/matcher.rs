@@ -1,4 +1,4 @@
-use clap::{Arg, ArgGroup, ArgMatches, Command};
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command};
 use std::process::exit;
 
 pub(crate) fn matcher() -> ArgMatches {
@@ -23,7 +23,134 @@ pub(crate) fn matcher() -> ArgMatches {
                 .short('p')
                 .value_name("PROVIDER_URL")
                 .env("PROVIDER_URL")
-                .help("Network-specific provider URL (e.g. RPC node URL)"),
+                .value_delimiter(',')
+                .help("Comma-separated network-specific provider URLs (e.g. RPC node URLs)"),
+        )
+        .arg(
+            Arg::new("max-retries")
+                .global(true)
+                .long("max-retries")
+                .value_name("MAX_RETRIES")
+                .env("MAX_RETRIES")
+                .required(false)
+                .help("[OPTIONAL] Number of retries of a provider request after transient errors"),
+        )
+        .arg(
+            Arg::new("rate-limit")
+                .global(true)
+                .long("rate-limit")
+                .value_name("RATE_LIMIT")
+                .env("RATE_LIMIT")
+                .required(false)
+                .help("[OPTIONAL] Largest number of requests per second to a single provider URL"),
+        )
+        .arg(
+            Arg::new("commitment")
+                .global(true)
+                .long("commitment")
+                .value_name("COMMITMENT")
+                .env("COMMITMENT")
+                .required(false)
+                .help("[OPTIONAL] Commitment of reads and writes: processed (default), confirmed, finalized or a number of confirmations"),
+        )
+        .arg(
+            Arg::new("fee-strategy")
+                .global(true)
+                .long("fee-strategy")
+                .value_name("FEE_STRATEGY")
+                .env("FEE_STRATEGY")
+                .required(false)
+                .value_parser(["legacy", "eip1559"])
+                .help("[OPTIONAL] Fee strategy of EVM transactions"),
+        )
+        .arg(
+            Arg::new("gas-multiplier")
+                .global(true)
+                .long("gas-multiplier")
+                .value_name("GAS_MULTIPLIER")
+                .env("GAS_MULTIPLIER")
+                .required(false)
+                .help("[OPTIONAL] Multiplier of the estimated gas limit of EVM transactions"),
+        )
+        .arg(
+            Arg::new("fee-multiplier")
+                .global(true)
+                .long("fee-multiplier")
+                .value_name("FEE_MULTIPLIER")
+                .env("FEE_MULTIPLIER")
+                .required(false)
+                .help("[OPTIONAL] Multiplier of the estimated fees of EVM transactions"),
+        )
+        .arg(
+            Arg::new("max-fee-per-gas")
+                .global(true)
+                .long("max-fee-per-gas")
+                .value_name("MAX_FEE_PER_GAS")
+                .env("MAX_FEE_PER_GAS")
+                .required(false)
+                .help("[OPTIONAL] Cap of the gas price or max fee per gas of EVM transactions in gwei"),
+        )
+        .arg(
+            Arg::new("max-priority-fee-per-gas")
+                .global(true)
+                .long("max-priority-fee-per-gas")
+                .value_name("FEE")
+                .env("MAX_PRIORITY_FEE_PER_GAS")
+                .required(false)
+                .help("[OPTIONAL] Cap of the priority fee per gas of EVM transactions in gwei"),
+        )
+        .arg(
+            Arg::new("replacement-timeout")
+                .global(true)
+                .long("replacement-timeout")
+                .value_name("SECONDS")
+                .env("REPLACEMENT_TIMEOUT")
+                .required(false)
+                .help("[OPTIONAL] Seconds after which a pending EVM transaction is replaced with bumped fees"),
+        )
+        .arg(
+            Arg::new("compute-unit-limit")
+                .global(true)
+                .long("compute-unit-limit")
+                .value_name("COMPUTE_UNIT_LIMIT")
+                .env("COMPUTE_UNIT_LIMIT")
+                .required(false)
+                .help("[OPTIONAL] Compute unit limit of Solana transactions"),
+        )
+        .arg(
+            Arg::new("compute-unit-price")
+                .global(true)
+                .long("compute-unit-price")
+                .value_name("COMPUTE_UNIT_PRICE")
+                .env("COMPUTE_UNIT_PRICE")
+                .required(false)
+                .help("[OPTIONAL] Compute unit price of Solana transactions in micro-lamports, or `auto` to estimate it from recent fees"),
+        )
+        .arg(
+            Arg::new("max-compute-unit-price")
+                .global(true)
+                .long("max-compute-unit-price")
+                .value_name("PRICE")
+                .env("MAX_COMPUTE_UNIT_PRICE")
+                .required(false)
+                .help("[OPTIONAL] Cap of the estimated compute unit price of Solana transactions in micro-lamports"),
+        )
+        .arg(
+            Arg::new("nonce-account")
+                .global(true)
+                .long("nonce-account")
+                .value_name("NONCE_ACCOUNT")
+                .env("NONCE_ACCOUNT")
+                .required(false)
+                .help("[OPTIONAL] Durable nonce account to sign Solana transactions with"),
+        )
+        .arg(
+            Arg::new("token-units")
+                .global(true)
+                .long("token-units")
+                .env("TOKEN_UNITS")
+                .action(ArgAction::SetTrue)
+                .help("[OPTIONAL] Amounts are in whole tokens, e.g. 1.5, instead of base units"),
         )
         .arg(
             Arg::new("contract-address")
@@ -2,11 +2,19 @@ pub mod client;
 
 pub use client::{
     amount::Amount,
+    batch::{Batch, BatchOperation, BatchResult},
+    commitment::Commitment,
+    contract_error::HapiContractError,
     entities::network::HapiCoreNetwork,
+    fees::{ComputeUnitPrice, FeeOptions, FeeStrategy},
     implementations::{
         HapiCoreEvm, HapiCoreNear, HapiCoreSolana, TokenContractEvm, TokenContractNear,
         TokenContractSolana,
     },
     interface::{HapiCore, HapiCoreOptions},
     token::TokenContract,
+    transport::TransportOptions,
 };
+
+#[cfg(feature = "mock")]
+pub use client::implementations::{HapiCoreMock, MockFixture};
@@ -3,6 +3,7 @@ pragma solidity ^0.8.22;
 
 import "@openzeppelin/contracts-upgradeable/access/OwnableUpgradeable.sol";
 import "@openzeppelin/contracts-upgradeable/access/AccessControlUpgradeable.sol";
+import "@openzeppelin/contracts-upgradeable/utils/MulticallUpgradeable.sol";
 import "@openzeppelin/contracts/token/ERC20/IERC20.sol";
 
 /**
@@ -10,8 +11,14 @@ import "@openzeppelin/contracts/token/ERC20/IERC20.sol";
  * @author HAPI Protocol development team
  *
  * Core contract for the HAPI protocol
+ *
+ * @dev Several calls can be made in a single atomic transaction with `multicall`
  */
-contract HapiCore is OwnableUpgradeable, AccessControlUpgradeable {
+contract HapiCore is
+    OwnableUpgradeable,
+    AccessControlUpgradeable,
+    MulticallUpgradeable
+{
     error AddressAlreadyConfirmed(address addr, uint128 reporter_id);
     error AddressNotFound(address addr);
     error AssetAlreadyConfirmed(
@@ -47,6 +54,7 @@ contract HapiCore is OwnableUpgradeable, AccessControlUpgradeable {
     function initialize() public initializer {
         __Ownable_init(_msgSender());
         __AccessControl_init();
+        __Multicall_init();
         _grantRole(DEFAULT_ADMIN_ROLE, _msgSender());
         _setRoleAdmin(DEFAULT_ADMIN_ROLE, AUTHORITY_ROLE);
         setAuthority(_msgSender());
@@ -0,0 +1,89 @@
+import { loadFixture } from "@nomicfoundation/hardhat-network-helpers";
+import { expect } from "chai";
+
+import { fixtureWithReporters } from "../setup";
+import { Category, CaseStatus, randomId } from "../util";
+
+describe("HapiCore: Multicall", function () {
+  it("Should create a case with its addresses in a single transaction", async function () {
+    const { hapiCore, wallets, reporters } = await loadFixture(
+      fixtureWithReporters
+    );
+
+    const case1 = {
+      id: randomId(),
+      name: "big hack 2023",
+      url: "https://big.hack",
+    };
+
+    const addresses = [
+      "0x9DDE9F8b85e4c4278545549e4eDF2E3E9d2c890E",
+      "0x922ffdfcb57de5dd6f641f275e98b684ce5576a3",
+    ];
+
+    await expect(
+      await hapiCore
+        .connect(wallets.publisher)
+        .multicall([
+          hapiCore.interface.encodeFunctionData("createCase", [
+            case1.id,
+            case1.name,
+            case1.url,
+          ]),
+          ...addresses.map((addr) =>
+            hapiCore.interface.encodeFunctionData("createAddress", [
+              addr,
+              case1.id,
+              5,
+              Category.Hacker,
+            ])
+          ),
+        ])
+    )
+      .to.emit(hapiCore, "CaseCreated")
+      .withArgs(case1.id)
+      .and.to.emit(hapiCore, "AddressCreated");
+
+    expect(await hapiCore.getCase(case1.id)).to.deep.equal([
+      case1.id,
+      case1.name,
+      reporters.publisher.id,
+      CaseStatus.Open,
+      case1.url,
+    ]);
+
+    expect(await hapiCore.getAddressCount()).to.equal(2);
+  });
+
+  it("Should revert all calls if one of them fails", async function () {
+    const { hapiCore, wallets } = await loadFixture(fixtureWithReporters);
+
+    const case1 = {
+      id: randomId(),
+      name: "big hack 2023",
+      url: "https://big.hack",
+    };
+
+    await expect(
+      hapiCore
+        .connect(wallets.publisher)
+        .multicall([
+          hapiCore.interface.encodeFunctionData("createCase", [
+            case1.id,
+            case1.name,
+            case1.url,
+          ]),
+          hapiCore.interface.encodeFunctionData("createAddress", [
+            "0x9DDE9F8b85e4c4278545549e4eDF2E3E9d2c890E",
+            case1.id,
+            11,
+            Category.Hacker,
+          ]),
+        ])
+    )
+      .to.be.revertedWithCustomError(hapiCore, "RiskOutOfRange")
+      .withArgs(11);
+
+    expect(await hapiCore.getCaseCount()).to.equal(0);
+  });
+});
@@ -1,11 +1,10 @@
 use {
     anyhow::{anyhow, bail, Result},
-    jsonwebtoken::{encode, EncodingKey, Header},
-    sea_orm::{Database, DatabaseConnection},
+    sea_orm::{Database, DatabaseConnection, EntityTrait},
     sea_orm_cli::MigrateSubcommands,
     sea_orm_migration::MigratorTrait,
-    secrecy::{ExposeSecret, SecretString},
-    std::net::SocketAddr,
+    secrecy::SecretString,
+    std::{net::SocketAddr, time::Duration},
     tokio::{sync::oneshot, task::JoinHandle},
     tracing::info,
     tracing::instrument,
@@ -14,19 +13,29 @@ use {
 
 use crate::{
     configuration::Configuration,
-    entity::{network, types::NetworkBackend},
+    entity::{
+        api_key,
+        indexer::HealthThresholds,
+        network,
+        pagination::MaxPageSize,
+        subscription::EntityUpdates,
+        types::{ApiKeyRole, NetworkBackend},
+    },
     migrations::Migrator,
-    observability::{update_network_metrics, MetricOp},
-    server::handlers::TokenClaims,
+    observability::{update_network_metrics, IndexerMonitor, MetricOp},
+    server::handlers::{create_indexer_token, StatsCache},
     service::{EntityMutation, EntityQuery},
 };
 
-const JWT_VALIDITY_DAYS: i64 = 365;
-
 #[derive(Clone)]
 pub struct AppState {
     pub database_conn: DatabaseConnection,
     pub jwt_secret: SecretString,
+    pub updates: EntityUpdates,
+    pub stats_cache: StatsCache,
+    pub require_api_key: bool,
+    pub max_page_size: MaxPageSize,
+    pub health_thresholds: HealthThresholds,
 }
 
 pub struct Application {
@@ -35,6 +44,8 @@ pub struct Application {
     pub state: AppState,
     pub shutdown_sender: Option<oneshot::Sender<()>>,
     pub server_handle: Option<JoinHandle<Result<()>>>,
+    indexer_monitor: Option<IndexerMonitor>,
+    monitor_handle: Option<JoinHandle<()>>,
 }
 
 impl Application {
@@ -44,8 +55,23 @@ impl Application {
         let state = AppState {
             database_conn,
             jwt_secret: configuration.jwt_secret.to_owned(),
+            updates: EntityUpdates::default(),
+            stats_cache: StatsCache::new(Duration::from_secs(configuration.stats_cache_ttl)),
+            require_api_key: configuration.require_api_key,
+            max_page_size: MaxPageSize(configuration.max_page_size),
+            health_thresholds: HealthThresholds {
+                lagging: configuration.indexer_lagging_threshold,
+                dead: configuration.indexer_dead_threshold,
+            },
         };
 
+        let indexer_monitor = IndexerMonitor::new(
+            state.database_conn.clone(),
+            state.health_thresholds,
+            Duration::from_secs(configuration.indexer_check_interval),
+            configuration.alert_webhook_url.clone(),
+        );
+
         info!("Application initialized");
 
         Ok(Self {
@@ -54,6 +80,8 @@ impl Application {
             state,
             shutdown_sender: None,
             server_handle: None,
+            indexer_monitor: Some(indexer_monitor),
+            monitor_handle: None,
         })
     }
 
@@ -150,29 +178,94 @@ impl Application {
         let now = chrono::Utc::now();
         let id = Uuid::new_v4();
 
-        EntityMutation::create_indexer(&self.state.database_conn, backend, chain_id, id, now)
-            .await?;
+        let indexer = EntityMutation::create_indexer(
+            &self.state.database_conn,
+            backend,
+            chain_id,
+            id,
+            now,
+            Uuid::new_v4(),
+        )
+        .await?;
 
-        let iat = now.timestamp() as usize;
-        let exp = (now + chrono::Duration::days(JWT_VALIDITY_DAYS)).timestamp() as usize;
-        let claims: TokenClaims = TokenClaims {
-            id: id.to_string(),
-            exp,
-            iat,
-        };
+        let token = create_indexer_token(&self.state.jwt_secret, &indexer)?;
 
-        let token = encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.state.jwt_secret.expose_secret().as_ref()),
-        )?;
+        tracing::info!("IndexerId: {}. Token: {}", id, token);
+
+        Ok(token)
+    }
+
+    /// Issues a new indexer token, previously issued tokens become invalid
+    #[instrument(level = "info", skip(self))]
+    pub async fn rotate_indexer_token(&self, id: Uuid) -> Result<String> {
+        let indexer =
+            EntityMutation::set_indexer_token(&self.state.database_conn, id, Some(Uuid::new_v4()))
+                .await?;
+
+        let token = create_indexer_token(&self.state.jwt_secret, &indexer)?;
 
         tracing::info!("IndexerId: {}. Token: {}", id, token);
 
         Ok(token)
     }
 
+    /// Revokes the indexer token, the indexer is rejected until the token is rotated
+    #[instrument(level = "info", skip(self))]
+    pub async fn revoke_indexer_token(&self, id: Uuid) -> Result<()> {
+        EntityMutation::set_indexer_token(&self.state.database_conn, id, None).await?;
+
+        tracing::info!("Token of indexer {} is revoked", id);
+
+        Ok(())
+    }
+
+    /// Creates an API key, the key is shown only once
+    #[instrument(level = "info", skip(self))]
+    pub async fn create_api_key(&self, name: String, role: ApiKeyRole) -> Result<String> {
+        let (api_key, key) =
+            EntityMutation::create_api_key(&self.state.database_conn, name, role).await?;
+
+        tracing::info!("ApiKeyId: {}. Role: {}. Key: {}", api_key.id, role, key);
+
+        Ok(key)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn list_api_keys(&self) -> Result<Vec<api_key::Model>> {
+        let api_keys = api_key::Entity::find()
+            .all(&self.state.database_conn)
+            .await?;
+
+        for api_key in &api_keys {
+            tracing::info!(
+                "ApiKeyId: {}. Name: {}. Role: {}. Created at: {}",
+                api_key.id,
+                api_key.name,
+                api_key.role,
+                api_key.created_at
+            );
+        }
+
+        Ok(api_keys)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn revoke_api_key(&self, id: Uuid) -> Result<()> {
+        if !EntityMutation::delete_api_key(&self.state.database_conn, id).await? {
+            bail!("API key {} does not exist", id);
+        }
+
+        tracing::info!("API key {} is revoked", id);
+
+        Ok(())
+    }
+
     pub async fn shutdown(&mut self) -> Result<()> {
+        // Stop the indexer monitor before its database connection is closed
+        if let Some(handle) = self.monitor_handle.take() {
+            handle.abort();
+        }
+
         // Close database connection
         self.state.database_conn.clone().close().await?;
 
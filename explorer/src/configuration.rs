@@ -6,6 +6,8 @@ use {
     std::env,
 };
 
+use crate::entity::pagination::DEFAULT_MAX_PAGE_SIZE;
+
 const CONFIG_PATH: &str = "configuration.toml";
 const SECRET_PATH: &str = "secret.toml";
 
@@ -37,6 +39,33 @@ pub struct Configuration {
 
     /// Origins to allow for CORS
     pub cors_origins: Option<Vec<String>>,
+
+    /// Time in seconds to cache the statistics endpoint responses
+    #[serde(default = "default_stats_cache_ttl")]
+    pub stats_cache_ttl: u64,
+
+    /// The largest page size of listing queries
+    #[serde(default = "default_max_page_size")]
+    pub max_page_size: u64,
+
+    /// Whether GraphQL requests without an API key are rejected
+    #[serde(default)]
+    pub require_api_key: bool,
+
+    /// Seconds since the last heartbeat after which an indexer is lagging
+    #[serde(default = "default_indexer_lagging_threshold")]
+    pub indexer_lagging_threshold: u64,
+
+    /// Seconds since the last heartbeat after which an indexer is dead
+    #[serde(default = "default_indexer_dead_threshold")]
+    pub indexer_dead_threshold: u64,
+
+    /// Interval in seconds between indexer health checks
+    #[serde(default = "default_indexer_check_interval")]
+    pub indexer_check_interval: u64,
+
+    /// URL to post alerts to when an indexer becomes lagging or dead
+    pub alert_webhook_url: Option<String>,
 }
 
 impl Default for Configuration {
@@ -49,6 +78,13 @@ impl Default for Configuration {
             database_url: String::new(),
             jwt_secret: default_jwt_secret(),
             cors_origins: None,
+            stats_cache_ttl: default_stats_cache_ttl(),
+            max_page_size: default_max_page_size(),
+            require_api_key: false,
+            indexer_lagging_threshold: default_indexer_lagging_threshold(),
+            indexer_dead_threshold: default_indexer_dead_threshold(),
+            indexer_check_interval: default_indexer_check_interval(),
+            alert_webhook_url: None,
         }
     }
 }
@@ -89,6 +125,26 @@ fn default_enable_metrics() -> bool {
     true
 }
 
+fn default_stats_cache_ttl() -> u64 {
+    60
+}
+
+fn default_max_page_size() -> u64 {
+    DEFAULT_MAX_PAGE_SIZE
+}
+
+fn default_indexer_lagging_threshold() -> u64 {
+    120
+}
+
+fn default_indexer_dead_threshold() -> u64 {
+    600
+}
+
+fn default_indexer_check_interval() -> u64 {
+    30
+}
+
 fn default_jwt_secret() -> SecretString {
     SecretString::new("my_ultra_secure_secret".to_string())
 }
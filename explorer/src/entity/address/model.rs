@@ -1,15 +1,23 @@
 use {
-    async_graphql::SimpleObject,
+    async_graphql::{ComplexObject, Context, Result, SimpleObject},
     hapi_core::client::entities::address::Address as AddressPayload,
-    sea_orm::{entity::prelude::*, NotSet, Set},
+    sea_orm::{entity::prelude::*, Condition, NotSet, Set},
+    serde::Serialize,
 };
 
 use super::query_utils::{AddressCondition, AddressFilter};
-use crate::entity::{case, reporter, types::Category, EntityFilter, FromPayload};
+use crate::entity::{
+    case,
+    loader::{CaseKey, EntityDataLoader, NetworkKey, ReporterKey},
+    network, reporter,
+    search::{hash_prefix_match, prefix_match, SearchTerm},
+    types::Category,
+    EntityFilter, FromPayload,
+};
 
 // Note: risk and confirmations do not correspond to the types of contracts (due to Postgresql restrictions)
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
-#[graphql(name = "Address")]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject, Serialize)]
+#[graphql(name = "Address", complex)]
 #[sea_orm(table_name = "address")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -23,6 +31,39 @@ pub struct Model {
     pub confirmations: String,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub overridden_at: Option<DateTime>,
+    #[graphql(skip)]
+    pub updated_block: i64,
+    #[graphql(skip)]
+    pub updated_block_index: i64,
+}
+
+#[ComplexObject]
+impl Model {
+    /// Case the address belongs to
+    async fn case(&self, ctx: &Context<'_>) -> Result<Option<case::Model>> {
+        let loader = ctx.data_unchecked::<EntityDataLoader>();
+
+        Ok(loader
+            .load_one(CaseKey(self.network_id.clone(), self.case_id))
+            .await?)
+    }
+
+    /// Reporter of the address
+    async fn reporter(&self, ctx: &Context<'_>) -> Result<Option<reporter::Model>> {
+        let loader = ctx.data_unchecked::<EntityDataLoader>();
+
+        Ok(loader
+            .load_one(ReporterKey(self.network_id.clone(), self.reporter_id))
+            .await?)
+    }
+
+    /// Network of the address
+    async fn network(&self, ctx: &Context<'_>) -> Result<Option<network::Model>> {
+        let loader = ctx.data_unchecked::<EntityDataLoader>();
+
+        Ok(loader.load_one(NetworkKey(self.network_id.clone())).await?)
+    }
 }
 
 impl EntityFilter for Entity {
@@ -60,17 +101,21 @@ impl EntityFilter for Entity {
         query
     }
 
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("network_id"),
-            String::from("address"),
-            String::from("case_id::text"),
-            String::from("reporter_id::text"),
-            String::from("risk::text"),
-            String::from("category::text"),
-            String::from("confirmations::text"),
-        ]
+    // Search query
+    fn search(selected: Select<Entity>, term: &SearchTerm) -> Select<Entity> {
+        match term {
+            SearchTerm::Uuid(id) => selected.filter(
+                Condition::any()
+                    .add(Column::CaseId.eq(*id))
+                    .add(Column::ReporterId.eq(*id)),
+            ),
+            SearchTerm::Hash(value) => selected.filter(hash_prefix_match(Column::Address, value)),
+            SearchTerm::Text(value) => selected.filter(
+                Condition::any()
+                    .add(hash_prefix_match(Column::Address, value))
+                    .add(prefix_match(Column::NetworkId, value)),
+            ),
+        }
     }
 }
 
@@ -124,6 +169,11 @@ impl FromPayload<AddressPayload> for ActiveModel {
             confirmations: Set(payload.confirmations.to_string()),
             created_at,
             updated_at,
+            // A chain update supersedes the manual correction
+            overridden_at: Set(None),
+            // Position of the event is set by the upsert
+            updated_block: NotSet,
+            updated_block_index: NotSet,
         }
     }
 }
@@ -10,7 +10,7 @@ use super::{
 };
 
 use crate::{
-    entity::pagination::{EntityInput, EntityPage},
+    entity::pagination::{EntityInput, EntityPage, MaxPageSize},
     service::EntityQuery,
 };
 
@@ -37,6 +37,19 @@ impl AddressQuery {
         Ok(address)
     }
 
+    /// Get the address on all networks
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn lookup_address(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address address")] address: String,
+    ) -> Result<Vec<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let addresses = EntityQuery::lookup_address(db, &address).await?;
+
+        Ok(addresses)
+    }
+
     /// Get multiple addresses
     #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
     pub async fn get_many_addresses(
@@ -48,7 +61,8 @@ impl AddressQuery {
         >,
     ) -> Result<EntityPage<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
-        let page = EntityQuery::find_many::<super::model::Entity>(db, input).await?;
+        let max_page_size = ctx.data_unchecked::<MaxPageSize>().0;
+        let page = EntityQuery::find_many::<super::model::Entity>(db, input, max_page_size).await?;
 
         Ok(page)
     }
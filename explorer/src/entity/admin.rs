@@ -0,0 +1,458 @@
+use {
+    async_graphql::{Context, InputObject, Object, Result, SimpleObject},
+    sea_orm::{ActiveModelTrait, ActiveValue::Set, DatabaseConnection},
+    secrecy::SecretString,
+    tracing::instrument,
+    uuid::Uuid,
+};
+
+use crate::{
+    entity::{
+        address, asset, case, indexer, network, reporter,
+        subscription::{EntityUpdate, EntityUpdates},
+        types::{ApiKeyRole, CaseStatus, Category, NetworkBackend, ReporterRole, ReporterStatus},
+    },
+    observability::{
+        update_address_metrics, update_asset_metrics, update_case_metrics, update_network_metrics,
+        update_reporter_metrics, MetricOp,
+    },
+    server::handlers::create_indexer_token,
+    service::{EntityMutation, EntityQuery},
+};
+
+// Risk scores are limited to 0..=10 by the contracts
+const MAX_RISK: u8 = 10;
+
+/// Network creation parameters
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+pub struct CreateNetworkInput {
+    pub id: String,
+    pub name: String,
+    pub backend: NetworkBackend,
+    pub chain_id: Option<String>,
+    pub authority: String,
+    pub stake_token: String,
+}
+
+/// Network update parameters, omitted fields are left unchanged
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+pub struct UpdateNetworkInput {
+    pub id: String,
+    pub name: Option<String>,
+    pub authority: Option<String>,
+    pub stake_token: Option<String>,
+}
+
+/// Address correction, omitted fields are left unchanged
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+pub struct AddressCorrection {
+    pub network_id: String,
+    pub address: String,
+    pub case_id: Option<Uuid>,
+    pub reporter_id: Option<Uuid>,
+    pub risk: Option<u8>,
+    pub category: Option<Category>,
+}
+
+/// Asset correction, omitted fields are left unchanged
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+pub struct AssetCorrection {
+    pub network_id: String,
+    pub address: String,
+    pub id: String,
+    pub case_id: Option<Uuid>,
+    pub reporter_id: Option<Uuid>,
+    pub risk: Option<u8>,
+    pub category: Option<Category>,
+}
+
+/// Case correction, omitted fields are left unchanged
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+pub struct CaseCorrection {
+    pub network_id: String,
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub status: Option<CaseStatus>,
+    pub reporter_id: Option<Uuid>,
+}
+
+/// Reporter correction, omitted fields are left unchanged
+#[derive(Clone, Eq, PartialEq, InputObject, Debug)]
+pub struct ReporterCorrection {
+    pub network_id: String,
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub role: Option<ReporterRole>,
+    pub status: Option<ReporterStatus>,
+}
+
+/// Created indexer with its token
+#[derive(Clone, Debug, SimpleObject)]
+pub struct IndexerToken {
+    pub id: Uuid,
+    pub network_id: String,
+    pub token: String,
+}
+
+/// The GraphQl Mutation segment, available only with an admin API key
+#[derive(Default)]
+pub struct AdminMutation {}
+
+/// Administrative mutations
+#[Object]
+impl AdminMutation {
+    /// Create a new network
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn create_network(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Network parameters")] input: CreateNetworkInput,
+    ) -> Result<network::Model> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let network = EntityMutation::create_network(
+            db,
+            input.id,
+            input.name,
+            input.backend,
+            input.chain_id,
+            input.authority,
+            input.stake_token,
+        )
+        .await?;
+
+        update_network_metrics(network.clone(), MetricOp::Increment);
+
+        Ok(network)
+    }
+
+    /// Update an existing network
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn update_network(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Network parameters")] input: UpdateNetworkInput,
+    ) -> Result<network::Model> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let old = EntityQuery::find_entity_by_id::<network::Entity, _>(db, input.id.clone())
+            .await?
+            .ok_or("Network not found")?;
+
+        let new = EntityMutation::update_network(
+            db,
+            input.id,
+            input.name,
+            input.authority,
+            input.stake_token,
+        )
+        .await?;
+
+        update_network_metrics(old, MetricOp::Decrement);
+        update_network_metrics(new.clone(), MetricOp::Increment);
+
+        Ok(new)
+    }
+
+    /// Delete a network, fails while the network still has entities or indexers
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn delete_network(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Network id")] id: String,
+    ) -> Result<network::Model> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let network = EntityMutation::delete_network(db, id)
+            .await?
+            .ok_or("Network not found")?;
+
+        update_network_metrics(network.clone(), MetricOp::Decrement);
+
+        Ok(network)
+    }
+
+    /// Create an indexer for the network and issue its token
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn create_indexer(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Network id")] network_id: String,
+    ) -> Result<IndexerToken> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let network = EntityQuery::find_entity_by_id::<network::Entity, _>(db, network_id)
+            .await?
+            .ok_or("Network not found")?;
+
+        let indexer = EntityMutation::create_indexer(
+            db,
+            network.backend,
+            network.chain_id,
+            Uuid::new_v4(),
+            chrono::Utc::now(),
+            Uuid::new_v4(),
+        )
+        .await?;
+
+        issue_token(ctx, indexer)
+    }
+
+    /// Issue a new indexer token, previously issued tokens become invalid
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn rotate_indexer_token(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Indexer id")] id: Uuid,
+    ) -> Result<IndexerToken> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let indexer = EntityMutation::set_indexer_token(db, id, Some(Uuid::new_v4())).await?;
+
+        issue_token(ctx, indexer)
+    }
+
+    /// Revoke the indexer token, the indexer is rejected until the token is rotated
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn revoke_indexer_token(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Indexer id")] id: Uuid,
+    ) -> Result<Uuid> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let indexer = EntityMutation::set_indexer_token(db, id, None).await?;
+
+        Ok(indexer.id)
+    }
+
+    /// Manually correct an address
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn update_address(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address correction")] input: AddressCorrection,
+    ) -> Result<address::Model> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let old = EntityQuery::find_entity_by_id::<address::Entity, _>(
+            db,
+            (input.network_id.clone(), input.address.clone()),
+        )
+        .await?
+        .ok_or("Address not found")?;
+
+        let mut model = address::ActiveModel {
+            network_id: Set(input.network_id),
+            address: Set(input.address),
+            overridden_at: Set(Some(chrono::Utc::now().naive_utc())),
+            ..Default::default()
+        };
+
+        if let Some(case_id) = input.case_id {
+            model.case_id = Set(case_id);
+        }
+        if let Some(reporter_id) = input.reporter_id {
+            model.reporter_id = Set(reporter_id);
+        }
+        if let Some(risk) = input.risk {
+            model.risk = Set(check_risk(risk)?.into());
+        }
+        if let Some(category) = input.category {
+            model.category = Set(category);
+        }
+
+        let new = model.update(db).await?;
+
+        update_address_metrics(old, MetricOp::Decrement);
+        update_address_metrics(new.clone(), MetricOp::Increment);
+        publish(ctx, EntityUpdate::Address(new.clone()));
+
+        Ok(new)
+    }
+
+    /// Manually correct an asset
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn update_asset(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset correction")] input: AssetCorrection,
+    ) -> Result<asset::Model> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let old = EntityQuery::find_entity_by_id::<asset::Entity, _>(
+            db,
+            (
+                input.network_id.clone(),
+                input.address.clone(),
+                input.id.clone(),
+            ),
+        )
+        .await?
+        .ok_or("Asset not found")?;
+
+        let mut model = asset::ActiveModel {
+            network_id: Set(input.network_id),
+            address: Set(input.address),
+            id: Set(input.id),
+            overridden_at: Set(Some(chrono::Utc::now().naive_utc())),
+            ..Default::default()
+        };
+
+        if let Some(case_id) = input.case_id {
+            model.case_id = Set(case_id);
+        }
+        if let Some(reporter_id) = input.reporter_id {
+            model.reporter_id = Set(reporter_id);
+        }
+        if let Some(risk) = input.risk {
+            model.risk = Set(check_risk(risk)?.into());
+        }
+        if let Some(category) = input.category {
+            model.category = Set(category);
+        }
+
+        let new = model.update(db).await?;
+
+        update_asset_metrics(old, MetricOp::Decrement);
+        update_asset_metrics(new.clone(), MetricOp::Increment);
+        publish(ctx, EntityUpdate::Asset(new.clone()));
+
+        Ok(new)
+    }
+
+    /// Manually correct a case
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn update_case(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Case correction")] input: CaseCorrection,
+    ) -> Result<case::Model> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let old = EntityQuery::find_entity_by_id::<case::Entity, _>(
+            db,
+            (input.network_id.clone(), input.id),
+        )
+        .await?
+        .ok_or("Case not found")?;
+
+        let mut model = case::ActiveModel {
+            network_id: Set(input.network_id),
+            id: Set(input.id),
+            overridden_at: Set(Some(chrono::Utc::now().naive_utc())),
+            ..Default::default()
+        };
+
+        if let Some(name) = input.name {
+            model.name = Set(name);
+        }
+        if let Some(url) = input.url {
+            model.url = Set(url);
+        }
+        if let Some(status) = input.status {
+            model.status = Set(status);
+        }
+        if let Some(reporter_id) = input.reporter_id {
+            model.reporter_id = Set(reporter_id);
+        }
+
+        let new = model.update(db).await?;
+
+        update_case_metrics(old, MetricOp::Decrement);
+        update_case_metrics(new.clone(), MetricOp::Increment);
+        publish(ctx, EntityUpdate::Case(new.clone()));
+
+        Ok(new)
+    }
+
+    /// Manually correct a reporter
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn update_reporter(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Reporter correction")] input: ReporterCorrection,
+    ) -> Result<reporter::Model> {
+        check_admin(ctx)?;
+
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let old = EntityQuery::find_entity_by_id::<reporter::Entity, _>(
+            db,
+            (input.network_id.clone(), input.id),
+        )
+        .await?
+        .ok_or("Reporter not found")?;
+
+        let mut model = reporter::ActiveModel {
+            network_id: Set(input.network_id),
+            id: Set(input.id),
+            overridden_at: Set(Some(chrono::Utc::now().naive_utc())),
+            ..Default::default()
+        };
+
+        if let Some(name) = input.name {
+            model.name = Set(name);
+        }
+        if let Some(url) = input.url {
+            model.url = Set(url);
+        }
+        if let Some(role) = input.role {
+            model.role = Set(role);
+        }
+        if let Some(status) = input.status {
+            model.status = Set(status);
+        }
+
+        let new = model.update(db).await?;
+
+        update_reporter_metrics(old, MetricOp::Decrement);
+        update_reporter_metrics(new.clone(), MetricOp::Increment);
+        publish(ctx, EntityUpdate::Reporter(new.clone()));
+
+        Ok(new)
+    }
+}
+
+/// Rejects requests made without an admin API key
+fn check_admin(ctx: &Context<'_>) -> Result<()> {
+    match ctx.data_opt::<ApiKeyRole>() {
+        Some(ApiKeyRole::Admin) => Ok(()),
+        Some(_) => Err("Admin API key is required".into()),
+        None => Err("You are not authenticated, please provide API key".into()),
+    }
+}
+
+/// Rejects risk scores out of the contract range
+fn check_risk(risk: u8) -> Result<u8> {
+    if risk > MAX_RISK {
+        return Err(format!("Risk must be between 0 and {MAX_RISK}").into());
+    }
+
+    Ok(risk)
+}
+
+fn issue_token(ctx: &Context<'_>, indexer: indexer::Model) -> Result<IndexerToken> {
+    let jwt_secret = ctx.data_unchecked::<SecretString>();
+    let token = create_indexer_token(jwt_secret, &indexer)?;
+
+    Ok(IndexerToken {
+        id: indexer.id,
+        network_id: indexer.network_id,
+        token,
+    })
+}
+
+fn publish(ctx: &Context<'_>, update: EntityUpdate) {
+    ctx.data_unchecked::<EntityUpdates>().publish(update);
+}
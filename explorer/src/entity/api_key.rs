@@ -0,0 +1,35 @@
+use {
+    sea_orm::entity::prelude::*,
+    serde::Serialize,
+    web3::{signing::keccak256, types::H256},
+};
+
+use super::types::ApiKeyRole;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
+#[sea_orm(table_name = "api_key")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub name: String,
+    /// Hash of the key, the key itself is shown only once on creation
+    #[serde(skip_serializing)]
+    pub key_hash: String,
+    pub role: ApiKeyRole,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Generates a new random API key
+pub fn generate_key() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+/// Returns the hash under which the key is stored
+pub fn hash_key(key: &str) -> String {
+    format!("{:x}", H256::from(keccak256(key.as_bytes())))
+}
@@ -1,22 +1,26 @@
 use {
-    async_graphql::SimpleObject,
+    async_graphql::{ComplexObject, Context, Result, SimpleObject},
     hapi_core::client::entities::case::Case as CasePayload,
     sea_orm::{
-        entity::prelude::*, EntityTrait, JoinType, NotSet, QueryOrder, QuerySelect, Select, Set,
+        entity::prelude::*, Condition, EntityTrait, JoinType, NotSet, QueryOrder, QuerySelect,
+        Select, Set,
     },
+    serde::Serialize,
 };
 
 use super::query_utils::{CaseCondition, CaseFilter};
 use crate::entity::{
     address, asset,
-    pagination::{order_by_column, Ordering},
+    loader::{CaseAddressesKey, CaseAssetsKey, EntityDataLoader},
+    pagination::{order_by_columns, with_primary_key, Ordering},
     reporter,
+    search::{full_text_match, prefix_match, SearchTerm, NAME_URL_DOCUMENT},
     types::CaseStatus,
     EntityFilter, FromPayload,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
-#[graphql(name = "Case")]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject, Serialize)]
+#[graphql(name = "Case", complex)]
 #[sea_orm(table_name = "case")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -29,6 +33,34 @@ pub struct Model {
     pub reporter_id: Uuid,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub overridden_at: Option<DateTime>,
+    #[graphql(skip)]
+    pub updated_block: i64,
+    #[graphql(skip)]
+    pub updated_block_index: i64,
+}
+
+#[ComplexObject]
+impl Model {
+    /// Addresses of the case, the earliest created up to the page size limit
+    async fn addresses(&self, ctx: &Context<'_>) -> Result<Vec<address::Model>> {
+        let loader = ctx.data_unchecked::<EntityDataLoader>();
+        let addresses = loader
+            .load_one(CaseAddressesKey(self.network_id.clone(), self.id))
+            .await?;
+
+        Ok(addresses.unwrap_or_default())
+    }
+
+    /// Assets of the case, the earliest created up to the page size limit
+    async fn assets(&self, ctx: &Context<'_>) -> Result<Vec<asset::Model>> {
+        let loader = ctx.data_unchecked::<EntityDataLoader>();
+        let assets = loader
+            .load_one(CaseAssetsKey(self.network_id.clone(), self.id))
+            .await?;
+
+        Ok(assets.unwrap_or_default())
+    }
 }
 
 impl EntityFilter for Entity {
@@ -73,22 +105,39 @@ impl EntityFilter for Entity {
                 sort_by_count(selected, ordering, Relation::Address)
             }
             Some(CaseCondition::AssetCount) => sort_by_count(selected, ordering, Relation::Asset),
-            _ => order_by_column(selected, ordering, condition),
+            _ => order_by_columns(
+                selected,
+                ordering.unwrap_or_default(),
+                &Self::cursor_columns(condition).unwrap_or_default(),
+            ),
+        }
+    }
+
+    // Count ordering is not unique and can not be used for cursor pagination
+    fn cursor_columns(condition: Option<CaseCondition>) -> Option<Vec<Column>> {
+        match condition {
+            Some(CaseCondition::AddressCount) | Some(CaseCondition::AssetCount) => None,
+            _ => Some(with_primary_key::<Entity>(vec![condition
+                .unwrap_or_default()
+                .into()])),
         }
     }
 
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("network_id"),
-            String::from("id::text"),
-            String::from("name"),
-            String::from("url"),
-            String::from("status::text"),
-            String::from("reporter_id::text"),
-            String::from("created_at::text"),
-            String::from("updated_at::text"),
-        ]
+    // Search query
+    fn search(selected: Select<Entity>, term: &SearchTerm) -> Select<Entity> {
+        match term {
+            SearchTerm::Uuid(id) => selected.filter(
+                Condition::any()
+                    .add(Column::Id.eq(*id))
+                    .add(Column::ReporterId.eq(*id)),
+            ),
+            SearchTerm::Hash(value) => selected.filter(full_text_match(NAME_URL_DOCUMENT, value)),
+            SearchTerm::Text(value) => selected.filter(
+                Condition::any()
+                    .add(full_text_match(NAME_URL_DOCUMENT, value))
+                    .add(prefix_match(Column::NetworkId, value)),
+            ),
+        }
     }
 }
 
@@ -163,6 +212,11 @@ impl FromPayload<CasePayload> for ActiveModel {
             reporter_id: Set(payload.reporter_id.to_owned()),
             created_at,
             updated_at,
+            // A chain update supersedes the manual correction
+            overridden_at: Set(None),
+            // Position of the event is set by the upsert
+            updated_block: NotSet,
+            updated_block_index: NotSet,
         }
     }
 }
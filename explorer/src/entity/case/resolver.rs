@@ -11,7 +11,7 @@ use super::{
 };
 
 use crate::{
-    entity::pagination::{EntityInput, EntityPage},
+    entity::pagination::{EntityInput, EntityPage, MaxPageSize},
     service::EntityQuery,
 };
 
@@ -45,7 +45,8 @@ impl CaseQuery {
         #[graphql(desc = "Case input parameters")] input: EntityInput<CaseFilter, CaseCondition>,
     ) -> Result<EntityPage<Model>> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
-        let page = EntityQuery::find_many::<super::model::Entity>(db, input).await?;
+        let max_page_size = ctx.data_unchecked::<MaxPageSize>().0;
+        let page = EntityQuery::find_many::<super::model::Entity>(db, input, max_page_size).await?;
 
         Ok(page)
     }
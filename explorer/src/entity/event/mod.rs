@@ -0,0 +1,6 @@
+pub(super) mod model;
+pub(super) mod query_utils;
+pub(super) mod resolver;
+
+pub use model::{ActiveModel, Column, Entity, Model};
+pub(crate) use resolver::EventQuery;
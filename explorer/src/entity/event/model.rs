@@ -0,0 +1,173 @@
+use {
+    async_graphql::{ComplexObject, Json as JsonValue, SimpleObject},
+    hapi_indexer::{PushData, PushPayload},
+    sea_orm::{entity::prelude::*, Condition, Set},
+};
+
+use super::query_utils::{EventCondition, EventFilter};
+use crate::entity::{
+    network,
+    pagination::with_primary_key,
+    search::{hash_prefix_match, prefix_match, SearchTerm},
+    types::EntityType,
+    EntityFilter,
+};
+
+// Note: tx_index, block and block_index do not correspond to the types of payload (due to Postgresql restrictions)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
+#[graphql(name = "Event", complex)]
+#[sea_orm(table_name = "event")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub network_id: String,
+    pub name: String,
+    pub tx_hash: String,
+    pub tx_index: i64,
+    /// Timestamp of the transaction block
+    pub timestamp: DateTime,
+    /// Number of the transaction block (slot for Solana)
+    pub block: i64,
+    /// Position of the event in the block
+    pub block_index: i64,
+    pub entity_type: EntityType,
+    /// Key of the changed entity: address for addresses, `address:asset_id` for assets,
+    /// id for cases and reporters and network id for network events
+    pub entity_id: String,
+    #[graphql(skip)]
+    pub data: Json,
+    /// Time when the event was received
+    pub created_at: DateTime,
+}
+
+#[ComplexObject]
+impl Model {
+    /// Entity data of the event
+    async fn data(&self) -> JsonValue<Json> {
+        JsonValue(self.data.clone())
+    }
+}
+
+impl EntityFilter for Entity {
+    type Filter = EventFilter;
+    type Condition = EventCondition;
+
+    // Filtering query
+    fn filter(selected: Select<Entity>, filter_options: &EventFilter) -> Select<Entity> {
+        let mut query = selected;
+
+        if let Some(network) = &filter_options.network_id {
+            query = query.filter(Column::NetworkId.eq(network));
+        }
+
+        if let Some(name) = &filter_options.name {
+            query = query.filter(Column::Name.eq(name));
+        }
+
+        if let Some(tx_hash) = &filter_options.tx_hash {
+            query = query.filter(Column::TxHash.eq(tx_hash));
+        }
+
+        if let Some(entity_type) = filter_options.entity_type {
+            query = query.filter(Column::EntityType.eq(entity_type));
+        }
+
+        if let Some(entity_id) = &filter_options.entity_id {
+            query = query.filter(Column::EntityId.eq(entity_id));
+        }
+
+        query
+    }
+
+    // Ordering query, events within the same block keep the order they were received in
+    fn cursor_columns(condition: Option<EventCondition>) -> Option<Vec<Column>> {
+        Some(with_primary_key::<Entity>(vec![
+            condition.unwrap_or_default().into(),
+            Column::CreatedAt,
+        ]))
+    }
+
+    // Search query
+    fn search(selected: Select<Entity>, term: &SearchTerm) -> Select<Entity> {
+        match term {
+            SearchTerm::Uuid(id) => selected.filter(
+                Condition::any()
+                    .add(Column::Id.eq(*id))
+                    .add(Column::EntityId.eq(id.to_string())),
+            ),
+            SearchTerm::Hash(value) => selected.filter(
+                Condition::any()
+                    .add(hash_prefix_match(Column::TxHash, value))
+                    .add(hash_prefix_match(Column::EntityId, value)),
+            ),
+            SearchTerm::Text(value) => selected.filter(
+                Condition::any()
+                    .add(Column::Name.eq(value))
+                    .add(hash_prefix_match(Column::EntityId, value))
+                    .add(prefix_match(Column::NetworkId, value)),
+            ),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "network::Entity",
+        from = "Column::NetworkId",
+        to = "network::model::Column::Id"
+    )]
+    Network,
+}
+
+impl Related<network::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Network.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    /// Creates the event log record, fails if the event data is not serializable
+    pub fn from_payload(
+        network_id: String,
+        timestamp: DateTime,
+        payload: PushPayload,
+    ) -> Result<Self, serde_json::Error> {
+        let data = serde_json::to_value(&payload.data)?;
+
+        let (entity_type, entity_id) = match &payload.data {
+            PushData::Address(address) => (EntityType::Address, address.address.to_owned()),
+            PushData::Asset(asset) => (
+                EntityType::Asset,
+                get_asset_key(&asset.address, &asset.asset_id.to_string()),
+            ),
+            PushData::Case(case) => (EntityType::Case, case.id.to_string()),
+            PushData::Reporter(reporter) => (EntityType::Reporter, reporter.id.to_string()),
+            PushData::Configuration(_) | PushData::Authority(_) => {
+                (EntityType::Network, network_id.to_owned())
+            }
+        };
+
+        Ok(Self {
+            id: Set(Uuid::new_v4()),
+            network_id: Set(network_id),
+            name: Set(payload.event.name.to_string()),
+            tx_hash: Set(payload.event.tx_hash),
+            tx_index: Set(payload.event.tx_index as i64),
+            timestamp: Set(timestamp),
+            block: Set(payload.event.block as i64),
+            block_index: Set(payload.event.block_index as i64),
+            entity_type: Set(entity_type),
+            entity_id: Set(entity_id),
+            data: Set(data),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+        })
+    }
+}
+
+/// Returns the key of the asset used in the event log
+pub(crate) fn get_asset_key(address: &str, asset_id: &str) -> String {
+    format!("{address}:{asset_id}")
+}
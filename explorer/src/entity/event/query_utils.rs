@@ -0,0 +1,39 @@
+use async_graphql::{Enum, InputObject};
+
+use super::model::Column;
+use crate::entity::types::EntityType;
+
+/// Conditions to filter event listings by
+#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
+pub struct EventFilter {
+    pub network_id: Option<String>,
+    pub name: Option<String>,
+    pub tx_hash: Option<String>,
+    pub entity_type: Option<EntityType>,
+    pub entity_id: Option<String>,
+}
+
+/// Available ordering values for event
+#[derive(Enum, Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub enum EventCondition {
+    /// Order by network
+    NetworkId,
+    /// Order by event name
+    Name,
+    /// Order by the timestamp of the transaction block
+    #[default]
+    Timestamp,
+    /// Order by the time when event was received
+    CreatedAt,
+}
+
+impl From<EventCondition> for Column {
+    fn from(condition: EventCondition) -> Self {
+        match condition {
+            EventCondition::NetworkId => Column::NetworkId,
+            EventCondition::Name => Column::Name,
+            EventCondition::Timestamp => Column::Timestamp,
+            EventCondition::CreatedAt => Column::CreatedAt,
+        }
+    }
+}
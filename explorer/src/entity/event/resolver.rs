@@ -0,0 +1,142 @@
+use {
+    async_graphql::{Context, Object, Result},
+    sea_orm::DatabaseConnection,
+    tracing::instrument,
+    uuid::Uuid,
+};
+
+use super::{
+    model::{get_asset_key, Model},
+    query_utils::{EventCondition, EventFilter},
+};
+
+use crate::{
+    entity::{
+        pagination::{EntityInput, EntityPage, MaxPageSize, Paginator},
+        types::EntityType,
+    },
+    service::EntityQuery,
+};
+
+/// The GraphQl Query segment
+#[derive(Default)]
+pub struct EventQuery {}
+
+/// Queries for the `Event` model
+#[Object]
+impl EventQuery {
+    /// Get multiple events
+    #[instrument(level = "debug", skip(self, ctx), fields(input = ?input))]
+    pub async fn get_many_events(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Event input parameters")] input: EntityInput<EventFilter, EventCondition>,
+    ) -> Result<EntityPage<Model>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let max_page_size = ctx.data_unchecked::<MaxPageSize>().0;
+        let page = EntityQuery::find_many::<super::model::Entity>(db, input, max_page_size).await?;
+
+        Ok(page)
+    }
+
+    /// Get the history of address changes
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_address_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address")] address: String,
+        #[graphql(desc = "Address network")] network_id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        get_history(ctx, network_id, EntityType::Address, address, pagination).await
+    }
+
+    /// Get the history of asset changes
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_asset_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset address")] address: String,
+        #[graphql(desc = "Asset id")] asset_id: String,
+        #[graphql(desc = "Asset network")] network_id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        let key = get_asset_key(&address, &asset_id);
+
+        get_history(ctx, network_id, EntityType::Asset, key, pagination).await
+    }
+
+    /// Get the history of case changes
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_case_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Case id")] id: Uuid,
+        #[graphql(desc = "Case network")] network_id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        get_history(
+            ctx,
+            network_id,
+            EntityType::Case,
+            id.to_string(),
+            pagination,
+        )
+        .await
+    }
+
+    /// Get the history of reporter changes
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_reporter_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Reporter id")] id: Uuid,
+        #[graphql(desc = "Reporter network")] network_id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        get_history(
+            ctx,
+            network_id,
+            EntityType::Reporter,
+            id.to_string(),
+            pagination,
+        )
+        .await
+    }
+
+    /// Get the history of network authority and configuration changes
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_network_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Network id")] id: String,
+        #[graphql(desc = "Pagination options")] pagination: Option<Paginator>,
+    ) -> Result<EntityPage<Model>> {
+        get_history(ctx, id.clone(), EntityType::Network, id, pagination).await
+    }
+}
+
+/// Fetches events of the entity, newest first
+async fn get_history(
+    ctx: &Context<'_>,
+    network_id: String,
+    entity_type: EntityType,
+    entity_id: String,
+    pagination: Option<Paginator>,
+) -> Result<EntityPage<Model>> {
+    let input = EntityInput {
+        filtering: Some(EventFilter {
+            network_id: Some(network_id),
+            entity_type: Some(entity_type),
+            entity_id: Some(entity_id),
+            ..Default::default()
+        }),
+        pagination,
+        ..Default::default()
+    };
+
+    let db = ctx.data_unchecked::<DatabaseConnection>();
+    let max_page_size = ctx.data_unchecked::<MaxPageSize>().0;
+
+    Ok(EntityQuery::find_many::<super::model::Entity>(db, input, max_page_size).await?)
+}
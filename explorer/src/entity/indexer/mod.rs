@@ -0,0 +1,8 @@
+pub(super) mod model;
+pub(super) mod resolver;
+
+pub use model::{
+    ActiveModel, Column, Entity, HealthThresholds, IndexerCursor, IndexerHealth, IndexerStatus,
+    Model,
+};
+pub(crate) use resolver::IndexerQuery;
@@ -0,0 +1,113 @@
+use {
+    async_graphql::{Enum, SimpleObject},
+    hapi_indexer::IndexingCursor,
+    sea_orm::entity::prelude::*,
+    serde::Serialize,
+    std::fmt,
+};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
+#[sea_orm(table_name = "indexer")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub network_id: String,
+    pub created_at: DateTime,
+    pub last_heartbeat: DateTime,
+    pub cursor: String,
+    /// Id of the active token, tokens are revoked if it is not set
+    #[serde(skip_serializing)]
+    pub token_id: Option<Uuid>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// Evaluates the indexer health at the given time
+    pub fn health(&self, thresholds: &HealthThresholds, now: DateTime) -> IndexerHealth {
+        let seconds_since_heartbeat = (now - self.last_heartbeat).num_seconds().max(0);
+
+        // The cursor is empty until the first heartbeat
+        let cursor = match serde_json::from_str(&self.cursor) {
+            Ok(IndexingCursor::Block(block)) => Some(IndexerCursor {
+                block: Some(block),
+                transaction: None,
+            }),
+            Ok(IndexingCursor::Transaction(transaction)) => Some(IndexerCursor {
+                block: None,
+                transaction: Some(transaction),
+            }),
+            Ok(IndexingCursor::None) | Err(_) => None,
+        };
+
+        IndexerHealth {
+            id: self.id,
+            network_id: self.network_id.clone(),
+            last_heartbeat: self.last_heartbeat,
+            seconds_since_heartbeat,
+            cursor,
+            status: thresholds.status(seconds_since_heartbeat),
+        }
+    }
+}
+
+/// Indexer status by the time since its last heartbeat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexerStatus {
+    Healthy,
+    Lagging,
+    Dead,
+}
+
+impl fmt::Display for IndexerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IndexerStatus::Healthy => write!(f, "healthy"),
+            IndexerStatus::Lagging => write!(f, "lagging"),
+            IndexerStatus::Dead => write!(f, "dead"),
+        }
+    }
+}
+
+/// Seconds since the last heartbeat after which an indexer is lagging or dead
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthThresholds {
+    pub lagging: u64,
+    pub dead: u64,
+}
+
+impl HealthThresholds {
+    pub fn status(&self, seconds_since_heartbeat: i64) -> IndexerStatus {
+        let seconds = seconds_since_heartbeat.max(0) as u64;
+
+        if seconds >= self.dead {
+            IndexerStatus::Dead
+        } else if seconds >= self.lagging {
+            IndexerStatus::Lagging
+        } else {
+            IndexerStatus::Healthy
+        }
+    }
+}
+
+/// Last indexed block or transaction reported by the indexer
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
+pub struct IndexerCursor {
+    pub block: Option<u64>,
+    pub transaction: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
+pub struct IndexerHealth {
+    pub id: Uuid,
+    pub network_id: String,
+    pub last_heartbeat: DateTime,
+    pub seconds_since_heartbeat: i64,
+    /// Not set until the indexer reports its first indexed block or transaction
+    pub cursor: Option<IndexerCursor>,
+    pub status: IndexerStatus,
+}
@@ -0,0 +1,36 @@
+use {
+    async_graphql::{Context, Object, Result},
+    sea_orm::DatabaseConnection,
+    tracing::instrument,
+};
+
+use super::model::{HealthThresholds, IndexerHealth};
+
+use crate::service::EntityQuery;
+
+/// The GraphQl Query segment
+#[derive(Default)]
+pub struct IndexerQuery {}
+
+/// Queries for the indexer health
+#[Object]
+impl IndexerQuery {
+    /// Get the health of indexers
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_indexer_health(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Indexer network")] network_id: Option<String>,
+    ) -> Result<Vec<IndexerHealth>> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
+        let thresholds = ctx.data_unchecked::<HealthThresholds>();
+        let now = chrono::Utc::now().naive_utc();
+
+        let indexers = EntityQuery::find_indexers(db, network_id.as_deref()).await?;
+
+        Ok(indexers
+            .iter()
+            .map(|indexer| indexer.health(thresholds, now))
+            .collect())
+    }
+}
@@ -0,0 +1,318 @@
+use {
+    async_graphql::dataloader::{DataLoader, Loader},
+    sea_orm::{
+        entity::prelude::*,
+        sea_query::{Alias, Expr, Order, OverStatement, Query, SimpleExpr, WindowStatement},
+        Condition, DatabaseConnection, Iterable, PrimaryKeyToColumn, QueryOrder,
+    },
+    std::{collections::HashMap, hash::Hash, sync::Arc},
+};
+
+use crate::entity::{address, asset, case, network, pagination::MaxPageSize, reporter};
+
+/// Data loader shared by the relation fields of the GraphQL entities
+pub type EntityDataLoader = DataLoader<EntityLoader>;
+
+/// Batches the relation lookups of a request into a single query per relation
+pub struct EntityLoader {
+    db: DatabaseConnection,
+    max_page_size: MaxPageSize,
+}
+
+impl EntityLoader {
+    /// Related lists are capped at `max_page_size` entities
+    pub fn new(db: DatabaseConnection, max_page_size: MaxPageSize) -> Self {
+        Self { db, max_page_size }
+    }
+}
+
+/// Case by network id and case id
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CaseKey(pub String, pub Uuid);
+
+/// Reporter by network id and reporter id
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ReporterKey(pub String, pub Uuid);
+
+/// Network by its id
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NetworkKey(pub String);
+
+/// Addresses of a case by network id and case id
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CaseAddressesKey(pub String, pub Uuid);
+
+/// Assets of a case by network id and case id
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CaseAssetsKey(pub String, pub Uuid);
+
+/// Addresses of a reporter by network id and reporter id
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ReporterAddressesKey(pub String, pub Uuid);
+
+/// Assets of a reporter by network id and reporter id
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ReporterAssetsKey(pub String, pub Uuid);
+
+// Matches any of the (network id, id) pairs
+fn network_pairs<'a, C: ColumnTrait>(
+    network_column: C,
+    id_column: C,
+    keys: impl Iterator<Item = (&'a String, Uuid)>,
+) -> Condition {
+    keys.fold(Condition::any(), |condition, (network_id, id)| {
+        condition.add(
+            Condition::all()
+                .add(network_column.eq(network_id.as_str()))
+                .add(id_column.eq(id)),
+        )
+    })
+}
+
+// Entities matching the condition, at most `limit` of each group in creation order
+async fn find_first_of_groups<E: EntityTrait>(
+    db: &DatabaseConnection,
+    condition: Condition,
+    group_columns: [E::Column; 2],
+    order_column: E::Column,
+    limit: u64,
+) -> Result<Vec<E::Model>, DbErr> {
+    let primary_key: Vec<E::Column> = E::PrimaryKey::iter()
+        .map(PrimaryKeyToColumn::into_column)
+        .collect();
+    let row_number = Alias::new("row_number");
+
+    let mut window = WindowStatement::new();
+    for column in group_columns {
+        window.partition_by(column);
+    }
+    window.order_by(order_column, Order::Asc);
+    for column in &primary_key {
+        window.order_by(*column, Order::Asc);
+    }
+
+    let ranked = Query::select()
+        .columns(primary_key.clone())
+        .expr_window_as(Expr::cust("ROW_NUMBER()"), window, row_number.clone())
+        .from(E::default())
+        .cond_where(condition)
+        .to_owned();
+
+    let first = Query::select()
+        .columns(primary_key.clone())
+        .from_subquery(ranked, Alias::new("ranked"))
+        .and_where(Expr::col(row_number).lte(limit))
+        .to_owned();
+
+    let key = Expr::tuple(
+        primary_key
+            .into_iter()
+            .map(|column| SimpleExpr::from(Expr::col((E::default(), column)))),
+    );
+
+    E::find()
+        .filter(key.in_subquery(first))
+        .order_by_asc(order_column)
+        .all(db)
+        .await
+}
+
+fn group_by<K: Eq + Hash, V>(values: Vec<V>, key: impl Fn(&V) -> K) -> HashMap<K, Vec<V>> {
+    let mut groups: HashMap<K, Vec<V>> = HashMap::new();
+
+    for value in values {
+        groups.entry(key(&value)).or_default().push(value);
+    }
+
+    groups
+}
+
+#[async_trait::async_trait]
+impl Loader<CaseKey> for EntityLoader {
+    type Value = case::Model;
+    type Error = Arc<DbErr>;
+
+    async fn load(&self, keys: &[CaseKey]) -> Result<HashMap<CaseKey, Self::Value>, Self::Error> {
+        let cases = case::Entity::find()
+            .filter(network_pairs(
+                case::Column::NetworkId,
+                case::Column::Id,
+                keys.iter().map(|CaseKey(network_id, id)| (network_id, *id)),
+            ))
+            .all(&self.db)
+            .await?;
+
+        Ok(cases
+            .into_iter()
+            .map(|case| (CaseKey(case.network_id.clone(), case.id), case))
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl Loader<ReporterKey> for EntityLoader {
+    type Value = reporter::Model;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[ReporterKey],
+    ) -> Result<HashMap<ReporterKey, Self::Value>, Self::Error> {
+        let reporters = reporter::Entity::find()
+            .filter(network_pairs(
+                reporter::Column::NetworkId,
+                reporter::Column::Id,
+                keys.iter()
+                    .map(|ReporterKey(network_id, id)| (network_id, *id)),
+            ))
+            .all(&self.db)
+            .await?;
+
+        Ok(reporters
+            .into_iter()
+            .map(|reporter| {
+                (
+                    ReporterKey(reporter.network_id.clone(), reporter.id),
+                    reporter,
+                )
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl Loader<NetworkKey> for EntityLoader {
+    type Value = network::Model;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[NetworkKey],
+    ) -> Result<HashMap<NetworkKey, Self::Value>, Self::Error> {
+        let networks = network::Entity::find()
+            .filter(network::Column::Id.is_in(keys.iter().map(|NetworkKey(id)| id.as_str())))
+            .all(&self.db)
+            .await?;
+
+        Ok(networks
+            .into_iter()
+            .map(|network| (NetworkKey(network.id.clone()), network))
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl Loader<CaseAddressesKey> for EntityLoader {
+    type Value = Vec<address::Model>;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[CaseAddressesKey],
+    ) -> Result<HashMap<CaseAddressesKey, Self::Value>, Self::Error> {
+        let addresses = find_first_of_groups::<address::Entity>(
+            &self.db,
+            network_pairs(
+                address::Column::NetworkId,
+                address::Column::CaseId,
+                keys.iter()
+                    .map(|CaseAddressesKey(network_id, id)| (network_id, *id)),
+            ),
+            [address::Column::NetworkId, address::Column::CaseId],
+            address::Column::CreatedAt,
+            self.max_page_size.0,
+        )
+        .await?;
+
+        Ok(group_by(addresses, |address| {
+            CaseAddressesKey(address.network_id.clone(), address.case_id)
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Loader<CaseAssetsKey> for EntityLoader {
+    type Value = Vec<asset::Model>;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[CaseAssetsKey],
+    ) -> Result<HashMap<CaseAssetsKey, Self::Value>, Self::Error> {
+        let assets = find_first_of_groups::<asset::Entity>(
+            &self.db,
+            network_pairs(
+                asset::Column::NetworkId,
+                asset::Column::CaseId,
+                keys.iter()
+                    .map(|CaseAssetsKey(network_id, id)| (network_id, *id)),
+            ),
+            [asset::Column::NetworkId, asset::Column::CaseId],
+            asset::Column::CreatedAt,
+            self.max_page_size.0,
+        )
+        .await?;
+
+        Ok(group_by(assets, |asset| {
+            CaseAssetsKey(asset.network_id.clone(), asset.case_id)
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Loader<ReporterAddressesKey> for EntityLoader {
+    type Value = Vec<address::Model>;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[ReporterAddressesKey],
+    ) -> Result<HashMap<ReporterAddressesKey, Self::Value>, Self::Error> {
+        let addresses = find_first_of_groups::<address::Entity>(
+            &self.db,
+            network_pairs(
+                address::Column::NetworkId,
+                address::Column::ReporterId,
+                keys.iter()
+                    .map(|ReporterAddressesKey(network_id, id)| (network_id, *id)),
+            ),
+            [address::Column::NetworkId, address::Column::ReporterId],
+            address::Column::CreatedAt,
+            self.max_page_size.0,
+        )
+        .await?;
+
+        Ok(group_by(addresses, |address| {
+            ReporterAddressesKey(address.network_id.clone(), address.reporter_id)
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Loader<ReporterAssetsKey> for EntityLoader {
+    type Value = Vec<asset::Model>;
+    type Error = Arc<DbErr>;
+
+    async fn load(
+        &self,
+        keys: &[ReporterAssetsKey],
+    ) -> Result<HashMap<ReporterAssetsKey, Self::Value>, Self::Error> {
+        let assets = find_first_of_groups::<asset::Entity>(
+            &self.db,
+            network_pairs(
+                asset::Column::NetworkId,
+                asset::Column::ReporterId,
+                keys.iter()
+                    .map(|ReporterAssetsKey(network_id, id)| (network_id, *id)),
+            ),
+            [asset::Column::NetworkId, asset::Column::ReporterId],
+            asset::Column::CreatedAt,
+            self.max_page_size.0,
+        )
+        .await?;
+
+        Ok(group_by(assets, |asset| {
+            ReporterAssetsKey(asset.network_id.clone(), asset.reporter_id)
+        }))
+    }
+}
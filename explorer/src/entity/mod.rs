@@ -1,14 +1,23 @@
 pub mod address;
+pub mod admin;
+pub mod api_key;
 pub mod asset;
 pub mod case;
+pub mod event;
 pub mod indexer;
+pub mod loader;
 pub mod network;
 pub mod pagination;
 pub mod reporter;
+pub mod search;
 pub mod statistics;
+pub mod subscription;
 pub mod types;
 
-use self::pagination::{order_by_column, Ordering};
+use self::{
+    pagination::{order_by_columns, with_primary_key, Ordering},
+    search::SearchTerm,
+};
 use sea_orm::{prelude::DateTime, EntityTrait, Select};
 
 pub trait FromPayload<T>: Sized {
@@ -27,7 +36,19 @@ pub trait EntityFilter: Sized + EntityTrait {
 
     fn filter(selected: Select<Self>, filter_options: &Self::Filter) -> Select<Self>;
 
-    fn columns_for_search() -> Vec<String>;
+    fn search(selected: Select<Self>, term: &SearchTerm) -> Select<Self>;
+
+    /// Columns the entities are ordered by, completed with the primary key to make the order unique.
+    /// Returns None if the ordering can not be used for cursor pagination
+    fn cursor_columns(condition: Option<Self::Condition>) -> Option<Vec<Self::Column>>
+    where
+        Self::Column: From<Self::Condition>,
+        Self::Condition: Default,
+    {
+        Some(with_primary_key::<Self>(vec![condition
+            .unwrap_or_default()
+            .into()]))
+    }
 
     fn order(
         selected: Select<Self>,
@@ -38,6 +59,8 @@ pub trait EntityFilter: Sized + EntityTrait {
         Self::Column: From<Self::Condition>,
         Self::Condition: Default,
     {
-        order_by_column(selected, ordering, condition)
+        let columns = Self::cursor_columns(condition).unwrap_or_default();
+
+        order_by_columns(selected, ordering.unwrap_or_default(), &columns)
     }
 }
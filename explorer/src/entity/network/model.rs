@@ -1,7 +1,14 @@
 use super::query_utils::{NetworkCondition, NetworkFilter};
-use crate::entity::{types::NetworkBackend, EntityFilter};
+use crate::entity::{
+    search::{hash_match, no_match, prefix_match, SearchTerm},
+    types::NetworkBackend,
+    EntityFilter,
+};
 
-use {async_graphql::SimpleObject, sea_orm::entity::prelude::*};
+use {
+    async_graphql::SimpleObject,
+    sea_orm::{entity::prelude::*, Condition},
+};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
 #[graphql(name = "Network")]
@@ -45,18 +52,20 @@ impl EntityFilter for Entity {
         query
     }
 
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("id"),
-            String::from("name"),
-            String::from("backend::text"),
-            String::from("chain_id"),
-            String::from("authority"),
-            String::from("stake_token"),
-            String::from("created_at::text"),
-            String::from("updated_at::text"),
-        ]
+    // Search query, networks are few and not indexed
+    fn search(selected: Select<Entity>, term: &SearchTerm) -> Select<Entity> {
+        match term {
+            SearchTerm::Uuid(_) => selected.filter(no_match()),
+            SearchTerm::Hash(value) | SearchTerm::Text(value) => selected.filter(
+                Condition::any()
+                    .add(Column::Id.eq(value))
+                    // Networks are few, so chain ids are matched by any part
+                    .add(Column::ChainId.contains(value))
+                    .add(hash_match(Column::Authority, value))
+                    .add(hash_match(Column::StakeToken, value))
+                    .add(prefix_match(Column::Name, value)),
+            ),
+        }
     }
 }
 
@@ -1,6 +1,12 @@
 use {
     async_graphql::{Enum, InputObject, InputType, OutputType, SimpleObject},
-    sea_orm::{EntityTrait, QueryOrder, Select},
+    base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine},
+    sea_orm::{
+        prelude::DateTime, sea_query::Cond, ColumnTrait, DbErr, EntityTrait, IdenStatic, Iterable,
+        ModelTrait, PrimaryKeyToColumn, QueryOrder, Select, Value,
+    },
+    serde::{Deserialize, Serialize},
+    uuid::Uuid,
 };
 
 use super::{
@@ -16,6 +22,10 @@ use super::{
         model::Model as Case,
         query_utils::{CaseCondition, CaseFilter},
     },
+    event::{
+        model::Model as Event,
+        query_utils::{EventCondition, EventFilter},
+    },
     network::{
         model::Model as Network,
         query_utils::{NetworkCondition, NetworkFilter},
@@ -28,6 +38,17 @@ use super::{
 
 const DEFAULT_PAGE_NUM: u64 = 1;
 pub const DEFAULT_PAGE_SIZE: u64 = 10;
+pub const DEFAULT_MAX_PAGE_SIZE: u64 = 100;
+
+/// The largest page a listing query may return
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MaxPageSize(pub u64);
+
+impl Default for MaxPageSize {
+    fn default() -> Self {
+        Self(DEFAULT_MAX_PAGE_SIZE)
+    }
+}
 
 /// A convenience wrapper for pagination
 #[derive(Clone, Eq, PartialEq, InputObject, Debug)]
@@ -53,25 +74,173 @@ pub enum Ordering {
     Desc,
 }
 
-/// Method for query ordering by column
-pub fn order_by_column<M, C>(
-    query: Select<M>,
-    ordering: Option<Ordering>,
-    condition: Option<C>,
-) -> Select<M>
+impl Ordering {
+    /// Returns the opposite ordering
+    pub fn reverse(self) -> Self {
+        match self {
+            Ordering::Asc => Ordering::Desc,
+            Ordering::Desc => Ordering::Asc,
+        }
+    }
+}
+
+/// Method for query ordering by columns
+pub fn order_by_columns<M>(query: Select<M>, ordering: Ordering, columns: &[M::Column]) -> Select<M>
+where
+    M: EntityTrait,
+{
+    columns.iter().fold(query, |query, column| match ordering {
+        Ordering::Asc => query.order_by_asc(*column),
+        Ordering::Desc => query.order_by_desc(*column),
+    })
+}
+
+/// Completes ordering columns with the primary key, making the order unique
+pub fn with_primary_key<M>(columns: Vec<M::Column>) -> Vec<M::Column>
+where
+    M: EntityTrait,
+{
+    let mut res: Vec<M::Column> = vec![];
+
+    for column in columns
+        .into_iter()
+        .chain(M::PrimaryKey::iter().map(|key| key.into_column()))
+    {
+        if !res.iter().any(|c| c.as_str() == column.as_str()) {
+            res.push(column);
+        }
+    }
+
+    res
+}
+
+/// Value of a cursor column, keeps the type the column is compared with
+#[derive(Serialize, Deserialize)]
+enum CursorValue {
+    Bool(bool),
+    Int(i64),
+    String(String),
+    Uuid(String),
+    DateTime(DateTime),
+}
+
+impl TryFrom<Value> for CursorValue {
+    type Error = DbErr;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let value = match value {
+            Value::Bool(Some(v)) => CursorValue::Bool(v),
+            Value::TinyInt(Some(v)) => CursorValue::Int(v.into()),
+            Value::SmallInt(Some(v)) => CursorValue::Int(v.into()),
+            Value::Int(Some(v)) => CursorValue::Int(v.into()),
+            Value::BigInt(Some(v)) => CursorValue::Int(v),
+            Value::String(Some(v)) => CursorValue::String(*v),
+            Value::Uuid(Some(v)) => CursorValue::Uuid(v.to_string()),
+            Value::ChronoDateTime(Some(v)) => CursorValue::DateTime(*v),
+            _ => {
+                return Err(DbErr::Custom(
+                    "Ordering column can not be used in cursor".to_string(),
+                ))
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+impl TryFrom<CursorValue> for Value {
+    type Error = DbErr;
+
+    fn try_from(value: CursorValue) -> Result<Self, Self::Error> {
+        let value = match value {
+            CursorValue::Bool(v) => v.into(),
+            CursorValue::Int(v) => v.into(),
+            CursorValue::String(v) => v.into(),
+            CursorValue::Uuid(v) => Uuid::parse_str(&v)
+                .map_err(|_| DbErr::Custom("Invalid cursor".to_string()))?
+                .into(),
+            CursorValue::DateTime(v) => v.into(),
+        };
+
+        Ok(value)
+    }
+}
+
+/// Builds an opaque cursor pointing to the model
+pub fn encode_cursor<M>(
+    model: &M,
+    columns: &[<M::Entity as EntityTrait>::Column],
+) -> Result<String, DbErr>
+where
+    M: ModelTrait,
+{
+    let values = columns
+        .iter()
+        .map(|column| CursorValue::try_from(model.get(*column)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let json = serde_json::to_vec(&values).map_err(|e| DbErr::Custom(e.to_string()))?;
+
+    Ok(URL_SAFE_NO_PAD.encode(json))
+}
+
+/// Builds the condition selecting entities that follow the cursor in the given ordering
+pub fn cursor_condition<M>(
+    cursor: &str,
+    columns: &[M::Column],
+    ordering: Ordering,
+) -> Result<Cond, DbErr>
 where
     M: EntityTrait,
-    M::Column: From<C>,
-    C: Default,
 {
-    let ordering = ordering.unwrap_or_default();
-    let condition = condition.unwrap_or_default();
+    let invalid_cursor = || DbErr::Custom("Invalid cursor".to_string());
+
+    let json = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .map_err(|_| invalid_cursor())?;
+    let values = serde_json::from_slice::<Vec<CursorValue>>(&json)
+        .map_err(|_| invalid_cursor())?
+        .into_iter()
+        .map(Value::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let column = M::Column::from(condition);
-    match ordering {
-        Ordering::Asc => query.order_by_asc(column),
-        Ordering::Desc => query.order_by_desc(column),
+    if values.len() != columns.len() {
+        return Err(invalid_cursor());
     }
+
+    // (a, b) > (x, y) is expanded to a > x OR (a = x AND b > y)
+    let mut condition = Cond::any();
+
+    for (index, column) in columns.iter().enumerate() {
+        let mut branch = Cond::all();
+
+        for (prev, value) in columns.iter().zip(&values).take(index) {
+            branch = branch.add(ColumnTrait::eq(prev, value.clone()));
+        }
+
+        let value = values[index].clone();
+        branch = branch.add(match ordering {
+            Ordering::Asc => column.gt(value),
+            Ordering::Desc => column.lt(value),
+        });
+
+        condition = condition.add(branch);
+    }
+
+    Ok(condition)
+}
+
+/// Relay-style information about the returned page
+#[derive(Clone, Debug, Default, Eq, PartialEq, SimpleObject)]
+pub struct PageInfo {
+    /// Whether there are entities after the page
+    pub has_next_page: bool,
+    /// Whether there are entities before the page
+    pub has_previous_page: bool,
+    /// Cursor of the first entity of the page
+    pub start_cursor: Option<String>,
+    /// Cursor of the last entity of the page
+    pub end_cursor: Option<String>,
 }
 
 /// Paginated response for an entity
@@ -81,6 +250,7 @@ where
 #[graphql(concrete(name = "CasePage", params(Case)))]
 #[graphql(concrete(name = "AddressPage", params(Address)))]
 #[graphql(concrete(name = "AssetPage", params(Asset)))]
+#[graphql(concrete(name = "EventPage", params(Event)))]
 pub struct EntityPage<Entity: Send + Sync + OutputType> {
     /// The page of data being returned
     pub data: Vec<Entity>,
@@ -88,6 +258,8 @@ pub struct EntityPage<Entity: Send + Sync + OutputType> {
     pub total: u64,
     /// The number of pages available
     pub page_count: u64,
+    /// Cursors of the page and whether there are adjacent pages
+    pub page_info: PageInfo,
 }
 
 /// Entity input type
@@ -97,6 +269,7 @@ pub struct EntityPage<Entity: Send + Sync + OutputType> {
 #[graphql(concrete(name = "CaseInput", params(CaseFilter, CaseCondition)))]
 #[graphql(concrete(name = "AddressInput", params(AddressFilter, AddressCondition)))]
 #[graphql(concrete(name = "AssetInput", params(AssetFilter, AssetCondition)))]
+#[graphql(concrete(name = "EventInput", params(EventFilter, EventCondition)))]
 pub struct EntityInput<F: InputType, C: InputType> {
     /// Conditions to filter entities by
     pub filtering: Option<F>,
@@ -110,6 +283,18 @@ pub struct EntityInput<F: InputType, C: InputType> {
     /// Pagination options
     pub pagination: Option<Paginator>,
 
+    /// Cursor pagination: the number of entities following the `after` cursor
+    pub first: Option<u64>,
+
+    /// Cursor pagination: cursor to return entities after
+    pub after: Option<String>,
+
+    /// Cursor pagination: the number of entities preceding the `before` cursor
+    pub last: Option<u64>,
+
+    /// Cursor pagination: cursor to return entities before
+    pub before: Option<String>,
+
     /// String value to search entities
     pub search: Option<String>,
 }
@@ -1,19 +1,21 @@
 use {
-    async_graphql::SimpleObject,
+    async_graphql::{ComplexObject, Context, Result, SimpleObject},
     hapi_core::client::entities::reporter::Reporter as ReporterPayload,
-    sea_orm::{entity::prelude::*, NotSet, Set},
+    sea_orm::{entity::prelude::*, Condition, NotSet, Set},
 };
 
 use super::query_utils::{ReporterCondition, ReporterFilter};
 use crate::entity::{
     address, asset, case,
+    loader::{EntityDataLoader, ReporterAddressesKey, ReporterAssetsKey},
+    search::{full_text_match, hash_prefix_match, prefix_match, SearchTerm, NAME_URL_DOCUMENT},
     types::{ReporterRole, ReporterStatus},
     EntityFilter, FromPayload,
 };
 
 // Note: unlock_timestamp and stake do not correspond to the types of contracts (due to Postgresql restrictions)
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, SimpleObject)]
-#[graphql(name = "Reporter")]
+#[graphql(name = "Reporter", complex)]
 #[sea_orm(table_name = "reporter")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -29,6 +31,34 @@ pub struct Model {
     pub unlock_timestamp: String,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub overridden_at: Option<DateTime>,
+    #[graphql(skip)]
+    pub updated_block: i64,
+    #[graphql(skip)]
+    pub updated_block_index: i64,
+}
+
+#[ComplexObject]
+impl Model {
+    /// Addresses of the reporter, the earliest created up to the page size limit
+    async fn addresses(&self, ctx: &Context<'_>) -> Result<Vec<address::Model>> {
+        let loader = ctx.data_unchecked::<EntityDataLoader>();
+        let addresses = loader
+            .load_one(ReporterAddressesKey(self.network_id.clone(), self.id))
+            .await?;
+
+        Ok(addresses.unwrap_or_default())
+    }
+
+    /// Assets of the reporter, the earliest created up to the page size limit
+    async fn assets(&self, ctx: &Context<'_>) -> Result<Vec<asset::Model>> {
+        let loader = ctx.data_unchecked::<EntityDataLoader>();
+        let assets = loader
+            .load_one(ReporterAssetsKey(self.network_id.clone(), self.id))
+            .await?;
+
+        Ok(assets.unwrap_or_default())
+    }
 }
 
 impl EntityFilter for Entity {
@@ -66,21 +96,18 @@ impl EntityFilter for Entity {
         query
     }
 
-    /// Columns for search
-    fn columns_for_search() -> Vec<String> {
-        vec![
-            String::from("network_id"),
-            String::from("id::text"),
-            String::from("account"),
-            String::from("role::text"),
-            String::from("status::text"),
-            String::from("name"),
-            String::from("url"),
-            String::from("stake"),
-            String::from("unlock_timestamp"),
-            String::from("created_at::text"),
-            String::from("updated_at::text"),
-        ]
+    // Search query
+    fn search(selected: Select<Entity>, term: &SearchTerm) -> Select<Entity> {
+        match term {
+            SearchTerm::Uuid(id) => selected.filter(Column::Id.eq(*id)),
+            SearchTerm::Hash(value) => selected.filter(hash_prefix_match(Column::Account, value)),
+            SearchTerm::Text(value) => selected.filter(
+                Condition::any()
+                    .add(full_text_match(NAME_URL_DOCUMENT, value))
+                    .add(hash_prefix_match(Column::Account, value))
+                    .add(prefix_match(Column::NetworkId, value)),
+            ),
+        }
     }
 }
 
@@ -136,6 +163,11 @@ impl FromPayload<ReporterPayload> for ActiveModel {
             unlock_timestamp: Set(payload.unlock_timestamp.to_string()),
             created_at,
             updated_at,
+            // A chain update supersedes the manual correction
+            overridden_at: Set(None),
+            // Position of the event is set by the upsert
+            updated_block: NotSet,
+            updated_block_index: NotSet,
         }
     }
 }
@@ -0,0 +1,122 @@
+use {
+    sea_orm::{
+        sea_query::{Expr, Func, SimpleExpr},
+        ColumnTrait,
+    },
+    uuid::Uuid,
+};
+
+/// Full-text document of cases and reporters, matches the expression of their GIN indexes
+pub const NAME_URL_DOCUMENT: &str = "name || ' ' || url";
+
+/// Search value classified by its shape, each shape is matched against its own columns
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SearchTerm {
+    /// Entity, case or reporter id
+    Uuid(Uuid),
+    /// Address, account or transaction hash: 0x-prefixed hex, base58 or NEAR account id
+    Hash(String),
+    /// Free text
+    Text(String),
+}
+
+impl SearchTerm {
+    /// Classifies the search value, returns None for a blank value
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+
+        if value.is_empty() {
+            return None;
+        }
+
+        if let Ok(id) = Uuid::parse_str(value) {
+            return Some(Self::Uuid(id));
+        }
+
+        // EVM addresses are stored checksummed, hex values are matched in lowercase
+        if is_hex(value) {
+            return Some(Self::Hash(value.to_lowercase()));
+        }
+
+        if is_base58(value) || is_near_account(value) {
+            return Some(Self::Hash(value.to_string()));
+        }
+
+        Some(Self::Text(value.to_string()))
+    }
+}
+
+fn is_hex(value: &str) -> bool {
+    value.strip_prefix("0x").map_or(false, |hex| {
+        !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+// Solana addresses are at least 32 characters long
+fn is_base58(value: &str) -> bool {
+    value.len() >= 32
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l'))
+}
+
+// Named accounts end with the top-level account, implicit accounts are 64 hex characters
+fn is_near_account(value: &str) -> bool {
+    let is_named = (value.ends_with(".near") || value.ends_with(".testnet"))
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.'));
+    let is_implicit = value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit());
+
+    is_named || is_implicit
+}
+
+/// Matches column values starting with the value, uses `text_pattern_ops` indexes
+pub fn prefix_match<C: ColumnTrait>(column: C, value: &str) -> SimpleExpr {
+    column.like(prefix_pattern(value))
+}
+
+/// Matches hash column values starting with the value, hex values are compared with the
+/// lowercased column and use `lower(column) text_pattern_ops` indexes
+pub fn hash_prefix_match<C: ColumnTrait>(column: C, value: &str) -> SimpleExpr {
+    if is_hex(value) {
+        lower(column).like(prefix_pattern(&value.to_lowercase()))
+    } else {
+        prefix_match(column, value)
+    }
+}
+
+/// Matches hash column values equal to the value, hex values are compared in lowercase
+pub fn hash_match<C: ColumnTrait>(column: C, value: &str) -> SimpleExpr {
+    if is_hex(value) {
+        lower(column).eq(value.to_lowercase())
+    } else {
+        column.eq(value)
+    }
+}
+
+fn lower<C: ColumnTrait>(column: C) -> Expr {
+    Expr::expr(Func::lower(Expr::col(column.as_column_ref())))
+}
+
+fn prefix_pattern(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("{escaped}%")
+}
+
+/// Matches the document against the words of the value, uses GIN indexes over the same document
+pub fn full_text_match(document: &str, value: &str) -> SimpleExpr {
+    Expr::cust_with_values(
+        format!("to_tsvector('simple', {document}) @@ plainto_tsquery('simple', $1)"),
+        [value],
+    )
+}
+
+/// Condition matching no rows, for search terms that do not apply to the entity
+pub fn no_match() -> SimpleExpr {
+    Expr::cust("FALSE")
+}
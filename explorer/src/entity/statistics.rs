@@ -1,14 +1,19 @@
 use {
     async_graphql::{Context, Object, Result, SimpleObject},
-    chrono::{Datelike, Utc, Weekday},
-    sea_orm::{DatabaseConnection, EntityTrait, PaginatorTrait, QuerySelect},
+    chrono::{Datelike, NaiveDate, NaiveDateTime, Utc, Weekday},
+    sea_orm::{
+        sea_query::Expr, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Iterable,
+        PaginatorTrait, QueryFilter, QuerySelect, Select, TryGetable,
+    },
+    serde::Serialize,
+    std::collections::BTreeMap,
     tracing::instrument,
     web3::types::U256,
 };
 
 use super::{
     address::query_utils::AddressCondition, asset::query_utils::AssetCondition,
-    case::query_utils::CaseCondition, pagination::DEFAULT_PAGE_SIZE,
+    case::query_utils::CaseCondition, pagination::DEFAULT_PAGE_SIZE, types::Category,
 };
 
 use crate::{
@@ -17,8 +22,17 @@ use crate::{
 };
 
 pub const CHART_LENGTH: usize = 10;
+pub const MAX_CHART_LENGTH: usize = 52;
 
-#[derive(Clone, Debug, PartialEq, Eq, SimpleObject)]
+/// Risk buckets as (name, min risk, max risk) with inclusive bounds
+pub const RISK_BUCKETS: [(&str, i16, i16); 4] = [
+    ("none", 0, 0),
+    ("low", 1, 3),
+    ("medium", 4, 6),
+    ("high", 7, 10),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
 #[graphql(name = "Dashboard")]
 pub struct Dashboard {
     pub staked_by_reporters: String,
@@ -35,7 +49,7 @@ pub struct Dashboard {
     pub top_cases_by_asset: Vec<case::Model>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, SimpleObject)]
+#[derive(Clone, Debug, PartialEq, Eq, SimpleObject, Serialize)]
 pub struct Charts {
     pub labels: Vec<String>,
     pub addresses: Vec<u64>,
@@ -43,6 +57,46 @@ pub struct Charts {
     pub cases: Vec<u64>,
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct NetworkBreakdown {
+    pub network_id: String,
+    pub addresses: u64,
+    pub assets: u64,
+    pub cases: u64,
+    pub reporters: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CategoryBreakdown {
+    pub category: Category,
+    pub addresses: u64,
+    pub assets: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RiskBreakdown {
+    pub bucket: String,
+    pub min_risk: i16,
+    pub max_risk: i16,
+    pub addresses: u64,
+    pub assets: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Breakdowns {
+    pub networks: Vec<NetworkBreakdown>,
+    pub categories: Vec<CategoryBreakdown>,
+    pub risks: Vec<RiskBreakdown>,
+}
+
+/// Full statistics, served by the REST endpoint
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Statistics {
+    pub dashboard: Dashboard,
+    pub charts: Charts,
+    pub breakdowns: Breakdowns,
+}
+
 /// The GraphQl Query segment
 #[derive(Default)]
 pub struct StatisticsQuery {}
@@ -54,84 +108,292 @@ impl StatisticsQuery {
     #[instrument(level = "debug", skip(self, ctx))]
     pub async fn get_dashboard(&self, ctx: &Context<'_>) -> Result<Dashboard> {
         let db = ctx.data_unchecked::<DatabaseConnection>();
-        let (year, week) = get_current_week();
 
-        let (staked_by_reporters, total_reporters_count) = get_reporter_dashboard(db).await?;
+        Ok(get_dashboard(db, None).await?)
+    }
+
+    /// Get a chart statistics
+    #[instrument(level = "debug", skip(self, ctx))]
+    pub async fn get_charts(&self, ctx: &Context<'_>) -> Result<Charts> {
+        let db = ctx.data_unchecked::<DatabaseConnection>();
 
-        let (total_addresses_count, new_weekly_address_count, last_added_addresses) =
-            get_address_dashboard(db, year, week).await?;
+        Ok(get_charts(db, None, CHART_LENGTH).await?)
+    }
+}
+
+/// Collects dashboard, charts and breakdowns, optionally limited to a single network.
+/// Charts cover the last `weeks` weeks, breakdowns count entities created within
+/// this window if it is given and all entities otherwise
+#[instrument(level = "debug", skip(db))]
+pub async fn get_statistics(
+    db: &DatabaseConnection,
+    network_id: Option<&str>,
+    weeks: Option<usize>,
+) -> Result<Statistics, DbErr> {
+    let length = weeks.unwrap_or(CHART_LENGTH).clamp(1, MAX_CHART_LENGTH);
+
+    let since = match weeks {
+        Some(_) => get_past_weeks(length)
+            .first()
+            .and_then(|(year, week)| NaiveDate::from_isoywd_opt(*year, *week, Weekday::Mon))
+            .and_then(|date| date.and_hms_opt(0, 0, 0)),
+        None => None,
+    };
+
+    Ok(Statistics {
+        dashboard: get_dashboard(db, network_id).await?,
+        charts: get_charts(db, network_id, length).await?,
+        breakdowns: get_breakdowns(db, network_id, since).await?,
+    })
+}
+
+async fn get_dashboard(
+    db: &DatabaseConnection,
+    network_id: Option<&str>,
+) -> Result<Dashboard, DbErr> {
+    let (year, week) = get_current_week();
+
+    let (staked_by_reporters, total_reporters_count) =
+        get_reporter_dashboard(db, network_id).await?;
+
+    let (total_addresses_count, new_weekly_address_count, last_added_addresses) =
+        get_address_dashboard(db, network_id, year, week).await?;
+
+    let (total_asset_count, new_weekly_asset_count, last_added_assets) =
+        get_asset_dashboard(db, network_id, year, week).await?;
+
+    let (total_case_count, new_weekly_case_count, top_cases_by_address, top_cases_by_asset) =
+        get_case_dashboard(db, network_id, year, week).await?;
+
+    let dashboard = Dashboard {
+        staked_by_reporters,
+        total_reporters_count,
+
+        total_case_count,
+        new_weekly_case_count,
+        top_cases_by_address,
+        top_cases_by_asset,
 
-        let (total_asset_count, new_weekly_asset_count, last_added_assets) =
-            get_asset_dashboard(db, year, week).await?;
+        total_addresses_count,
+        new_weekly_address_count,
+        last_added_addresses,
 
-        let (total_case_count, new_weekly_case_count, top_cases_by_address, top_cases_by_asset) =
-            get_case_dashboard(db, year, week).await?;
+        total_asset_count,
+        new_weekly_asset_count,
+        last_added_assets,
+    };
 
-        let dashboard = Dashboard {
-            staked_by_reporters,
-            total_reporters_count,
+    Ok(dashboard)
+}
 
-            total_case_count,
-            new_weekly_case_count,
-            top_cases_by_address,
-            top_cases_by_asset,
+async fn get_charts(
+    db: &DatabaseConnection,
+    network_id: Option<&str>,
+    length: usize,
+) -> Result<Charts, DbErr> {
+    let weeks = get_past_weeks(length);
+
+    let mut labels = vec![];
+    let mut addresses = vec![];
+    let mut assets = vec![];
+    let mut cases = vec![];
+
+    for (year, week) in weeks {
+        let label = format!("{}:{}", year, week);
+        labels.push(label);
+
+        let query = filter_network(
+            address::Entity::find(),
+            address::Column::NetworkId,
+            network_id,
+        );
+        let addresses_count = count_rows_per_week(db, query, year, week).await?;
+        addresses.push(addresses_count);
+
+        let query = filter_network(asset::Entity::find(), asset::Column::NetworkId, network_id);
+        let assets_count = count_rows_per_week(db, query, year, week).await?;
+        assets.push(assets_count);
+
+        let query = filter_network(case::Entity::find(), case::Column::NetworkId, network_id);
+        let cases_count = count_rows_per_week(db, query, year, week).await?;
+        cases.push(cases_count);
+    }
 
-            total_addresses_count,
-            new_weekly_address_count,
-            last_added_addresses,
+    let charts = Charts {
+        labels,
+        addresses,
+        assets,
+        cases,
+    };
 
-            total_asset_count,
-            new_weekly_asset_count,
-            last_added_assets,
-        };
+    Ok(charts)
+}
 
-        Ok(dashboard)
+async fn get_breakdowns(
+    db: &DatabaseConnection,
+    network_id: Option<&str>,
+    since: Option<NaiveDateTime>,
+) -> Result<Breakdowns, DbErr> {
+    let addresses = filter_since(
+        filter_network(
+            address::Entity::find(),
+            address::Column::NetworkId,
+            network_id,
+        ),
+        address::Column::CreatedAt,
+        since,
+    );
+    let assets = filter_since(
+        filter_network(asset::Entity::find(), asset::Column::NetworkId, network_id),
+        asset::Column::CreatedAt,
+        since,
+    );
+    let cases = filter_since(
+        filter_network(case::Entity::find(), case::Column::NetworkId, network_id),
+        case::Column::CreatedAt,
+        since,
+    );
+    let reporters = filter_since(
+        filter_network(
+            reporter::Entity::find(),
+            reporter::Column::NetworkId,
+            network_id,
+        ),
+        reporter::Column::CreatedAt,
+        since,
+    );
+
+    let mut networks: BTreeMap<String, NetworkBreakdown> = BTreeMap::new();
+
+    for (id, count) in count_grouped(db, addresses.clone(), address::Column::NetworkId).await? {
+        network_entry(&mut networks, id).addresses = count;
+    }
+    for (id, count) in count_grouped(db, assets.clone(), asset::Column::NetworkId).await? {
+        network_entry(&mut networks, id).assets = count;
+    }
+    for (id, count) in count_grouped(db, cases, case::Column::NetworkId).await? {
+        network_entry(&mut networks, id).cases = count;
+    }
+    for (id, count) in count_grouped(db, reporters, reporter::Column::NetworkId).await? {
+        network_entry(&mut networks, id).reporters = count;
     }
 
-    /// Get a chart statistics
-    #[instrument(level = "debug", skip(self, ctx))]
-    pub async fn get_charts(&self, ctx: &Context<'_>) -> Result<Charts> {
-        let db = ctx.data_unchecked::<DatabaseConnection>();
-        let weeks = get_past_weeks();
+    let address_categories: Vec<(Category, u64)> =
+        count_grouped(db, addresses.clone(), address::Column::Category).await?;
+    let asset_categories: Vec<(Category, u64)> =
+        count_grouped(db, assets.clone(), asset::Column::Category).await?;
+
+    let count_category = |counts: &[(Category, u64)], category: Category| {
+        counts
+            .iter()
+            .find(|(value, _)| *value == category)
+            .map_or(0, |(_, count)| *count)
+    };
+
+    let categories = Category::iter()
+        .map(|category| CategoryBreakdown {
+            category,
+            addresses: count_category(&address_categories, category),
+            assets: count_category(&asset_categories, category),
+        })
+        .collect();
 
-        let mut labels = vec![];
-        let mut addresses = vec![];
-        let mut assets = vec![];
-        let mut cases = vec![];
+    let address_risks: Vec<(i16, u64)> =
+        count_grouped(db, addresses, address::Column::Risk).await?;
+    let asset_risks: Vec<(i16, u64)> = count_grouped(db, assets, asset::Column::Risk).await?;
 
-        for (year, week) in weeks {
-            let label = format!("{}:{}", year, week);
-            labels.push(label);
+    let sum_bucket = |risks: &[(i16, u64)], min: i16, max: i16| {
+        risks
+            .iter()
+            .filter(|(risk, _)| (min..=max).contains(risk))
+            .map(|(_, count)| count)
+            .sum()
+    };
 
-            let addresses_count =
-                count_rows_per_week(db, address::Entity::find(), year, week).await?;
-            addresses.push(addresses_count);
+    let risks = RISK_BUCKETS
+        .iter()
+        .map(|(bucket, min_risk, max_risk)| RiskBreakdown {
+            bucket: bucket.to_string(),
+            min_risk: *min_risk,
+            max_risk: *max_risk,
+            addresses: sum_bucket(&address_risks, *min_risk, *max_risk),
+            assets: sum_bucket(&asset_risks, *min_risk, *max_risk),
+        })
+        .collect();
 
-            let assets_count = count_rows_per_week(db, asset::Entity::find(), year, week).await?;
-            assets.push(assets_count);
+    Ok(Breakdowns {
+        networks: networks.into_values().collect(),
+        categories,
+        risks,
+    })
+}
 
-            let cases_count = count_rows_per_week(db, case::Entity::find(), year, week).await?;
-            cases.push(cases_count);
-        }
+fn network_entry(
+    networks: &mut BTreeMap<String, NetworkBreakdown>,
+    network_id: String,
+) -> &mut NetworkBreakdown {
+    networks
+        .entry(network_id.clone())
+        .or_insert_with(|| NetworkBreakdown {
+            network_id,
+            ..Default::default()
+        })
+}
 
-        let charts = Charts {
-            labels,
-            addresses,
-            assets,
-            cases,
-        };
+fn filter_network<M: EntityTrait>(
+    query: Select<M>,
+    column: M::Column,
+    network_id: Option<&str>,
+) -> Select<M> {
+    match network_id {
+        Some(network_id) => query.filter(column.eq(network_id)),
+        None => query,
+    }
+}
 
-        Ok(charts)
+fn filter_since<M: EntityTrait>(
+    query: Select<M>,
+    column: M::Column,
+    since: Option<NaiveDateTime>,
+) -> Select<M> {
+    match since {
+        Some(since) => query.filter(column.gte(since)),
+        None => query,
     }
 }
 
+/// Counts rows of the query grouped by the given column
+async fn count_grouped<M, V>(
+    db: &DatabaseConnection,
+    query: Select<M>,
+    column: M::Column,
+) -> Result<Vec<(V, u64)>, DbErr>
+where
+    M: EntityTrait,
+    V: TryGetable,
+{
+    let rows: Vec<(V, i64)> = query
+        .select_only()
+        .column(column)
+        .column_as(Expr::col(column).count(), "count")
+        .group_by(column)
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(value, count)| (value, count as u64))
+        .collect())
+}
+
 fn get_current_week() -> (i32, u32) {
     let now = Utc::now();
     let iso_week = now.iso_week();
     (iso_week.year(), iso_week.week())
 }
 
-fn get_past_weeks() -> Vec<(i32, u32)> {
+fn get_past_weeks(length: usize) -> Vec<(i32, u32)> {
     let mut weeks = Vec::new();
     let mut current_date = Utc::now();
 
@@ -141,7 +403,7 @@ fn get_past_weeks() -> Vec<(i32, u32)> {
             - chrono::Duration::days(current_date.weekday().num_days_from_monday() as i64);
     }
 
-    for _ in 0..CHART_LENGTH {
+    for _ in 0..length {
         let iso_week = current_date.iso_week();
         weeks.push((iso_week.year(), iso_week.week()));
 
@@ -153,8 +415,17 @@ fn get_past_weeks() -> Vec<(i32, u32)> {
     weeks
 }
 
-async fn get_reporter_dashboard(db: &DatabaseConnection) -> Result<(String, u64)> {
-    let stakes: Vec<String> = reporter::Entity::find()
+async fn get_reporter_dashboard(
+    db: &DatabaseConnection,
+    network_id: Option<&str>,
+) -> Result<(String, u64), DbErr> {
+    let query = filter_network(
+        reporter::Entity::find(),
+        reporter::Column::NetworkId,
+        network_id,
+    );
+
+    let stakes: Vec<String> = query
         .select_only()
         .column(reporter::Column::Stake)
         .into_tuple()
@@ -166,8 +437,10 @@ async fn get_reporter_dashboard(db: &DatabaseConnection) -> Result<(String, u64)
     let total_reporters_count = stakes.len() as u64;
     let staked_by_reporters = stakes
         .iter()
-        .map(|s: &String| U256::from_dec_str(s).unwrap())
-        .fold(U256::zero(), |acc, x| acc + x)
+        .map(|s: &String| {
+            U256::from_dec_str(s).map_err(|e| DbErr::Custom(format!("Invalid stake {s}: {e}")))
+        })
+        .try_fold(U256::zero(), |acc, x| x.map(|x| acc + x))?
         .to_string();
 
     Ok((staked_by_reporters, total_reporters_count))
@@ -175,10 +448,11 @@ async fn get_reporter_dashboard(db: &DatabaseConnection) -> Result<(String, u64)
 
 async fn get_case_dashboard(
     db: &DatabaseConnection,
+    network_id: Option<&str>,
     year: i32,
     week: u32,
-) -> Result<(u64, u64, Vec<case::Model>, Vec<case::Model>)> {
-    let query = case::Entity::find();
+) -> Result<(u64, u64, Vec<case::Model>, Vec<case::Model>), DbErr> {
+    let query = filter_network(case::Entity::find(), case::Column::NetworkId, network_id);
 
     let total_case_count = query.clone().count(db).await?;
     let new_weekly_case_count = count_rows_per_week(db, query.clone(), year, week).await?;
@@ -204,10 +478,15 @@ async fn get_case_dashboard(
 
 async fn get_address_dashboard(
     db: &DatabaseConnection,
+    network_id: Option<&str>,
     year: i32,
     week: u32,
-) -> Result<(u64, u64, Vec<address::Model>)> {
-    let query = address::Entity::find();
+) -> Result<(u64, u64, Vec<address::Model>), DbErr> {
+    let query = filter_network(
+        address::Entity::find(),
+        address::Column::NetworkId,
+        network_id,
+    );
 
     let total_addresses_count = query.clone().count(db).await?;
     let new_weekly_address_count = count_rows_per_week(db, query.clone(), year, week).await?;
@@ -226,10 +505,11 @@ async fn get_address_dashboard(
 
 async fn get_asset_dashboard(
     db: &DatabaseConnection,
+    network_id: Option<&str>,
     year: i32,
     week: u32,
-) -> Result<(u64, u64, Vec<asset::Model>)> {
-    let query = asset::Entity::find();
+) -> Result<(u64, u64, Vec<asset::Model>), DbErr> {
+    let query = filter_network(asset::Entity::find(), asset::Column::NetworkId, network_id);
 
     let total_asset_count = query.clone().count(db).await?;
     let new_weekly_asset_count = count_rows_per_week(db, query.clone(), year, week).await?;
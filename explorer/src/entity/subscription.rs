@@ -0,0 +1,176 @@
+use {
+    async_graphql::{
+        futures_util::{
+            future::ready,
+            stream::{self, Stream, StreamExt},
+        },
+        Context, InputObject, Subscription,
+    },
+    tokio::sync::broadcast::{self, error::RecvError},
+};
+
+use crate::entity::{address, asset, case, reporter, types::Category};
+
+/// The number of updates a subscriber may lag behind before missing them
+pub const UPDATES_CAPACITY: usize = 1024;
+
+/// Entity stored by the event handler
+#[derive(Clone, Debug)]
+pub enum EntityUpdate {
+    Address(address::Model),
+    Asset(asset::Model),
+    Case(case::Model),
+    Reporter(reporter::Model),
+}
+
+/// Broadcast channel of stored entities, feeding GraphQL subscriptions
+#[derive(Clone)]
+pub struct EntityUpdates(broadcast::Sender<EntityUpdate>);
+
+impl Default for EntityUpdates {
+    fn default() -> Self {
+        Self(broadcast::channel(UPDATES_CAPACITY).0)
+    }
+}
+
+impl EntityUpdates {
+    /// Sends the update to all current subscribers
+    pub fn publish(&self, update: EntityUpdate) {
+        // Sending fails only when there are no subscribers
+        if self.0.send(update).is_err() {
+            tracing::trace!("No subscribers for entity update");
+        }
+    }
+
+    fn subscribe(&self) -> impl Stream<Item = EntityUpdate> {
+        stream::unfold(self.0.subscribe(), |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(update) => return Some((update, receiver)),
+                    Err(RecvError::Lagged(count)) => {
+                        tracing::warn!(count, "Subscriber lagged behind, updates are skipped");
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+}
+
+/// Conditions to filter address and asset updates by
+#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
+pub struct RiskUpdateFilter {
+    pub network_id: Option<String>,
+    pub category: Option<Category>,
+    pub min_risk: Option<u8>,
+}
+
+impl RiskUpdateFilter {
+    fn matches(&self, network_id: &str, category: Category, risk: i16) -> bool {
+        self.network_id.as_ref().map_or(true, |id| id == network_id)
+            && self.category.map_or(true, |c| c == category)
+            && self.min_risk.map_or(true, |min| risk >= min.into())
+    }
+}
+
+/// Conditions to filter case and reporter updates by
+#[derive(Clone, Eq, PartialEq, InputObject, Debug, Default)]
+pub struct NetworkUpdateFilter {
+    pub network_id: Option<String>,
+}
+
+impl NetworkUpdateFilter {
+    fn matches(&self, network_id: &str) -> bool {
+        self.network_id.as_ref().map_or(true, |id| id == network_id)
+    }
+}
+
+/// The GraphQl Subscription root
+#[derive(Default)]
+pub struct Subscription;
+
+/// Subscriptions to entities created or updated by the indexers
+#[Subscription]
+impl Subscription {
+    /// Address is created or updated
+    async fn address_changed(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address update filter")] filter: Option<RiskUpdateFilter>,
+    ) -> impl Stream<Item = address::Model> {
+        let filter = filter.unwrap_or_default();
+
+        ctx.data_unchecked::<EntityUpdates>()
+            .subscribe()
+            .filter_map(move |update| {
+                ready(match update {
+                    EntityUpdate::Address(address)
+                        if filter.matches(&address.network_id, address.category, address.risk) =>
+                    {
+                        Some(address)
+                    }
+                    _ => None,
+                })
+            })
+    }
+
+    /// Asset is created or updated
+    async fn asset_changed(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Asset update filter")] filter: Option<RiskUpdateFilter>,
+    ) -> impl Stream<Item = asset::Model> {
+        let filter = filter.unwrap_or_default();
+
+        ctx.data_unchecked::<EntityUpdates>()
+            .subscribe()
+            .filter_map(move |update| {
+                ready(match update {
+                    EntityUpdate::Asset(asset)
+                        if filter.matches(&asset.network_id, asset.category, asset.risk) =>
+                    {
+                        Some(asset)
+                    }
+                    _ => None,
+                })
+            })
+    }
+
+    /// Case is created or updated
+    async fn case_changed(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Case update filter")] filter: Option<NetworkUpdateFilter>,
+    ) -> impl Stream<Item = case::Model> {
+        let filter = filter.unwrap_or_default();
+
+        ctx.data_unchecked::<EntityUpdates>()
+            .subscribe()
+            .filter_map(move |update| {
+                ready(match update {
+                    EntityUpdate::Case(case) if filter.matches(&case.network_id) => Some(case),
+                    _ => None,
+                })
+            })
+    }
+
+    /// Reporter is created or updated
+    async fn reporter_changed(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Reporter update filter")] filter: Option<NetworkUpdateFilter>,
+    ) -> impl Stream<Item = reporter::Model> {
+        let filter = filter.unwrap_or_default();
+
+        ctx.data_unchecked::<EntityUpdates>()
+            .subscribe()
+            .filter_map(move |update| {
+                ready(match update {
+                    EntityUpdate::Reporter(reporter) if filter.matches(&reporter.network_id) => {
+                        Some(reporter)
+                    }
+                    _ => None,
+                })
+            })
+    }
+}
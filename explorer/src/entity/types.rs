@@ -63,7 +63,7 @@ impl From<HapiCoreNetwork> for NetworkBackend {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Serialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "category")]
 pub enum Category {
     #[sea_orm(string_value = "none")]
@@ -236,7 +236,7 @@ impl fmt::Display for ReporterStatus {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Serialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "case_status")]
 pub enum CaseStatus {
     #[sea_orm(string_value = "closed")]
@@ -262,3 +262,48 @@ impl fmt::Display for CaseStatus {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(16))")]
+pub enum EntityType {
+    #[sea_orm(string_value = "address")]
+    Address,
+    #[sea_orm(string_value = "asset")]
+    Asset,
+    #[sea_orm(string_value = "case")]
+    Case,
+    #[sea_orm(string_value = "reporter")]
+    Reporter,
+    #[sea_orm(string_value = "network")]
+    Network,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Enum, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(16))")]
+pub enum ApiKeyRole {
+    #[sea_orm(string_value = "admin")]
+    Admin,
+    #[sea_orm(string_value = "read_only")]
+    ReadOnly,
+}
+
+impl FromStr for ApiKeyRole {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "admin" => Ok(ApiKeyRole::Admin),
+            "read_only" => Ok(ApiKeyRole::ReadOnly),
+            _ => Err(anyhow!("Unknown API key role: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for ApiKeyRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApiKeyRole::Admin => write!(f, "admin"),
+            ApiKeyRole::ReadOnly => write!(f, "read_only"),
+        }
+    }
+}
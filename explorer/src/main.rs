@@ -2,11 +2,14 @@ use {
     anyhow::Result,
     clap::{command, Parser, Subcommand},
     hapi_explorer::{
-        application::Application, configuration::get_configuration, entity::types::NetworkBackend,
+        application::Application,
+        configuration::get_configuration,
+        entity::types::{ApiKeyRole, NetworkBackend},
         observability::setup_tracing,
     },
     sea_orm_cli::MigrateSubcommands,
     tokio::net::TcpListener,
+    uuid::Uuid,
 };
 
 #[derive(Subcommand, PartialEq, Eq, Debug, Clone)]
@@ -47,6 +50,25 @@ pub enum NetworkSubcommands {
     },
 }
 
+#[derive(Subcommand, PartialEq, Eq, Debug, Clone)]
+pub enum ApiKeySubcommands {
+    #[command(about = "Create new API key")]
+    Create {
+        #[arg(long, help = "API key display name")]
+        name: String,
+
+        #[arg(long, help = "API key role (admin, read_only)")]
+        role: ApiKeyRole,
+    },
+    #[command(about = "List API keys")]
+    List,
+    #[command(about = "Revoke API key")]
+    Revoke {
+        #[arg(long, help = "API key id")]
+        id: Uuid,
+    },
+}
+
 #[derive(Parser)]
 enum ExplorerCli {
     #[command(about = "Run explorer server")]
@@ -56,6 +78,11 @@ enum ExplorerCli {
         #[command(subcommand)]
         subcommand: NetworkSubcommands,
     },
+    #[command(about = "Run commands related to API keys")]
+    ApiKey {
+        #[command(subcommand)]
+        subcommand: ApiKeySubcommands,
+    },
     #[command(about = "Run migrations")]
     Migrate {
         #[command(subcommand)]
@@ -67,6 +94,16 @@ enum ExplorerCli {
         #[arg(long, default_value = None, help = "Network chain id (optional)")]
         chain_id: Option<String>,
     },
+    #[command(about = "Issue a new indexer token and revoke the previous one")]
+    RotateIndexerToken {
+        #[arg(long, help = "Indexer id")]
+        id: Uuid,
+    },
+    #[command(about = "Revoke indexer token")]
+    RevokeIndexerToken {
+        #[arg(long, help = "Indexer id")]
+        id: Uuid,
+    },
 }
 
 #[tokio::main]
@@ -107,11 +144,30 @@ async fn main() -> Result<()> {
                 authority,
             } => app.update_network(id, name, authority, stake_token).await,
         },
+        ExplorerCli::ApiKey { subcommand } => match subcommand {
+            ApiKeySubcommands::Create { name, role } => {
+                app.create_api_key(name, role).await?;
+
+                Ok(())
+            }
+            ApiKeySubcommands::List => {
+                app.list_api_keys().await?;
+
+                Ok(())
+            }
+            ApiKeySubcommands::Revoke { id } => app.revoke_api_key(id).await,
+        },
         ExplorerCli::CreateIndexer { backend, chain_id } => {
             app.create_indexer(backend, chain_id).await?;
 
             Ok(())
         }
+        ExplorerCli::RotateIndexerToken { id } => {
+            app.rotate_indexer_token(id).await?;
+
+            Ok(())
+        }
+        ExplorerCli::RevokeIndexerToken { id } => app.revoke_indexer_token(id).await,
     }?;
 
     app.shutdown().await
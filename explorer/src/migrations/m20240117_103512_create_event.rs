@@ -0,0 +1,80 @@
+use super::Network;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Event::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Event::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Event::NetworkId).string().not_null())
+                    .col(ColumnDef::new(Event::Name).string().not_null())
+                    .col(ColumnDef::new(Event::TxHash).string().not_null())
+                    .col(ColumnDef::new(Event::TxIndex).big_integer().not_null())
+                    .col(ColumnDef::new(Event::Timestamp).timestamp().not_null())
+                    .col(ColumnDef::new(Event::EntityType).string_len(16).not_null())
+                    .col(ColumnDef::new(Event::EntityId).string().not_null())
+                    .col(ColumnDef::new(Event::Data).json_binary().not_null())
+                    .col(ColumnDef::new(Event::CreatedAt).timestamp().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-event_network_id")
+                            .from(Event::Table, Event::NetworkId)
+                            .to(Network::Table, Network::Id)
+                            .on_delete(ForeignKeyAction::NoAction)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-event_entity")
+                    .table(Event::Table)
+                    .col(Event::NetworkId)
+                    .col(Event::EntityType)
+                    .col(Event::EntityId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-event_timestamp")
+                    .table(Event::Table)
+                    .col(Event::Timestamp)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Event::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum Event {
+    Table,
+    Id,
+    NetworkId,
+    Name,
+    TxHash,
+    TxIndex,
+    Timestamp,
+    EntityType,
+    EntityId,
+    Data,
+    CreatedAt,
+}
@@ -0,0 +1,34 @@
+use super::Event;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-event_tx")
+                    .table(Event::Table)
+                    .col(Event::NetworkId)
+                    .col(Event::TxHash)
+                    .col(Event::TxIndex)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-event_tx")
+                    .table(Event::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
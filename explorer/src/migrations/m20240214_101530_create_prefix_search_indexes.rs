@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Indexes of search by id and by address, account or hash prefix.
+/// Prefix matches need `text_pattern_ops`, which is not supported by the index builder
+const INDEXES: &[(&str, &str, &str)] = &[
+    (
+        "idx-address_address_prefix",
+        "address",
+        "address text_pattern_ops",
+    ),
+    ("idx-address_case_id", "address", "case_id"),
+    ("idx-address_reporter_id", "address", "reporter_id"),
+    (
+        "idx-asset_address_prefix",
+        "asset",
+        "address text_pattern_ops",
+    ),
+    ("idx-asset_case_id", "asset", "case_id"),
+    ("idx-asset_reporter_id", "asset", "reporter_id"),
+    ("idx-case_reporter_id", "case", "reporter_id"),
+    (
+        "idx-reporter_account_prefix",
+        "reporter",
+        "account text_pattern_ops",
+    ),
+    (
+        "idx-event_tx_hash_prefix",
+        "event",
+        "tx_hash text_pattern_ops",
+    ),
+    (
+        "idx-event_entity_id_prefix",
+        "event",
+        "entity_id text_pattern_ops",
+    ),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (name, table, column) in INDEXES {
+            db.execute_unprepared(&format!(r#"CREATE INDEX "{name}" ON "{table}" ({column})"#))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (name, _, _) in INDEXES {
+            db.execute_unprepared(&format!(r#"DROP INDEX "{name}""#))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
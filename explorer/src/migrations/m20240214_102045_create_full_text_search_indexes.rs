@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// GIN indexes of case and reporter full-text search, the expression must match the search query
+const INDEXES: &[(&str, &str)] = &[
+    ("idx-case_full_text", "case"),
+    ("idx-reporter_full_text", "reporter"),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (name, table) in INDEXES {
+            db.execute_unprepared(&format!(
+                r#"CREATE INDEX "{name}" ON "{table}" USING GIN (to_tsvector('simple', name || ' ' || url))"#
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (name, _) in INDEXES {
+            db.execute_unprepared(&format!(r#"DROP INDEX "{name}""#))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Indexes of search by EVM address, account or hash, which are stored checksummed
+/// and matched in lowercase. Serve both prefix and exact matches
+const INDEXES: &[(&str, &str, &str)] = &[
+    (
+        "idx-address_address_lower",
+        "address",
+        "lower(address) text_pattern_ops",
+    ),
+    (
+        "idx-asset_address_lower",
+        "asset",
+        "lower(address) text_pattern_ops",
+    ),
+    (
+        "idx-reporter_account_lower",
+        "reporter",
+        "lower(account) text_pattern_ops",
+    ),
+    (
+        "idx-event_tx_hash_lower",
+        "event",
+        "lower(tx_hash) text_pattern_ops",
+    ),
+    (
+        "idx-event_entity_id_lower",
+        "event",
+        "lower(entity_id) text_pattern_ops",
+    ),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (name, table, column) in INDEXES {
+            db.execute_unprepared(&format!(r#"CREATE INDEX "{name}" ON "{table}" ({column})"#))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (name, _, _) in INDEXES {
+            db.execute_unprepared(&format!(r#"DROP INDEX "{name}""#))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use super::{Address, Asset, Case, Event, Reporter};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tables of entities that are updated by events
+fn tables() -> Vec<DynIden> {
+    vec![
+        Address::Table.into_iden(),
+        Asset::Table.into_iden(),
+        Case::Table.into_iden(),
+        Reporter::Table.into_iden(),
+    ]
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Event::Table)
+                    .add_column(
+                        ColumnDef::new(EventBlock::Block)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(EventBlock::BlockIndex)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in tables() {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(
+                            ColumnDef::new(EventBlock::UpdatedBlock)
+                                .big_integer()
+                                .not_null()
+                                .default(0),
+                        )
+                        .add_column(
+                            ColumnDef::new(EventBlock::UpdatedBlockIndex)
+                                .big_integer()
+                                .not_null()
+                                .default(0),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(EventBlock::UpdatedBlock)
+                        .drop_column(EventBlock::UpdatedBlockIndex)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Event::Table)
+                    .drop_column(EventBlock::Block)
+                    .drop_column(EventBlock::BlockIndex)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum EventBlock {
+    Block,
+    BlockIndex,
+    UpdatedBlock,
+    UpdatedBlockIndex,
+}
@@ -9,14 +9,27 @@ mod m20231127_170630_create_case_status_type;
 mod m20231205_131413_create_indexer;
 mod m20231205_131413_create_network;
 mod m20231211_164133_create_network_backend;
+mod m20240117_103512_create_event;
+mod m20240124_151240_create_event_tx_index;
+mod m20240131_094215_add_indexer_token_id;
+mod m20240207_112408_create_api_key;
+mod m20240214_101530_create_prefix_search_indexes;
+mod m20240214_102045_create_full_text_search_indexes;
+mod m20240215_093015_create_lowercase_search_indexes;
+mod m20240216_104210_add_overridden_at;
+mod m20240219_110315_add_event_block;
 
 pub(super) use m20231127_162603_create_category_type::Category;
 pub(super) use m20231127_165849_create_reporter_role_type::ReporterRole;
 pub(super) use m20231127_170357_create_reporter_status_type::ReporterStatus;
 pub(super) use m20231127_170630_create_case_status_type::CaseStatus;
+pub(super) use m20231205_131413_create_indexer::Indexer;
 pub(super) use m20231205_131413_create_network::Network;
 pub(super) use m20231211_164133_create_network_backend::NetworkBackend;
+pub(super) use m20240117_103512_create_event::Event;
 
+pub(super) use m20231127_140636_create_address::Address;
+pub(super) use m20231127_160838_create_asset::Asset;
 pub(super) use m20231127_161317_create_reporter::Reporter;
 pub(super) use m20231127_162130_create_case::Case;
 
@@ -39,6 +52,15 @@ impl MigratorTrait for Migrator {
             Box::new(m20231127_162130_create_case::Migration),
             Box::new(m20231127_140636_create_address::Migration),
             Box::new(m20231127_160838_create_asset::Migration),
+            Box::new(m20240117_103512_create_event::Migration),
+            Box::new(m20240124_151240_create_event_tx_index::Migration),
+            Box::new(m20240131_094215_add_indexer_token_id::Migration),
+            Box::new(m20240207_112408_create_api_key::Migration),
+            Box::new(m20240214_101530_create_prefix_search_indexes::Migration),
+            Box::new(m20240214_102045_create_full_text_search_indexes::Migration),
+            Box::new(m20240215_093015_create_lowercase_search_indexes::Migration),
+            Box::new(m20240216_104210_add_overridden_at::Migration),
+            Box::new(m20240219_110315_add_event_block::Migration),
         ]
     }
 }
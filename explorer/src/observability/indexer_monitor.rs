@@ -0,0 +1,145 @@
+use {
+    anyhow::{bail, Result},
+    sea_orm::DatabaseConnection,
+    serde::Serialize,
+    std::{collections::HashMap, time::Duration},
+    uuid::Uuid,
+};
+
+use crate::{
+    entity::indexer::{HealthThresholds, IndexerHealth, IndexerStatus},
+    service::EntityQuery,
+};
+
+const INDEXER_HEARTBEAT_AGE_METRIC: &str = "indexer_seconds_since_heartbeat";
+const INDEXER_STATUS_METRIC: &str = "indexer_status";
+
+/// Alert posted to the webhook when an indexer becomes lagging or dead
+#[derive(Serialize)]
+struct IndexerAlert<'a> {
+    #[serde(flatten)]
+    health: &'a IndexerHealth,
+    previous_status: Option<IndexerStatus>,
+}
+
+/// Periodically evaluates the indexer health, exports it as metrics and alerts on stale indexers
+pub(crate) struct IndexerMonitor {
+    db: DatabaseConnection,
+    thresholds: HealthThresholds,
+    interval: Duration,
+    alert_webhook_url: Option<String>,
+    web_client: reqwest::Client,
+    statuses: HashMap<Uuid, IndexerStatus>,
+}
+
+impl IndexerMonitor {
+    pub(crate) fn new(
+        db: DatabaseConnection,
+        thresholds: HealthThresholds,
+        interval: Duration,
+        alert_webhook_url: Option<String>,
+    ) -> Self {
+        Self {
+            db,
+            thresholds,
+            interval,
+            alert_webhook_url,
+            web_client: reqwest::Client::new(),
+            statuses: HashMap::new(),
+        }
+    }
+
+    pub(crate) async fn run(mut self) {
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.check().await {
+                tracing::error!(error = ?e, "Indexer health check failed");
+            }
+        }
+    }
+
+    async fn check(&mut self) -> Result<()> {
+        let now = chrono::Utc::now().naive_utc();
+        let indexers = EntityQuery::find_indexers(&self.db, None).await?;
+
+        for indexer in indexers {
+            let health = indexer.health(&self.thresholds, now);
+            update_indexer_metrics(&health);
+
+            let previous_status = self.statuses.get(&health.id).copied();
+
+            if previous_status == Some(health.status) {
+                continue;
+            }
+
+            if health.status != IndexerStatus::Healthy {
+                tracing::warn!(
+                    indexer_id = %health.id,
+                    network_id = %health.network_id,
+                    status = %health.status,
+                    seconds_since_heartbeat = health.seconds_since_heartbeat,
+                    "Indexer is stale"
+                );
+
+                // The status is not recorded until the alert is delivered, so it is retried on the next check
+                if let Err(e) = self.send_alert(&health, previous_status).await {
+                    tracing::error!(error = ?e, indexer_id = %health.id, "Failed to send indexer alert");
+                    continue;
+                }
+            }
+
+            self.statuses.insert(health.id, health.status);
+        }
+
+        Ok(())
+    }
+
+    async fn send_alert(
+        &self,
+        health: &IndexerHealth,
+        previous_status: Option<IndexerStatus>,
+    ) -> Result<()> {
+        let Some(url) = &self.alert_webhook_url else {
+            return Ok(());
+        };
+
+        let response = self
+            .web_client
+            .post(url)
+            .json(&IndexerAlert {
+                health,
+                previous_status,
+            })
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            bail!("Alert webhook responded with {}", response.status());
+        }
+
+        Ok(())
+    }
+}
+
+fn update_indexer_metrics(health: &IndexerHealth) {
+    let labels = vec![
+        ("indexer_id", health.id.to_string()),
+        ("network_id", health.network_id.clone()),
+    ];
+
+    let status = match health.status {
+        IndexerStatus::Healthy => 0.0,
+        IndexerStatus::Lagging => 1.0,
+        IndexerStatus::Dead => 2.0,
+    };
+
+    metrics::gauge!(
+        INDEXER_HEARTBEAT_AGE_METRIC,
+        health.seconds_since_heartbeat as f64,
+        &labels
+    );
+    metrics::gauge!(INDEXER_STATUS_METRIC, status, &labels);
+}
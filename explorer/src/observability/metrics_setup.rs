@@ -1,6 +1,5 @@
 use {
     anyhow::Result,
-    async_graphql::{InputType, OutputType},
     axum::{extract::MatchedPath, http::Request, middleware::Next, response::IntoResponse},
     metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle},
     sea_orm::EntityTrait,
@@ -9,8 +8,7 @@ use {
 
 use crate::{
     application::Application,
-    entity::{address, asset, case, network, pagination::EntityInput, reporter, EntityFilter},
-    service::EntityQuery,
+    entity::{address, asset, case, network, reporter},
 };
 
 const REQUEST_DURATION_METRIC: &str = "http_requests_duration_seconds";
@@ -90,19 +88,15 @@ impl Application {
         Ok(())
     }
 
+    // Metrics are counted over all entities, bypassing the page size limit of listing queries
     async fn fetch_metrics<M, F>(&self, metric_fn: F) -> Result<()>
     where
-        M: EntityTrait + EntityFilter,
-        <M as EntityFilter>::Filter: InputType + Default,
-        <M as EntityFilter>::Condition: InputType + Default,
-        M::Model: OutputType,
-        M::Column: From<<M as EntityFilter>::Condition>,
+        M: EntityTrait,
         F: Fn(M::Model, MetricOp),
     {
-        let page =
-            EntityQuery::find_many::<M>(&self.state.database_conn, EntityInput::default()).await?;
+        let entities = M::find().all(&self.state.database_conn).await?;
 
-        for entity in page.data {
+        for entity in entities {
             metric_fn(entity, MetricOp::Increment);
         }
 
@@ -1,6 +1,8 @@
+mod indexer_monitor;
 mod metrics_setup;
 mod tracing_setup;
 
+pub(crate) use indexer_monitor::IndexerMonitor;
 pub(crate) use metrics_setup::{
     setup_metrics, track_metrics, update_address_metrics, update_asset_metrics,
     update_case_metrics, update_network_metrics, update_reporter_metrics, MetricOp,
@@ -1,9 +1,10 @@
 use {
     anyhow::{anyhow, Result},
+    async_graphql_axum::GraphQLSubscription,
     axum::{
         http::HeaderValue,
         middleware,
-        routing::{get, post, put},
+        routing::{get, get_service, post, put},
         Extension, Router, Server,
     },
     std::{future::ready, net::SocketAddr},
@@ -14,8 +15,8 @@ use {
 
 use super::{
     handlers::{
-        auth_handler, event_handler, graphiql_playground, graphql_handler, health_handler,
-        indexer_handler, indexer_heartbeat_handler, stats_handler,
+        api_key_handler, auth_handler, event_handler, graphiql_playground, graphql_handler,
+        health_handler, indexer_handler, indexer_heartbeat_handler, stats_handler,
     },
     schema::create_graphql_schema,
 };
@@ -42,7 +43,13 @@ impl Application {
     }
 
     async fn create_router(&self, origins: &Option<Vec<String>>) -> Result<Router> {
-        let schema = create_graphql_schema(self.state.database_conn.clone())?;
+        let schema = create_graphql_schema(
+            self.state.database_conn.clone(),
+            self.state.updates.clone(),
+            self.state.jwt_secret.clone(),
+            self.state.max_page_size,
+            self.state.health_thresholds,
+        )?;
         let cors = self.create_cors_layer(origins)?;
 
         let router = Router::new()
@@ -55,9 +62,26 @@ impl Application {
                 )),
             )
             .route("/stats", get(stats_handler))
-            .route("/graphql", get(graphiql_playground).post(graphql_handler))
+            .route(
+                "/graphql",
+                get(graphiql_playground).post(graphql_handler).route_layer(
+                    middleware::from_fn_with_state(self.state.clone(), api_key_handler),
+                ),
+            )
+            .route(
+                "/graphql/ws",
+                get_service(GraphQLSubscription::new(schema.clone())).route_layer(
+                    middleware::from_fn_with_state(self.state.clone(), api_key_handler),
+                ),
+            )
             .route("/indexer", get(indexer_handler))
-            .route("/indexer/:id/heartbeat", put(indexer_heartbeat_handler))
+            .route(
+                "/indexer/:id/heartbeat",
+                put(indexer_heartbeat_handler).route_layer(middleware::from_fn_with_state(
+                    self.state.clone(),
+                    auth_handler,
+                )),
+            )
             .with_state(self.state.clone())
             .layer(Extension(schema))
             .layer(cors);
@@ -105,6 +129,10 @@ impl Application {
             async move { server.await.map_err(|e| anyhow!(e)) },
         ));
 
+        if let Some(monitor) = self.indexer_monitor.take() {
+            self.monitor_handle = Some(tokio::spawn(monitor.run()));
+        }
+
         Ok(())
     }
 
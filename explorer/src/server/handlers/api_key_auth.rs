@@ -0,0 +1,44 @@
+use axum::{
+    extract::State,
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::IntoResponse,
+};
+
+use crate::{application::AppState, error::AppError, service::EntityQuery};
+
+const API_KEY_HEADER: &str = "X-API-Key";
+
+/// Resolves the API key role and passes it to the handler
+pub(crate) async fn api_key_handler<B>(
+    state: State<AppState>,
+    mut req: Request<B>,
+    next: Next<B>,
+) -> Result<impl IntoResponse, AppError> {
+    let key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_owned());
+
+    match key {
+        Some(key) => {
+            let api_key = EntityQuery::find_api_key(&state.database_conn, &key)
+                .await?
+                .ok_or_else(|| {
+                    AppError::new(StatusCode::UNAUTHORIZED, "Invalid API key".to_string())
+                })?;
+
+            req.extensions_mut().insert(api_key.role);
+        }
+        None if state.require_api_key => {
+            return Err(AppError::new(
+                StatusCode::UNAUTHORIZED,
+                "You are not authenticated, please provide API key".to_string(),
+            ))
+        }
+        None => {}
+    }
+
+    Ok(next.run(req).await)
+}
@@ -1,6 +1,6 @@
 use {
     axum::{
-        extract::{Json, State},
+        extract::{Extension, Json, State},
         http::StatusCode,
     },
     hapi_core::client::{
@@ -10,52 +10,156 @@ use {
         },
         events::EventName,
     },
-    hapi_indexer::{PushData, PushPayload},
-    sea_orm::DatabaseConnection,
+    hapi_indexer::{ContractConfiguration, PushData, PushEvent, PushPayload},
+    sea_orm::{DatabaseTransaction, TransactionTrait},
     tracing::instrument,
 };
 
 use crate::{
     application::AppState,
-    entity::{address, asset, case, reporter},
+    entity::{
+        address, asset, case, indexer, network, reporter,
+        subscription::{EntityUpdate, EntityUpdates},
+    },
     error::AppError,
     observability::{
-        update_address_metrics, update_asset_metrics, update_case_metrics, update_reporter_metrics,
-        MetricOp,
+        update_address_metrics, update_asset_metrics, update_case_metrics, update_network_metrics,
+        update_reporter_metrics, MetricOp,
     },
     service::{get_network_id, EntityMutation, EntityQuery},
 };
 
 /// Handle events Requests
-#[instrument(level = "info", skip(state))]
+#[instrument(level = "info", skip(state, indexer))]
 pub(crate) async fn event_handler(
     state: State<AppState>,
+    Extension(indexer): Extension<indexer::Model>,
     Json(payload): Json<PushPayload>,
 ) -> Result<StatusCode, AppError> {
     tracing::info!(event = ?payload.event, "Received event");
-    let event_name = payload.event.name;
-    let timestamp = payload.event.timestamp;
     let db = &state.database_conn;
+    let updates = &state.updates;
 
     let network_id = get_network_id(
         db,
-        payload.network_data.network.into(),
-        payload.network_data.chain_id,
+        payload.network_data.network.clone().into(),
+        payload.network_data.chain_id.clone(),
     )
     .await?;
 
+    if network_id != indexer.network_id {
+        return Err(AppError::new(
+            StatusCode::FORBIDDEN,
+            format!("Indexer is not allowed to send events for network {network_id}"),
+        ));
+    }
+
+    // Indexer may resend events on retries and replays
+    if EntityQuery::find_event(
+        db,
+        &network_id,
+        &payload.event.tx_hash,
+        payload.event.tx_index,
+    )
+    .await?
+    .is_some()
+    {
+        tracing::info!(
+            tx_hash = payload.event.tx_hash,
+            "Event is already processed"
+        );
+        return Ok(StatusCode::OK);
+    }
+
+    // The entity and the event log are updated together, the transaction is rolled back on error.
+    // Subscribers and metrics are notified only after the commit
+    let txn = db.begin().await?;
+    let change = process_payload(&txn, payload.clone(), network_id.clone()).await?;
+    EntityMutation::create_event(&txn, payload, network_id).await?;
+    txn.commit().await?;
+
+    if let Some(change) = change {
+        change.apply(updates);
+    }
+
+    Ok(StatusCode::OK)
+}
+
+/// Entity change made by an event
+enum EntityChange {
+    Address(Option<address::Model>, address::Model),
+    Asset(Option<asset::Model>, asset::Model),
+    Case(Option<case::Model>, case::Model),
+    Reporter(Option<reporter::Model>, reporter::Model),
+    Network(network::Model, network::Model),
+}
+
+impl EntityChange {
+    /// Updates metrics and notifies subscribers
+    fn apply(self, updates: &EntityUpdates) {
+        match self {
+            EntityChange::Address(old, new) => {
+                if let Some(old) = old {
+                    update_address_metrics(old, MetricOp::Decrement);
+                }
+                update_address_metrics(new.clone(), MetricOp::Increment);
+                updates.publish(EntityUpdate::Address(new));
+            }
+            EntityChange::Asset(old, new) => {
+                if let Some(old) = old {
+                    update_asset_metrics(old, MetricOp::Decrement);
+                }
+                update_asset_metrics(new.clone(), MetricOp::Increment);
+                updates.publish(EntityUpdate::Asset(new));
+            }
+            EntityChange::Case(old, new) => {
+                if let Some(old) = old {
+                    update_case_metrics(old, MetricOp::Decrement);
+                }
+                update_case_metrics(new.clone(), MetricOp::Increment);
+                updates.publish(EntityUpdate::Case(new));
+            }
+            EntityChange::Reporter(old, new) => {
+                if let Some(old) = old {
+                    update_reporter_metrics(old, MetricOp::Decrement);
+                }
+                update_reporter_metrics(new.clone(), MetricOp::Increment);
+                updates.publish(EntityUpdate::Reporter(new));
+            }
+            EntityChange::Network(old, new) => {
+                update_network_metrics(old, MetricOp::Decrement);
+                update_network_metrics(new, MetricOp::Increment);
+            }
+        }
+    }
+}
+
+async fn process_payload(
+    db: &DatabaseTransaction,
+    payload: PushPayload,
+    network_id: String,
+) -> Result<Option<EntityChange>, AppError> {
+    let event = payload.event;
+    let event_name = event.name.clone();
+
     match payload.data {
         PushData::Address(address) => {
-            process_address_payload(address, event_name, db, network_id, timestamp).await
+            process_address_payload(address, event_name, db, network_id, &event).await
         }
         PushData::Asset(asset) => {
-            process_asset_payload(asset, event_name, db, network_id, timestamp).await
+            process_asset_payload(asset, event_name, db, network_id, &event).await
         }
         PushData::Case(case) => {
-            process_case_payload(case, event_name, db, network_id, timestamp).await
+            process_case_payload(case, event_name, db, network_id, &event).await
         }
         PushData::Reporter(reporter) => {
-            process_reporter_payload(reporter, event_name, db, network_id, timestamp).await
+            process_reporter_payload(reporter, event_name, db, network_id, &event).await
+        }
+        PushData::Authority(authority) => {
+            process_authority_payload(authority, event_name, db, network_id, &event).await
+        }
+        PushData::Configuration(configuration) => {
+            process_configuration_payload(configuration, event_name, db, network_id, &event).await
         }
     }
 }
@@ -64,185 +168,232 @@ pub(crate) async fn event_handler(
 async fn process_address_payload(
     address: AddressPayload,
     event_name: EventName,
-    db: &DatabaseConnection,
+    db: &DatabaseTransaction,
     network_id: String,
-    timestamp: u64,
-) -> Result<StatusCode, AppError> {
+    event: &PushEvent,
+) -> Result<Option<EntityChange>, AppError> {
     tracing::info!(address = ?address, "Received address");
 
-    let address = match event_name {
-        EventName::CreateAddress => {
-            EntityMutation::create_entity::<address::ActiveModel, _>(
-                db, address, network_id, timestamp,
-            )
-            .await?
-        }
-        EventName::UpdateAddress => {
-            let old = EntityQuery::find_entity_by_id::<address::Entity, _>(
-                db,
-                (network_id.clone(), address.address.clone()),
-            )
-            .await?
-            .ok_or(AppError::invalid_request("This address does not exist"))?;
-
-            let new = EntityMutation::update_entity::<address::ActiveModel, _>(
-                db, address, network_id, timestamp,
-            )
-            .await?;
+    if !matches!(
+        event_name,
+        EventName::CreateAddress | EventName::UpdateAddress | EventName::ConfirmAddress
+    ) {
+        return Err(AppError::invalid_request(&format!(
+            "Received unexpected event with address payload: {event_name}",
+        )));
+    }
 
-            update_address_metrics(old, MetricOp::Decrement);
+    let old = EntityQuery::find_entity_by_id::<address::Entity, _>(
+        db,
+        (network_id.clone(), address.address.clone()),
+    )
+    .await?;
 
-            new
-        }
-        _ => {
-            return Err(AppError::invalid_request(&format!(
-                "Received unexpected event with address payload: {event_name}",
-            )));
-        }
-    };
+    let new =
+        EntityMutation::upsert_entity::<address::ActiveModel, _>(db, address, network_id, event)
+            .await?;
 
-    update_address_metrics(address, MetricOp::Increment);
+    if old.as_ref() == Some(&new) {
+        tracing::info!("Address is not changed by an outdated event");
+        return Ok(None);
+    }
 
-    Ok(StatusCode::OK)
+    Ok(Some(EntityChange::Address(old, new)))
 }
 
 #[instrument(level = "trace", skip(db))]
 async fn process_asset_payload(
     asset: AssetPayload,
     event_name: EventName,
-    db: &DatabaseConnection,
+    db: &DatabaseTransaction,
     network_id: String,
-    timestamp: u64,
-) -> Result<StatusCode, AppError> {
+    event: &PushEvent,
+) -> Result<Option<EntityChange>, AppError> {
     tracing::info!(asset = ?asset, "Received asset");
 
-    let asset = match event_name {
-        EventName::CreateAsset => {
-            EntityMutation::create_entity::<asset::ActiveModel, _>(db, asset, network_id, timestamp)
-                .await?
-        }
-        EventName::UpdateAsset => {
-            let old = EntityQuery::find_entity_by_id::<asset::Entity, _>(
-                db,
-                (
-                    network_id.clone(),
-                    asset.address.clone(),
-                    asset.asset_id.to_string(),
-                ),
-            )
-            .await?
-            .ok_or(AppError::invalid_request("This asset does not exist"))?;
-
-            let new = EntityMutation::update_entity::<asset::ActiveModel, _>(
-                db, asset, network_id, timestamp,
-            )
-            .await?;
+    if !matches!(
+        event_name,
+        EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset
+    ) {
+        return Err(AppError::invalid_request(&format!(
+            "Received unexpected event with asset payload: {event_name}"
+        )));
+    }
 
-            update_asset_metrics(old, MetricOp::Decrement);
+    let old = EntityQuery::find_entity_by_id::<asset::Entity, _>(
+        db,
+        (
+            network_id.clone(),
+            asset.address.clone(),
+            asset.asset_id.to_string(),
+        ),
+    )
+    .await?;
 
-            new
-        }
-        _ => {
-            return Err(AppError::invalid_request(&format!(
-                "Received unexpected event with asset payload: {event_name}"
-            )));
-        }
-    };
+    let new = EntityMutation::upsert_entity::<asset::ActiveModel, _>(db, asset, network_id, event)
+        .await?;
 
-    update_asset_metrics(asset, MetricOp::Increment);
+    if old.as_ref() == Some(&new) {
+        tracing::info!("Asset is not changed by an outdated event");
+        return Ok(None);
+    }
 
-    Ok(StatusCode::OK)
+    Ok(Some(EntityChange::Asset(old, new)))
 }
 
 #[instrument(level = "trace", skip(db))]
 async fn process_case_payload(
     case: CasePayload,
     event_name: EventName,
-    db: &DatabaseConnection,
+    db: &DatabaseTransaction,
     network_id: String,
-    timestamp: u64,
-) -> Result<StatusCode, AppError> {
+    event: &PushEvent,
+) -> Result<Option<EntityChange>, AppError> {
     tracing::info!(case = ?case, "Received case");
 
-    let case = match event_name {
-        EventName::CreateCase => {
-            EntityMutation::create_entity::<case::ActiveModel, _>(db, case, network_id, timestamp)
-                .await?
-        }
-        EventName::UpdateCase => {
-            let old = EntityQuery::find_entity_by_id::<case::Entity, _>(
-                db,
-                (network_id.clone(), case.id),
-            )
-            .await?
-            .ok_or(AppError::invalid_request("This case does not exist"))?;
-
-            let new = EntityMutation::update_entity::<case::ActiveModel, _>(
-                db, case, network_id, timestamp,
-            )
-            .await?;
+    if !matches!(event_name, EventName::CreateCase | EventName::UpdateCase) {
+        return Err(AppError::invalid_request(&format!(
+            "Received unexpected event with case payload: {event_name}",
+        )));
+    }
 
-            update_case_metrics(old, MetricOp::Decrement);
+    let old = EntityQuery::find_entity_by_id::<case::Entity, _>(db, (network_id.clone(), case.id))
+        .await?;
 
-            new
-        }
-        _ => {
-            return Err(AppError::invalid_request(&format!(
-                "Received unexpected event with case payload: {event_name}",
-            )));
-        }
-    };
+    let new =
+        EntityMutation::upsert_entity::<case::ActiveModel, _>(db, case, network_id, event).await?;
 
-    update_case_metrics(case, MetricOp::Increment);
+    if old.as_ref() == Some(&new) {
+        tracing::info!("Case is not changed by an outdated event");
+        return Ok(None);
+    }
 
-    Ok(StatusCode::OK)
+    Ok(Some(EntityChange::Case(old, new)))
 }
 
 #[instrument(level = "trace", skip(db))]
 async fn process_reporter_payload(
     reporter: ReporterPayload,
     event_name: EventName,
-    db: &DatabaseConnection,
+    db: &DatabaseTransaction,
     network_id: String,
-    timestamp: u64,
-) -> Result<StatusCode, AppError> {
+    event: &PushEvent,
+) -> Result<Option<EntityChange>, AppError> {
     tracing::info!(reporter = ?reporter, "Received reporter");
 
-    let reporter = match event_name {
-        EventName::CreateReporter => {
-            EntityMutation::create_entity::<reporter::ActiveModel, _>(
-                db, reporter, network_id, timestamp,
-            )
-            .await?
-        }
-        EventName::UpdateReporter
-        | EventName::ActivateReporter
-        | EventName::DeactivateReporter
-        | EventName::Unstake => {
-            let old = EntityQuery::find_entity_by_id::<reporter::Entity, _>(
-                db,
-                (network_id.clone(), reporter.id),
-            )
-            .await?
-            .ok_or(AppError::invalid_request("This reporter does not exist"))?;
-
-            let new = EntityMutation::update_entity::<reporter::ActiveModel, _>(
-                db, reporter, network_id, timestamp,
-            )
+    if !matches!(
+        event_name,
+        EventName::CreateReporter
+            | EventName::UpdateReporter
+            | EventName::ActivateReporter
+            | EventName::DeactivateReporter
+            | EventName::Unstake
+    ) {
+        return Err(AppError::invalid_request(&format!(
+            "Received unexpected event with reporter payload: {event_name}"
+        )));
+    }
+
+    let old = EntityQuery::find_entity_by_id::<reporter::Entity, _>(
+        db,
+        (network_id.clone(), reporter.id),
+    )
+    .await?;
+
+    let new =
+        EntityMutation::upsert_entity::<reporter::ActiveModel, _>(db, reporter, network_id, event)
             .await?;
 
-            update_reporter_metrics(old, MetricOp::Decrement);
+    if old.as_ref() == Some(&new) {
+        tracing::info!("Reporter is not changed by an outdated event");
+        return Ok(None);
+    }
 
-            new
-        }
-        _ => {
-            return Err(AppError::invalid_request(&format!(
-                "Received unexpected event with reporter payload: {event_name}"
-            )));
+    Ok(Some(EntityChange::Reporter(old, new)))
+}
+
+#[instrument(level = "trace", skip(db))]
+async fn process_authority_payload(
+    authority: String,
+    event_name: EventName,
+    db: &DatabaseTransaction,
+    network_id: String,
+    event: &PushEvent,
+) -> Result<Option<EntityChange>, AppError> {
+    tracing::info!(authority, "Received authority");
+
+    if event_name != EventName::SetAuthority {
+        return Err(AppError::invalid_request(&format!(
+            "Received unexpected event with authority payload: {event_name}"
+        )));
+    }
+
+    if is_outdated_network_event(db, &network_id, event).await? {
+        return Ok(None);
+    }
+
+    update_network(db, network_id, Some(authority), None).await
+}
+
+#[instrument(level = "trace", skip(db))]
+async fn process_configuration_payload(
+    configuration: ContractConfiguration,
+    event_name: EventName,
+    db: &DatabaseTransaction,
+    network_id: String,
+    event: &PushEvent,
+) -> Result<Option<EntityChange>, AppError> {
+    tracing::info!(configuration = ?configuration, "Received configuration");
+
+    match (&event_name, configuration) {
+        (EventName::UpdateStakeConfiguration, ContractConfiguration::Stake(configuration)) => {
+            if is_outdated_network_event(db, &network_id, event).await? {
+                return Ok(None);
+            }
+
+            update_network(db, network_id, None, Some(configuration.token)).await
         }
-    };
+        // Reward configuration is not stored in explorer
+        (EventName::UpdateRewardConfiguration, ContractConfiguration::Reward(_)) => Ok(None),
+        (event_name, _) => Err(AppError::invalid_request(&format!(
+            "Received unexpected event with configuration payload: {event_name}"
+        ))),
+    }
+}
 
-    update_reporter_metrics(reporter, MetricOp::Increment);
+/// Network data has no event position, so it is compared with the event log
+async fn is_outdated_network_event(
+    db: &DatabaseTransaction,
+    network_id: &str,
+    event: &PushEvent,
+) -> Result<bool, AppError> {
+    let outdated = EntityQuery::has_newer_network_event(
+        db,
+        network_id,
+        &event.name.to_string(),
+        event.block,
+        event.block_index,
+    )
+    .await?;
 
-    Ok(StatusCode::OK)
+    if outdated {
+        tracing::info!(event_name = %event.name, "Network is not changed by an outdated event");
+    }
+
+    Ok(outdated)
+}
+
+async fn update_network(
+    db: &DatabaseTransaction,
+    network_id: String,
+    authority: Option<String>,
+    stake_token: Option<String>,
+) -> Result<Option<EntityChange>, AppError> {
+    let old = EntityQuery::find_entity_by_id::<network::Entity, _>(db, network_id.clone())
+        .await?
+        .ok_or(AppError::invalid_request("This network does not exist"))?;
+
+    let new = EntityMutation::update_network(db, network_id, None, authority, stake_token).await?;
+
+    Ok(Some(EntityChange::Network(old, new)))
 }
@@ -6,19 +6,27 @@ use {
     },
 };
 
-use crate::server::schema::AppSchema;
+use crate::{entity::types::ApiKeyRole, server::schema::AppSchema};
 
 /// Handle GraphQL playground
 pub(crate) async fn graphiql_playground() -> impl IntoResponse {
     Html(async_graphql::http::playground_source(
-        async_graphql::http::GraphQLPlaygroundConfig::new("/graphql"),
+        async_graphql::http::GraphQLPlaygroundConfig::new("/graphql")
+            .subscription_endpoint("/graphql/ws"),
     ))
 }
 
-/// Handle GraphQL Requests
+/// Handle GraphQL Requests, the API key role is passed to the resolvers
 pub(crate) async fn graphql_handler(
     schema: Extension<AppSchema>,
+    role: Option<Extension<ApiKeyRole>>,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
-    schema.execute(req.into_inner()).await.into()
+    let mut req = req.into_inner();
+
+    if let Some(Extension(role)) = role {
+        req = req.data(role);
+    }
+
+    schema.execute(req).await.into()
 }
@@ -1,6 +1,7 @@
 use {
     axum::{
-        extract::{Path, Query, State},
+        extract::{Extension, Path, Query, State},
+        http::StatusCode,
         response::IntoResponse,
         Json,
     },
@@ -49,11 +50,19 @@ pub(crate) async fn indexer_handler(
 
 pub(crate) async fn indexer_heartbeat_handler(
     state: State<AppState>,
+    Extension(indexer): Extension<indexer::Model>,
     Path(id): Path<Uuid>,
     cursor: String,
 ) -> Result<impl IntoResponse, AppError> {
     let db = &state.database_conn;
 
+    if id != indexer.id {
+        return Err(AppError::new(
+            StatusCode::FORBIDDEN,
+            "Token does not belong to this indexer".to_string(),
+        ));
+    }
+
     indexer::ActiveModel {
         id: Set(id),
         last_heartbeat: Set(chrono::Utc::now().naive_utc()),
@@ -1,4 +1,5 @@
 use {
+    anyhow::anyhow,
     axum::{
         extract::State,
         http::Request,
@@ -7,24 +8,33 @@ use {
         response::IntoResponse,
     },
     axum_extra::extract::cookie::CookieJar,
-    jsonwebtoken::{decode, DecodingKey, Validation},
-    secrecy::ExposeSecret,
+    jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation},
+    secrecy::{ExposeSecret, SecretString},
     serde::{Deserialize, Serialize},
+    uuid::Uuid,
 };
 
-use crate::{application::AppState, error::AppError};
+use crate::{application::AppState, entity::indexer, error::AppError, service::EntityQuery};
+
+const JWT_VALIDITY_DAYS: i64 = 365;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TokenClaims {
+    /// Indexer id
     pub id: String,
+    /// Network of the indexer
+    pub network_id: String,
+    /// Token id, changes on token rotation
+    pub jti: String,
     pub iat: usize,
     pub exp: usize,
 }
 
+/// Authenticates the indexer and passes its model to the handler
 pub(crate) async fn auth_handler<B>(
     state: State<AppState>,
     cookie_jar: CookieJar,
-    req: Request<B>,
+    mut req: Request<B>,
     next: Next<B>,
 ) -> Result<impl IntoResponse, AppError> {
     let jwt_secret = state.jwt_secret.clone();
@@ -49,12 +59,66 @@ pub(crate) async fn auth_handler<B>(
         )
     })?;
 
-    decode::<TokenClaims>(
+    let claims = decode::<TokenClaims>(
         &token,
         &DecodingKey::from_secret(jwt_secret.expose_secret().as_ref()),
         &Validation::default(),
     )
-    .map_err(|_| AppError::new(StatusCode::UNAUTHORIZED, "Invalid token".to_string()))?;
+    .map_err(|_| AppError::new(StatusCode::UNAUTHORIZED, "Invalid token".to_string()))?
+    .claims;
+
+    let indexer = get_token_indexer(&state, &claims).await?.ok_or_else(|| {
+        AppError::new(
+            StatusCode::UNAUTHORIZED,
+            "Token is revoked or does not match the indexer".to_string(),
+        )
+    })?;
+
+    req.extensions_mut().insert(indexer);
 
     Ok(next.run(req).await)
 }
+
+/// Returns the indexer bound to the token if the token is still active
+async fn get_token_indexer(
+    state: &AppState,
+    claims: &TokenClaims,
+) -> Result<Option<indexer::Model>, AppError> {
+    let (Ok(id), Ok(token_id)) = (Uuid::parse_str(&claims.id), Uuid::parse_str(&claims.jti)) else {
+        return Ok(None);
+    };
+
+    let indexer =
+        EntityQuery::find_entity_by_id::<indexer::Entity, _>(&state.database_conn, id).await?;
+
+    Ok(indexer.filter(|indexer| {
+        indexer.token_id == Some(token_id) && indexer.network_id == claims.network_id
+    }))
+}
+
+/// Issues a token for the currently active token id of the indexer
+pub(crate) fn create_indexer_token(
+    jwt_secret: &SecretString,
+    indexer: &indexer::Model,
+) -> anyhow::Result<String> {
+    let token_id = indexer
+        .token_id
+        .ok_or_else(|| anyhow!("Indexer {} has no active token", indexer.id))?;
+
+    let now = chrono::Utc::now();
+    let iat = now.timestamp() as usize;
+    let exp = (now + chrono::Duration::days(JWT_VALIDITY_DAYS)).timestamp() as usize;
+    let claims: TokenClaims = TokenClaims {
+        id: indexer.id.to_string(),
+        network_id: indexer.network_id.clone(),
+        jti: token_id.to_string(),
+        exp,
+        iat,
+    };
+
+    Ok(encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(jwt_secret.expose_secret().as_ref()),
+    )?)
+}
@@ -1,3 +1,4 @@
+mod api_key_auth;
 mod events;
 mod graphql;
 mod health;
@@ -5,11 +6,13 @@ mod indexer;
 mod jwt_auth;
 mod stats;
 
+pub(crate) use api_key_auth::api_key_handler;
 pub(crate) use events::event_handler;
 pub(crate) use graphql::{graphiql_playground, graphql_handler};
 pub(crate) use health::health_handler;
 pub(crate) use indexer::{indexer_handler, indexer_heartbeat_handler};
-pub(crate) use jwt_auth::auth_handler;
+pub(crate) use jwt_auth::{auth_handler, create_indexer_token};
 pub(crate) use stats::stats_handler;
 
 pub use jwt_auth::TokenClaims;
+pub use stats::StatsCache;
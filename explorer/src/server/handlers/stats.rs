@@ -1,6 +1,117 @@
-use axum::response::IntoResponse;
+use {
+    axum::{
+        extract::{Query, State},
+        response::IntoResponse,
+        Json,
+    },
+    sea_orm::EntityTrait,
+    serde::Deserialize,
+    serde_json::Value,
+    std::{
+        collections::HashMap,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+    tokio::sync::RwLock,
+};
+
+use crate::{
+    application::AppState,
+    entity::{
+        network,
+        statistics::{get_statistics, MAX_CHART_LENGTH},
+    },
+    error::AppError,
+};
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatsParams {
+    /// Limit statistics to a single network
+    network_id: Option<String>,
+    /// Time window in weeks
+    weeks: Option<usize>,
+}
+
+/// Cache of rendered statistics, keyed by request parameters
+#[derive(Clone)]
+pub struct StatsCache {
+    ttl: Duration,
+    entries: Arc<RwLock<HashMap<StatsParams, (Instant, Value)>>>,
+}
+
+impl StatsCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Default::default(),
+        }
+    }
+
+    async fn get(&self, params: &StatsParams) -> Option<Value> {
+        self.entries
+            .read()
+            .await
+            .get(params)
+            .filter(|(created_at, _)| created_at.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    async fn insert(&self, params: StatsParams, value: Value) {
+        let mut entries = self.entries.write().await;
+
+        entries.retain(|_, (created_at, _)| created_at.elapsed() < self.ttl);
+        entries.insert(params, (Instant::now(), value));
+    }
+}
 
 /// Handle stats Requests
-pub(crate) async fn stats_handler() -> impl IntoResponse {
-    unimplemented!()
+pub(crate) async fn stats_handler(
+    state: State<AppState>,
+    Query(params): Query<StatsParams>,
+) -> Result<impl IntoResponse, AppError> {
+    tracing::info!(?params, "Received stats request");
+
+    if let Some(value) = state.stats_cache.get(&params).await {
+        return Ok(Json(value));
+    }
+
+    let db = &state.database_conn;
+
+    if let Some(weeks) = params.weeks {
+        if weeks == 0 || weeks > MAX_CHART_LENGTH {
+            return Err(AppError::invalid_request(&format!(
+                "Weeks must be between 1 and {MAX_CHART_LENGTH}"
+            )));
+        }
+    }
+
+    if let Some(network_id) = &params.network_id {
+        if network::Entity::find_by_id(network_id.to_owned())
+            .one(db)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::invalid_request(&format!(
+                "Network {network_id} does not exist"
+            )));
+        }
+    }
+
+    let statistics = get_statistics(db, params.network_id.as_deref(), params.weeks).await?;
+
+    let json_response = serde_json::json!({
+        "data": statistics,
+        "meta": {
+            "network_id": params.network_id,
+            "weeks": params.weeks,
+            "generated_at": chrono::Utc::now().naive_utc(),
+        }
+    });
+
+    state
+        .stats_cache
+        .insert(params, json_response.clone())
+        .await;
+
+    Ok(Json(json_response))
 }
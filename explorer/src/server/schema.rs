@@ -1,12 +1,23 @@
 use {
     anyhow::Result,
-    async_graphql::{EmptyMutation, EmptySubscription, MergedObject, Schema},
+    async_graphql::{MergedObject, Schema},
     sea_orm::DatabaseConnection,
+    secrecy::SecretString,
 };
 
 use crate::entity::{
-    address::AddressQuery, asset::AssetQuery, case::CaseQuery, network::NetworkQuery,
-    reporter::ReporterQuery, statistics::StatisticsQuery,
+    address::AddressQuery,
+    admin::AdminMutation,
+    asset::AssetQuery,
+    case::CaseQuery,
+    event::EventQuery,
+    indexer::{HealthThresholds, IndexerQuery},
+    loader::{EntityDataLoader, EntityLoader},
+    network::NetworkQuery,
+    pagination::MaxPageSize,
+    reporter::ReporterQuery,
+    statistics::StatisticsQuery,
+    subscription::{EntityUpdates, Subscription},
 };
 
 /// Top-level application Query type
@@ -18,16 +29,37 @@ pub struct Query(
     ReporterQuery,
     NetworkQuery,
     StatisticsQuery,
+    EventQuery,
+    IndexerQuery,
 );
 
+/// Top-level application Mutation type
+#[derive(Default, MergedObject)]
+pub struct Mutation(AdminMutation);
+
 /// Top-level merged application schema
-pub type AppSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+pub type AppSchema = Schema<Query, Mutation, Subscription>;
 
-/// Building the GraphQL application schema, attaching the Database to the context
-pub(crate) fn create_graphql_schema(db: DatabaseConnection) -> Result<AppSchema> {
+/// Building the GraphQL application schema, attaching the Database, relation loader, entity updates,
+/// JWT secret, the page size limit and the indexer health thresholds to the context
+pub(crate) fn create_graphql_schema(
+    db: DatabaseConnection,
+    updates: EntityUpdates,
+    jwt_secret: SecretString,
+    max_page_size: MaxPageSize,
+    health_thresholds: HealthThresholds,
+) -> Result<AppSchema> {
     Ok(
-        Schema::build(Query::default(), EmptyMutation, EmptySubscription)
+        Schema::build(Query::default(), Mutation::default(), Subscription)
+            .data(EntityDataLoader::new(
+                EntityLoader::new(db.clone(), max_page_size),
+                tokio::spawn,
+            ))
             .data(db)
+            .data(updates)
+            .data(jwt_secret)
+            .data(max_page_size)
+            .data(health_thresholds)
             .finish(),
     )
 }
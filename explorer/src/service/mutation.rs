@@ -1,59 +1,131 @@
 use crate::entity::{
-    indexer, network,
-    {types::NetworkBackend, FromPayload},
+    api_key, event, indexer, network,
+    {
+        types::{ApiKeyRole, NetworkBackend},
+        FromPayload,
+    },
 };
 
 use super::get_network_id;
 
 use {
     chrono::{DateTime, NaiveDateTime, Utc},
-    sea_orm::*,
+    hapi_indexer::{PushEvent, PushPayload},
+    sea_orm::{
+        sea_query::{Expr, OnConflict},
+        *,
+    },
+    std::str::FromStr,
     uuid::Uuid,
 };
 
 pub struct EntityMutation;
 
 impl EntityMutation {
-    /// Universal method for inserting entities to database
-    pub async fn create_entity<M, T>(
-        db: &DbConn,
+    /// Universal method for inserting or updating entities in database.
+    /// Entity data is updated only if the event is not older than the last applied event,
+    /// events are ordered by their position on chain as block timestamps may be equal
+    pub async fn upsert_entity<M, T>(
+        db: &impl ConnectionTrait,
         payload: T,
         network_id: String,
-        timestamp: u64,
+        event: &PushEvent,
     ) -> Result<<M::Entity as EntityTrait>::Model, DbErr>
     where
         <M::Entity as EntityTrait>::Model: IntoActiveModel<M>,
         M: ActiveModelBehavior + FromPayload<T> + Send,
     {
-        let created_at = Some(
-            NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
-                .ok_or(DbErr::Custom("Invalid block timestamp".to_string()))?,
-        );
-
-        M::from(network_id, created_at, created_at, payload)
-            .insert(db)
-            .await
+        let event_time = get_event_time(event.timestamp)?;
+        let mut model = M::from(network_id, Some(event_time), Some(event_time), payload);
+
+        let created_at = get_column::<M::Entity>("created_at")?;
+        let updated_block = get_column::<M::Entity>("updated_block")?;
+        let updated_block_index = get_column::<M::Entity>("updated_block_index")?;
+
+        let block = event.block as i64;
+        let block_index = event.block_index as i64;
+
+        model.set(updated_block, block.into());
+        model.set(updated_block_index, block_index.into());
+
+        let primary_key: Vec<_> = <M::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| key.into_column())
+            .collect();
+
+        let mut primary_key_condition = Condition::all();
+        for column in &primary_key {
+            if let Some(value) = model.get(*column).into_value() {
+                primary_key_condition = primary_key_condition.add(column.eq(value));
+            }
+        }
+
+        let update_columns = <M::Entity as EntityTrait>::Column::iter().filter(|column| {
+            column.as_str() != created_at.as_str()
+                && !primary_key
+                    .iter()
+                    .any(|key| key.as_str() == column.as_str())
+        });
+
+        // Events of one block are ordered by their position in the block
+        let table = M::Entity::default();
+        let is_newer_event =
+            Expr::col((table, updated_block))
+                .lt(block)
+                .or(Expr::col((table, updated_block))
+                    .eq(block)
+                    .and(Expr::col((table, updated_block_index)).lte(block_index)));
+
+        M::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns(primary_key.clone())
+                    .update_columns(update_columns)
+                    .action_and_where(is_newer_event)
+                    .to_owned(),
+            )
+            .exec_without_returning(db)
+            .await?;
+
+        // An older event still tells when the entity was created
+        M::Entity::update_many()
+            .col_expr(created_at, Expr::value(event_time))
+            .filter(primary_key_condition.clone())
+            .filter(created_at.gt(event_time))
+            .exec(db)
+            .await?;
+
+        M::Entity::find()
+            .filter(primary_key_condition)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound(
+                "Upserted entity is not found".to_string(),
+            ))
     }
 
-    /// Universal method for updating entities in database
-    pub async fn update_entity<M, T>(
-        db: &DbConn,
-        payload: T,
+    /// Method for inserting event to the event log, events that are already stored are skipped
+    pub async fn create_event(
+        db: &impl ConnectionTrait,
+        payload: PushPayload,
         network_id: String,
-        timestamp: u64,
-    ) -> Result<<M::Entity as EntityTrait>::Model, DbErr>
-    where
-        <M::Entity as EntityTrait>::Model: IntoActiveModel<M>,
-        M: ActiveModelBehavior + FromPayload<T> + Send,
-    {
-        let updated_at = Some(
-            NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
-                .ok_or(DbErr::Custom("Invalid block timestamp".to_string()))?,
-        );
-
-        M::from(network_id, None, updated_at, payload)
-            .update(db)
-            .await
+    ) -> Result<(), DbErr> {
+        let event_time = get_event_time(payload.event.timestamp)?;
+        let model = event::ActiveModel::from_payload(network_id, event_time, payload)
+            .map_err(|e| DbErr::Json(format!("Failed to serialize event data: {e}")))?;
+
+        event::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    event::Column::NetworkId,
+                    event::Column::TxHash,
+                    event::Column::TxIndex,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(db)
+            .await?;
+
+        Ok(())
     }
 
     /// Method for creating network in database
@@ -82,7 +154,7 @@ impl EntityMutation {
 
     /// Method for updating network in database
     pub async fn update_network(
-        db: &DbConn,
+        db: &impl ConnectionTrait,
         id: String,
         name: Option<String>,
         authority: Option<String>,
@@ -99,7 +171,7 @@ impl EntityMutation {
             chain_id: NotSet,
             authority,
             stake_token,
-            created_at: Set(chrono::Utc::now().naive_utc()),
+            created_at: NotSet,
             updated_at: Set(chrono::Utc::now().naive_utc()),
         };
 
@@ -112,6 +184,7 @@ impl EntityMutation {
         chain_id: Option<String>,
         id: Uuid,
         timestamp: DateTime<Utc>,
+        token_id: Uuid,
     ) -> Result<indexer::Model, DbErr> {
         let network_id = get_network_id(db, backend, chain_id).await?;
 
@@ -121,8 +194,73 @@ impl EntityMutation {
             created_at: Set(timestamp.naive_utc()),
             last_heartbeat: Set(NaiveDateTime::default()),
             cursor: Set("".to_string()),
+            token_id: Set(Some(token_id)),
         }
         .insert(db)
         .await
     }
+
+    /// Method for replacing the active indexer token, `None` revokes the token
+    pub async fn set_indexer_token(
+        db: &DbConn,
+        id: Uuid,
+        token_id: Option<Uuid>,
+    ) -> Result<indexer::Model, DbErr> {
+        indexer::ActiveModel {
+            id: Set(id),
+            token_id: Set(token_id),
+            ..Default::default()
+        }
+        .update(db)
+        .await
+    }
+
+    /// Method for deleting network from database
+    pub async fn delete_network(db: &DbConn, id: String) -> Result<Option<network::Model>, DbErr> {
+        let Some(network) = network::Entity::find_by_id(id).one(db).await? else {
+            return Ok(None);
+        };
+
+        network.clone().delete(db).await?;
+
+        Ok(Some(network))
+    }
+
+    /// Method for creating API key, returns the stored model and the raw key
+    pub async fn create_api_key(
+        db: &DbConn,
+        name: String,
+        role: ApiKeyRole,
+    ) -> Result<(api_key::Model, String), DbErr> {
+        let key = api_key::generate_key();
+
+        let model = api_key::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(name),
+            key_hash: Set(api_key::hash_key(&key)),
+            role: Set(role),
+            created_at: Set(chrono::Utc::now().naive_utc()),
+        }
+        .insert(db)
+        .await?;
+
+        Ok((model, key))
+    }
+
+    /// Method for deleting API key from database
+    pub async fn delete_api_key(db: &DbConn, id: Uuid) -> Result<bool, DbErr> {
+        let result = api_key::Entity::delete_by_id(id).exec(db).await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+fn get_event_time(timestamp: u64) -> Result<NaiveDateTime, DbErr> {
+    NaiveDateTime::from_timestamp_opt(timestamp as i64, 0)
+        .ok_or(DbErr::Custom("Invalid block timestamp".to_string()))
+}
+
+fn get_column<E: EntityTrait>(name: &str) -> Result<E::Column, DbErr> {
+    E::Column::from_str(name)
+        .map_err(|_| DbErr::Custom(format!("Column {name} is not found in entity")))
 }
@@ -1,13 +1,17 @@
 use {
     async_graphql::{InputType, OutputType},
     chrono::{Duration, NaiveDate},
-    sea_orm::{prelude::*, sea_query::Cond, PaginatorTrait},
+    sea_orm::{prelude::*, Condition, ConnectionTrait, PaginatorTrait, QueryOrder, QuerySelect},
 };
 
 use crate::entity::{
-    network,
-    pagination::{EntityInput, EntityPage, Paginator},
-    types::NetworkBackend,
+    address, api_key, event, indexer, network,
+    pagination::{
+        cursor_condition, encode_cursor, order_by_columns, EntityInput, EntityPage, Ordering,
+        PageInfo, Paginator, DEFAULT_PAGE_SIZE,
+    },
+    search::{hash_match, SearchTerm},
+    types::{EntityType, NetworkBackend},
     EntityFilter,
 };
 
@@ -15,7 +19,10 @@ pub struct EntityQuery;
 
 impl EntityQuery {
     /// Universal method for fetching entity from database
-    pub async fn find_entity_by_id<M, T>(db: &DbConn, id: T) -> Result<Option<M::Model>, DbErr>
+    pub async fn find_entity_by_id<M, T>(
+        db: &impl ConnectionTrait,
+        id: T,
+    ) -> Result<Option<M::Model>, DbErr>
     where
         M: EntityTrait,
         T: Into<<M::PrimaryKey as PrimaryKeyTrait>::ValueType>,
@@ -23,10 +30,12 @@ impl EntityQuery {
         M::find_by_id(id).one(db).await
     }
 
-    /// Universal method for fetching entities from database
+    /// Universal method for fetching entities from database.
+    /// Pages are limited by the maximum page size, either by page number or by cursor
     pub async fn find_many<M>(
         db: &DbConn,
         input: EntityInput<<M as EntityFilter>::Filter, <M as EntityFilter>::Condition>,
+        max_page_size: u64,
     ) -> Result<EntityPage<M::Model>, DbErr>
     where
         M: EntityTrait + EntityFilter,
@@ -41,66 +50,296 @@ impl EntityQuery {
             query = M::filter(query, &filter);
         }
 
-        if let Some(search) = input.search {
-            query = Self::search(query, &search);
+        if let Some(term) = input.search.as_deref().and_then(SearchTerm::parse) {
+            query = M::search(query, &term);
+        }
+
+        let columns = M::cursor_columns(input.ordering_condition);
+
+        if let Some(cursor) = CursorArgs::from_input(&input, max_page_size)? {
+            if input.pagination.is_some() {
+                return Err(DbErr::Custom(
+                    "Page and cursor pagination can not be combined".to_string(),
+                ));
+            }
+
+            let columns = columns.ok_or(DbErr::Custom(
+                "Cursor pagination is not available for this ordering".to_string(),
+            ))?;
+
+            return Self::paginate_by_cursor(
+                db,
+                query,
+                input.ordering.unwrap_or_default(),
+                &columns,
+                cursor,
+            )
+            .await;
         }
 
         query = M::order(query, input.ordering, input.ordering_condition);
 
-        Self::paginate(db, query, input.pagination).await
+        let pagination = input.pagination.unwrap_or(Paginator {
+            page_num: 1,
+            page_size: max_page_size,
+        });
+
+        Self::paginate(db, query, pagination, max_page_size, columns).await
+    }
+
+    /// Method for fetching an address on all networks, EVM addresses are matched in any case
+    pub async fn lookup_address(db: &DbConn, address: &str) -> Result<Vec<address::Model>, DbErr> {
+        address::Entity::find()
+            .filter(hash_match(address::Column::Address, address.trim()))
+            .order_by_asc(address::Column::NetworkId)
+            .all(db)
+            .await
+    }
+
+    /// Method for fetching indexers, optionally of a single network
+    pub async fn find_indexers(
+        db: &DbConn,
+        network_id: Option<&str>,
+    ) -> Result<Vec<indexer::Model>, DbErr> {
+        let mut query = indexer::Entity::find();
+
+        if let Some(network_id) = network_id {
+            query = query.filter(indexer::Column::NetworkId.eq(network_id));
+        }
+
+        query
+            .order_by_asc(indexer::Column::NetworkId)
+            .order_by_asc(indexer::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    /// Method for fetching API key by its raw value
+    pub async fn find_api_key(db: &DbConn, key: &str) -> Result<Option<api_key::Model>, DbErr> {
+        api_key::Entity::find()
+            .filter(api_key::Column::KeyHash.eq(api_key::hash_key(key)))
+            .one(db)
+            .await
+    }
+
+    /// Method for fetching event from the event log by its transaction
+    pub async fn find_event(
+        db: &impl ConnectionTrait,
+        network_id: &str,
+        tx_hash: &str,
+        tx_index: u64,
+    ) -> Result<Option<event::Model>, DbErr> {
+        event::Entity::find()
+            .filter(event::Column::NetworkId.eq(network_id))
+            .filter(event::Column::TxHash.eq(tx_hash))
+            .filter(event::Column::TxIndex.eq(tx_index as i64))
+            .one(db)
+            .await
+    }
+
+    /// Checks whether the event log has a later network event with the same name
+    pub async fn has_newer_network_event(
+        db: &impl ConnectionTrait,
+        network_id: &str,
+        name: &str,
+        block: u64,
+        block_index: u64,
+    ) -> Result<bool, DbErr> {
+        let (block, block_index) = (block as i64, block_index as i64);
+
+        let count = event::Entity::find()
+            .filter(event::Column::NetworkId.eq(network_id))
+            .filter(event::Column::EntityType.eq(EntityType::Network))
+            .filter(event::Column::Name.eq(name))
+            .filter(
+                Condition::any().add(event::Column::Block.gt(block)).add(
+                    Condition::all()
+                        .add(event::Column::Block.eq(block))
+                        .add(event::Column::BlockIndex.gt(block_index)),
+                ),
+            )
+            .count(db)
+            .await?;
+
+        Ok(count > 0)
     }
 
-    fn search<M>(query: Select<M>, value: &str) -> Select<M>
+    /// Method for query pagination by page number
+    async fn paginate<M>(
+        db: &DbConn,
+        query: Select<M>,
+        pagination: Paginator,
+        max_page_size: u64,
+        columns: Option<Vec<M::Column>>,
+    ) -> Result<EntityPage<M::Model>, DbErr>
     where
-        M: EntityTrait + EntityFilter,
+        M: EntityTrait,
+        M::Model: OutputType,
     {
-        let value = &format!("%{}%", value);
-        let columns = M::columns_for_search();
-        let mut cond = Cond::any();
+        check_page_size(pagination.page_size, max_page_size)?;
 
-        for column in columns {
-            cond = cond.add(Expr::cust(column).like(value));
+        if pagination.page_num == 0 {
+            return Err(DbErr::Custom("Page number starts from 1".to_string()));
         }
 
-        query.filter(cond)
+        let paginator = query.paginate(db, pagination.page_size);
+        let total = paginator.num_items().await?;
+        let data = paginator.fetch_page(pagination.page_num - 1).await?;
+
+        let page_count = get_page_count(total, pagination.page_size);
+        let (start_cursor, end_cursor) = match columns {
+            Some(columns) => get_cursors(&data, &columns)?,
+            None => (None, None),
+        };
+
+        Ok(EntityPage {
+            data,
+            total,
+            page_count,
+            page_info: PageInfo {
+                has_next_page: pagination.page_num < page_count,
+                has_previous_page: pagination.page_num > 1,
+                start_cursor,
+                end_cursor,
+            },
+        })
     }
 
-    /// Method for query pagination
-    async fn paginate<M>(
+    /// Method for query pagination by cursor, the query is ordered by the cursor columns
+    async fn paginate_by_cursor<M>(
         db: &DbConn,
         query: Select<M>,
-        pagination: Option<Paginator>,
+        ordering: Ordering,
+        columns: &[M::Column],
+        cursor: CursorArgs,
     ) -> Result<EntityPage<M::Model>, DbErr>
     where
         M: EntityTrait,
         M::Model: OutputType,
     {
-        let page = if let Some(pagination) = pagination {
-            let paginator = query.paginate(db, pagination.page_size);
-            let total = paginator.num_items().await?;
-            let data = paginator.fetch_page(pagination.page_num - 1).await?;
-
-            let page_count =
-                total / pagination.page_size + u64::from(total % pagination.page_size != 0);
-
-            EntityPage {
-                data,
-                total,
-                page_count,
-            }
+        let total = query.clone().count(db).await?;
+
+        // Entities before the cursor are fetched in the reverse order
+        let ordering = if cursor.backward {
+            ordering.reverse()
         } else {
-            let data = query.all(db).await?;
-            let total = data.len() as u64;
+            ordering
+        };
 
-            EntityPage {
-                data,
-                total,
-                page_count: 1,
-            }
+        let mut query = order_by_columns(query, ordering, columns);
+
+        if let Some(value) = &cursor.cursor {
+            query = query.filter(cursor_condition::<M>(value, columns, ordering)?);
+        }
+
+        let mut data = query.limit(cursor.size + 1).all(db).await?;
+        let has_more = data.len() as u64 > cursor.size;
+
+        data.truncate(cursor.size as usize);
+
+        if cursor.backward {
+            data.reverse();
+        }
+
+        let (start_cursor, end_cursor) = get_cursors(&data, columns)?;
+        let has_cursor = cursor.cursor.is_some();
+
+        Ok(EntityPage {
+            data,
+            total,
+            page_count: get_page_count(total, cursor.size),
+            page_info: PageInfo {
+                has_next_page: if cursor.backward {
+                    has_cursor
+                } else {
+                    has_more
+                },
+                has_previous_page: if cursor.backward {
+                    has_more
+                } else {
+                    has_cursor
+                },
+                start_cursor,
+                end_cursor,
+            },
+        })
+    }
+}
+
+/// Cursor pagination options of the entity input
+struct CursorArgs {
+    size: u64,
+    cursor: Option<String>,
+    backward: bool,
+}
+
+impl CursorArgs {
+    fn from_input<F, C>(
+        input: &EntityInput<F, C>,
+        max_page_size: u64,
+    ) -> Result<Option<Self>, DbErr>
+    where
+        F: InputType,
+        C: InputType,
+    {
+        let forward = input.first.is_some() || input.after.is_some();
+        let backward = input.last.is_some() || input.before.is_some();
+
+        if forward && backward {
+            return Err(DbErr::Custom(
+                "Forward and backward cursor pagination can not be combined".to_string(),
+            ));
+        }
+
+        if !forward && !backward {
+            return Ok(None);
+        }
+
+        let (size, cursor) = if forward {
+            (input.first, input.after.clone())
+        } else {
+            (input.last, input.before.clone())
         };
 
-        Ok(page)
+        let size = size.unwrap_or(DEFAULT_PAGE_SIZE.min(max_page_size));
+        check_page_size(size, max_page_size)?;
+
+        Ok(Some(Self {
+            size,
+            cursor,
+            backward,
+        }))
+    }
+}
+
+fn check_page_size(page_size: u64, max_page_size: u64) -> Result<(), DbErr> {
+    if page_size == 0 || page_size > max_page_size {
+        return Err(DbErr::Custom(format!(
+            "Page size must be between 1 and {max_page_size}"
+        )));
     }
+
+    Ok(())
+}
+
+fn get_page_count(total: u64, page_size: u64) -> u64 {
+    total / page_size + u64::from(total % page_size != 0)
+}
+
+fn get_cursors<M>(
+    data: &[M],
+    columns: &[<M::Entity as EntityTrait>::Column],
+) -> Result<(Option<String>, Option<String>), DbErr>
+where
+    M: ModelTrait,
+{
+    let start = data
+        .first()
+        .map(|m| encode_cursor(m, columns))
+        .transpose()?;
+    let end = data.last().map(|m| encode_cursor(m, columns)).transpose()?;
+
+    Ok((start, end))
 }
 
 pub async fn get_network_id(
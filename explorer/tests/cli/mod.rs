@@ -1,6 +1,9 @@
 use {
     hapi_explorer::{
-        entity::{indexer, network, types::NetworkBackend},
+        entity::{
+            api_key, indexer, network,
+            types::{ApiKeyRole, NetworkBackend},
+        },
         migrations::Migrator,
     },
     sea_orm::{Database, DatabaseConnection, EntityTrait},
@@ -215,4 +218,103 @@ async fn indexer_command_test() {
 
     let indexer = indexers.first().unwrap();
     assert_eq!(indexer.network_id, id);
+    assert!(indexer.token_id.is_some());
+
+    let output = Command::new("./target/debug/hapi-explorer")
+        .args(["rotate-indexer-token", "--id", &indexer.id.to_string()])
+        .output()
+        .expect("Failed to rotate indexer token");
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let rotated = indexer::Entity::find_by_id(indexer.id)
+        .one(&db)
+        .await
+        .expect("Failed to find indexer")
+        .expect("Indexer does not exist");
+
+    assert!(rotated.token_id.is_some());
+    assert_ne!(rotated.token_id, indexer.token_id);
+
+    let output = Command::new("./target/debug/hapi-explorer")
+        .args(["revoke-indexer-token", "--id", &indexer.id.to_string()])
+        .output()
+        .expect("Failed to revoke indexer token");
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let revoked = indexer::Entity::find_by_id(indexer.id)
+        .one(&db)
+        .await
+        .expect("Failed to find indexer")
+        .expect("Indexer does not exist");
+
+    assert_eq!(revoked.token_id, None);
+}
+
+#[tokio::test]
+async fn api_key_command_test() {
+    let db = setup().await;
+
+    let name = String::from("Test Admin Key");
+    let role = ApiKeyRole::Admin;
+
+    let output = Command::new("./target/debug/hapi-explorer")
+        .args([
+            "api-key",
+            "create",
+            "--name",
+            &name,
+            "--role",
+            &role.to_string(),
+        ])
+        .output()
+        .expect("Failed to create API key");
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let api_keys = api_key::Entity::find()
+        .all(&db)
+        .await
+        .expect("Failed to find API key");
+
+    assert_eq!(api_keys.len(), 1);
+
+    let api_key = api_keys.first().unwrap();
+    assert_eq!(api_key.name, name);
+    assert_eq!(api_key.role, role);
+
+    let output = Command::new("./target/debug/hapi-explorer")
+        .args(["api-key", "list"])
+        .output()
+        .expect("Failed to list API keys");
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+
+    let output = Command::new("./target/debug/hapi-explorer")
+        .args(["api-key", "revoke", "--id", &api_key.id.to_string()])
+        .output()
+        .expect("Failed to revoke API key");
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let api_key = api_key::Entity::find_by_id(api_key.id)
+        .one(&db)
+        .await
+        .expect("Failed to find API key");
+
+    assert!(api_key.is_none());
 }
@@ -22,6 +22,38 @@ const GET_ADDRESS_QUERY: &str = "
         }
     }
 ";
+const LOOKUP_ADDRESS_QUERY: &str = "
+    query LookupAddress($address: String!) {
+        lookupAddress(address: $address) {
+            networkId
+            address
+            caseId
+            reporterId
+            risk
+            category
+            confirmations
+            createdAt
+            updatedAt
+        }
+    }
+";
+const GET_ADDRESS_RELATIONS_QUERY: &str = "
+    query GetAddress($address: String!, $networkId: String!) {
+        getAddress(address: $address, networkId: $networkId) {
+            case {
+                networkId
+                id
+            }
+            reporter {
+                networkId
+                id
+            }
+            network {
+                id
+            }
+        }
+    }
+";
 const GET_MANY_ADDRESSES: &str = "
     query GetManyAddresses(
         $input: AddressInput!
@@ -100,6 +132,80 @@ async fn get_address_test() {
     }
 }
 
+#[tokio::test]
+async fn lookup_address_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let addresses = test_app
+        .global_setup::<Address>(&sender, EventName::UpdateAddress)
+        .await;
+
+    let payload = addresses.first().expect("No addresses in test data");
+
+    let matching: Vec<&TestData<Address>> = addresses
+        .iter()
+        .filter(|address| address.data.address == payload.data.address)
+        .collect();
+
+    // Checksummed EVM addresses are looked up regardless of their case
+    for lookup in [
+        payload.data.address.clone(),
+        payload.data.address.to_lowercase(),
+        format!("0x{}", payload.data.address[2..].to_uppercase()),
+    ] {
+        let response = sender
+            .send_graphql(LOOKUP_ADDRESS_QUERY, json!({ "address": lookup }))
+            .await
+            .unwrap();
+
+        let found = response["lookupAddress"]
+            .as_array()
+            .expect("Empty response");
+        assert_eq!(found.len(), matching.len());
+
+        for address in &matching {
+            let value = found
+                .iter()
+                .find(|value| value["networkId"] == address.network_id)
+                .expect("Address is missing on the network");
+
+            check_address(address, value);
+        }
+    }
+}
+
+#[tokio::test]
+async fn get_address_relations_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let addresses = test_app
+        .global_setup::<Address>(&sender, EventName::UpdateAddress)
+        .await;
+
+    for payload in addresses {
+        let response = sender
+            .send_graphql(
+                GET_ADDRESS_RELATIONS_QUERY,
+                json!({
+                    "address": payload.data.address,
+                    "networkId": payload.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        let address = &response["getAddress"];
+        assert_eq!(address["case"]["networkId"], payload.network_id);
+        assert_eq!(address["case"]["id"], payload.data.case_id.to_string());
+        assert_eq!(address["reporter"]["networkId"], payload.network_id);
+        assert_eq!(
+            address["reporter"]["id"],
+            payload.data.reporter_id.to_string()
+        );
+        assert_eq!(address["network"]["id"], payload.network_id);
+    }
+}
+
 #[tokio::test]
 async fn get_many_addresses_test() {
     let test_app = TestApp::start(None).await;
@@ -172,8 +278,42 @@ async fn get_filtered_addresses_test() {
             .first()
             .unwrap();
 
-        check_address(&payload, address)
+        check_address(&payload, address);
+
+        let response = sender
+            .send_graphql(
+                GET_MANY_ADDRESSES,
+                json!({
+                "input":
+                {
+                    "search" : payload.data.case_id,
+                }
+
+                }),
+            )
+            .await
+            .unwrap();
+
+        let addresses_response = &response["getManyAddresses"];
+        assert_eq!(addresses_response["total"], 1);
     }
+
+    // Searching a number does not match risks and ids containing it
+    let response = sender
+        .send_graphql(
+            GET_MANY_ADDRESSES,
+            json!({
+            "input":
+            {
+                "search" : "1",
+            }
+
+            }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response["getManyAddresses"]["total"], 0);
 }
 
 #[tokio::test]
@@ -249,6 +389,33 @@ async fn get_searched_addresses_test() {
             .first()
             .unwrap();
 
-        check_address(&payload, address)
+        check_address(&payload, address);
+
+        // Checksummed EVM addresses are found in any case
+        let prefix = &payload.data.address[0..10];
+        for search in [
+            prefix.to_string(),
+            prefix.to_lowercase(),
+            format!("0x{}", prefix[2..].to_uppercase()),
+        ] {
+            let response = sender
+                .send_graphql(
+                    GET_MANY_ADDRESSES,
+                    json!({
+                    "input":
+                    {
+                        "filtering": {
+                            "networkId": payload.network_id,
+                        },
+                        "search" : search,
+                    }
+
+                    }),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response["getManyAddresses"]["total"], 1);
+        }
     }
 }
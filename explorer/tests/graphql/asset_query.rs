@@ -251,6 +251,33 @@ async fn get_searched_assets_test() {
             .first()
             .unwrap();
 
-        check_asset(&payload, asset)
+        check_asset(&payload, asset);
+
+        // Checksummed EVM addresses are found in any case
+        let prefix = &payload.data.address[0..10];
+        for search in [
+            prefix.to_string(),
+            prefix.to_lowercase(),
+            format!("0x{}", prefix[2..].to_uppercase()),
+        ] {
+            let response = sender
+                .send_graphql(
+                    GET_MANY_ASSETS,
+                    json!({
+                    "input":
+                    {
+                        "filtering": {
+                            "networkId": payload.network_id,
+                        },
+                        "search" : search,
+                    }
+
+                    }),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response["getManyAssets"]["total"], 1);
+        }
     }
 }
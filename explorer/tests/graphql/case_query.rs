@@ -5,6 +5,7 @@ use crate::helpers::{
 
 use {
     hapi_core::client::{entities::case::Case, events::EventName},
+    hapi_explorer::entity::pagination::DEFAULT_MAX_PAGE_SIZE,
     hapi_indexer::{PushData, PushPayload},
     serde_json::{json, Value},
 };
@@ -23,6 +24,22 @@ const GET_CASE_QUERY: &str = "
         }
     }
 ";
+const GET_CASE_RELATIONS_QUERY: &str = "
+    query GetCase($id: UUID!, $networkId: String!) {
+        getCase(id: $id, networkId: $networkId) {
+            addresses {
+                networkId
+                address
+                caseId
+            }
+            assets {
+                networkId
+                address
+                caseId
+            }
+        }
+    }
+";
 const GET_MANY_CASES: &str = "
     query GetManyCases(
         $input: CaseInput!
@@ -99,6 +116,93 @@ async fn get_case_test() {
     }
 }
 
+#[tokio::test]
+async fn get_case_relations_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let cases = test_app
+        .global_setup::<Case>(&sender, EventName::UpdateCase)
+        .await;
+
+    for payload in cases {
+        let response = sender
+            .send_graphql(
+                GET_CASE_RELATIONS_QUERY,
+                json!({
+                    "id": payload.data.id,
+                    "networkId": payload.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        let case = &response["getCase"];
+
+        for field in ["addresses", "assets"] {
+            let related = case[field].as_array().expect("Empty response");
+            assert_eq!(related.len(), 1);
+
+            for value in related {
+                assert_eq!(value["networkId"], payload.network_id);
+                assert_eq!(value["caseId"], payload.data.id.to_string());
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn get_case_relations_limit_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let cases = test_app
+        .global_setup::<Case>(&sender, EventName::UpdateCase)
+        .await;
+
+    // The case already has an address, so it ends up with one over the limit
+    let case = &cases[0];
+    let network = test_app.get_network(&case.network_id);
+    let test_data = (0..DEFAULT_MAX_PAGE_SIZE)
+        .map(|_| {
+            create_address_data(
+                case.data.reporter_id,
+                case.data.id,
+                &network.network,
+                network.model.chain_id.clone(),
+            )
+        })
+        .collect::<Vec<PushPayload>>();
+
+    test_app.send_events(&sender, &test_data).await;
+
+    for payload in &cases {
+        let response = sender
+            .send_graphql(
+                GET_CASE_RELATIONS_QUERY,
+                json!({
+                    "id": payload.data.id,
+                    "networkId": payload.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        let addresses = response["getCase"]["addresses"]
+            .as_array()
+            .expect("Empty response");
+
+        let expected = if payload.data.id == case.data.id && payload.network_id == case.network_id {
+            DEFAULT_MAX_PAGE_SIZE as usize
+        } else {
+            1
+        };
+        assert_eq!(addresses.len(), expected);
+
+        for value in addresses {
+            assert_eq!(value["caseId"], payload.data.id.to_string());
+        }
+    }
+}
+
 #[tokio::test]
 async fn get_many_cases_test() {
     let test_app = TestApp::start(None).await;
@@ -346,6 +450,63 @@ async fn get_searched_cases_test() {
             .first()
             .unwrap();
 
-        check_case(&payload, case)
+        check_case(&payload, case);
+
+        let response = sender
+            .send_graphql(
+                GET_MANY_CASES,
+                json!({
+                "input":
+                {
+                    "search" : payload.data.id,
+                }
+
+                }),
+            )
+            .await
+            .unwrap();
+
+        let cases_response = &response["getManyCases"];
+        assert_eq!(cases_response["total"], 1);
+
+        let response = sender
+            .send_graphql(
+                GET_MANY_CASES,
+                json!({
+                "input":
+                {
+                    "filtering": {
+                        "networkId": payload.network_id,
+                    },
+                    "search" : "closed",
+                }
+
+                }),
+            )
+            .await
+            .unwrap();
+
+        let cases_response = &response["getManyCases"];
+        assert_eq!(cases_response["total"], 1);
+
+        let response = sender
+            .send_graphql(
+                GET_MANY_CASES,
+                json!({
+                "input":
+                {
+                    "filtering": {
+                        "networkId": payload.network_id,
+                    },
+                    "search" : "opened",
+                }
+
+                }),
+            )
+            .await
+            .unwrap();
+
+        let cases_response = &response["getManyCases"];
+        assert_eq!(cases_response["total"], 0);
     }
 }
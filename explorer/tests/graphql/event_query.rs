@@ -0,0 +1,182 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp};
+
+use {
+    hapi_indexer::{PushData, PushPayload},
+    serde_json::json,
+};
+
+const GET_MANY_EVENTS: &str = "
+    query GetManyEvents(
+        $input: EventInput!
+    ) {
+        getManyEvents(
+            input: $input
+        ) {
+            data {
+                networkId
+                name
+                txHash
+                txIndex
+                entityType
+                entityId
+                data
+            }
+            total
+            pageCount
+        }
+    }
+";
+const GET_ADDRESS_HISTORY: &str = "
+    query GetAddressHistory($address: String!, $networkId: String!) {
+        getAddressHistory(address: $address, networkId: $networkId) {
+            data {
+                name
+                txHash
+                data
+            }
+            total
+        }
+    }
+";
+const GET_CASE_HISTORY: &str = "
+    query GetCaseHistory($id: UUID!, $networkId: String!) {
+        getCaseHistory(id: $id, networkId: $networkId) {
+            data {
+                name
+                txHash
+                data
+            }
+            total
+        }
+    }
+";
+
+#[tokio::test]
+async fn get_many_events_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let mut total = 0;
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&sender, &test_data).await;
+
+        total += test_data.len();
+    }
+
+    let response = sender
+        .send_graphql(
+            GET_MANY_EVENTS,
+            json!({
+                "input": {
+                    "pagination": {
+                        "pageNum": 1,
+                        "pageSize": total
+                    }
+                }
+            }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response["getManyEvents"]["total"], total);
+    assert_eq!(
+        response["getManyEvents"]["data"].as_array().unwrap().len(),
+        total
+    );
+
+    let network = &test_app.networks[0];
+    let response = sender
+        .send_graphql(
+            GET_MANY_EVENTS,
+            json!({
+                "input": {
+                    "filtering": {
+                        "networkId": network.model.id
+                    }
+                }
+            }),
+        )
+        .await
+        .unwrap();
+
+    let events = response["getManyEvents"]["data"].as_array().unwrap();
+    assert_eq!(events.len(), total / test_app.networks.len());
+    assert!(events
+        .iter()
+        .all(|event| event["networkId"] == network.model.id));
+}
+
+#[tokio::test]
+async fn entity_history_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &test_data).await;
+
+    let address_events: Vec<&PushPayload> = test_data
+        .iter()
+        .filter(|payload| matches!(payload.data, PushData::Address(_)))
+        .collect();
+    let address = match &address_events[0].data {
+        PushData::Address(address) => address.address.clone(),
+        _ => unreachable!(),
+    };
+    let address_events: Vec<&PushPayload> = address_events
+        .into_iter()
+        .filter(|payload| matches!(&payload.data, PushData::Address(a) if a.address == address))
+        .collect();
+
+    let response = sender
+        .send_graphql(
+            GET_ADDRESS_HISTORY,
+            json!({
+                "address": address,
+                "networkId": network.model.id,
+            }),
+        )
+        .await
+        .unwrap();
+
+    let history = &response["getAddressHistory"];
+    assert_eq!(history["total"], address_events.len());
+
+    for (event, payload) in history["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .zip(address_events.iter().rev())
+    {
+        assert_eq!(event["name"], payload.event.name.to_string());
+        assert_eq!(event["txHash"], payload.event.tx_hash);
+        assert_eq!(event["data"], serde_json::to_value(&payload.data).unwrap());
+    }
+
+    let case_events: Vec<&PushPayload> = test_data
+        .iter()
+        .filter(|payload| matches!(payload.data, PushData::Case(_)))
+        .collect();
+    let case_id = match &case_events[0].data {
+        PushData::Case(case) => case.id,
+        _ => unreachable!(),
+    };
+
+    let response = sender
+        .send_graphql(
+            GET_CASE_HISTORY,
+            json!({
+                "id": case_id,
+                "networkId": network.model.id,
+            }),
+        )
+        .await
+        .unwrap();
+
+    let expected = case_events
+        .iter()
+        .filter(|payload| matches!(&payload.data, PushData::Case(case) if case.id == case_id))
+        .count();
+    assert_eq!(response["getCaseHistory"]["total"], expected);
+}
@@ -0,0 +1,64 @@
+use crate::helpers::{RequestSender, TestApp, TEST_CURSOR_BLOCK};
+
+use serde_json::json;
+
+const GET_INDEXER_HEALTH_QUERY: &str = "
+    query GetIndexerHealth($networkId: String) {
+        getIndexerHealth(networkId: $networkId) {
+            id
+            networkId
+            lastHeartbeat
+            secondsSinceHeartbeat
+            cursor {
+                block
+                transaction
+            }
+            status
+        }
+    }
+";
+
+#[tokio::test]
+async fn get_indexer_health_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    // Indexers without heartbeats are dead
+    let response = sender
+        .send_graphql(GET_INDEXER_HEALTH_QUERY, json!({}))
+        .await
+        .unwrap();
+
+    let indexers = response["getIndexerHealth"]
+        .as_array()
+        .expect("Empty response");
+    assert_eq!(indexers.len(), test_app.networks.len());
+
+    for indexer in indexers {
+        assert_eq!(indexer["status"], "DEAD");
+        assert!(indexer["cursor"].is_null());
+    }
+
+    let network = &test_app.networks[0];
+    sender.send_heartbeat(&network.token).await.unwrap();
+
+    let response = sender
+        .send_graphql(
+            GET_INDEXER_HEALTH_QUERY,
+            json!({ "networkId": network.model.id }),
+        )
+        .await
+        .unwrap();
+
+    let indexers = response["getIndexerHealth"]
+        .as_array()
+        .expect("Empty response");
+    assert_eq!(indexers.len(), 1);
+
+    let indexer = &indexers[0];
+    assert_eq!(indexer["networkId"], network.model.id);
+    assert_eq!(indexer["status"], "HEALTHY");
+    assert_eq!(indexer["cursor"]["block"], TEST_CURSOR_BLOCK);
+    assert!(indexer["cursor"]["transaction"].is_null());
+    assert!(indexer["secondsSinceHeartbeat"].as_i64().unwrap() < 60);
+}
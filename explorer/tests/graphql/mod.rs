@@ -1,9 +1,13 @@
 mod address_query;
 mod asset_query;
 mod case_query;
+mod event_query;
+mod indexer_query;
+mod mutation_query;
 mod network_query;
 mod reporter_query;
 mod statistics_query;
+mod subscription_query;
 
 pub use case_query::check_case;
 
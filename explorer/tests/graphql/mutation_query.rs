@@ -0,0 +1,351 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp, WAITING_INTERVAL};
+
+use {
+    hapi_core::client::{
+        entities::{address::Address, reporter::Reporter},
+        events::EventName,
+    },
+    hapi_explorer::{
+        entity::{network, reporter, types::ApiKeyRole},
+        service::EntityMutation,
+    },
+    hapi_indexer::PushData,
+    sea_orm::EntityTrait,
+    serde_json::json,
+    tokio::time::{sleep, Duration},
+};
+
+const CREATE_NETWORK_MUTATION: &str = "
+    mutation CreateNetwork($input: CreateNetworkInput!) {
+        createNetwork(input: $input) {
+            id
+            name
+            backend
+            chainId
+            authority
+            stakeToken
+        }
+    }
+";
+const UPDATE_NETWORK_MUTATION: &str = "
+    mutation UpdateNetwork($input: UpdateNetworkInput!) {
+        updateNetwork(input: $input) {
+            id
+            name
+            authority
+            stakeToken
+        }
+    }
+";
+const DELETE_NETWORK_MUTATION: &str = "
+    mutation DeleteNetwork($id: String!) {
+        deleteNetwork(id: $id) {
+            id
+        }
+    }
+";
+const CREATE_INDEXER_MUTATION: &str = "
+    mutation CreateIndexer($networkId: String!) {
+        createIndexer(networkId: $networkId) {
+            id
+            networkId
+            token
+        }
+    }
+";
+const REVOKE_INDEXER_TOKEN_MUTATION: &str = "
+    mutation RevokeIndexerToken($id: UUID!) {
+        revokeIndexerToken(id: $id)
+    }
+";
+const UPDATE_REPORTER_MUTATION: &str = "
+    mutation UpdateReporter($input: ReporterCorrection!) {
+        updateReporter(input: $input) {
+            networkId
+            id
+            name
+            url
+            updatedAt
+            overriddenAt
+        }
+    }
+";
+const UPDATE_ADDRESS_MUTATION: &str = "
+    mutation UpdateAddress($input: AddressCorrection!) {
+        updateAddress(input: $input) {
+            risk
+        }
+    }
+";
+const GET_NETWORK_QUERY: &str = "
+    query GetNetwork($id: String!) {
+        getNetwork(id: $id) {
+            id
+        }
+    }
+";
+
+async fn create_api_key(test_app: &TestApp, role: ApiKeyRole) -> String {
+    let (_, key) =
+        EntityMutation::create_api_key(&test_app.db_connection, format!("test {role} key"), role)
+            .await
+            .expect("Failed to create API key");
+
+    key
+}
+
+fn network_input() -> serde_json::Value {
+    json!({
+        "input": {
+            "id": "admin_network",
+            "name": "Admin Network",
+            "backend": "EVM",
+            "chainId": "11155111",
+            "authority": "0x0000000000000000000000000000000000000001",
+            "stakeToken": "0x0000000000000000000000000000000000000002",
+        }
+    })
+}
+
+#[tokio::test]
+async fn mutation_authorization_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let read_only_key = create_api_key(&test_app, ApiKeyRole::ReadOnly).await;
+
+    assert!(sender
+        .send_graphql(CREATE_NETWORK_MUTATION, network_input())
+        .await
+        .is_err());
+
+    assert!(sender
+        .send_graphql_with_key(
+            CREATE_NETWORK_MUTATION,
+            network_input(),
+            Some(&read_only_key)
+        )
+        .await
+        .is_err());
+
+    assert!(sender
+        .send_graphql_with_key(
+            GET_NETWORK_QUERY,
+            json!({ "id": test_app.networks[0].model.id }),
+            Some("invalid_key")
+        )
+        .await
+        .is_err());
+
+    let response = sender
+        .send_graphql_with_key(
+            GET_NETWORK_QUERY,
+            json!({ "id": test_app.networks[0].model.id }),
+            Some(&read_only_key),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response["getNetwork"]["id"], test_app.networks[0].model.id);
+
+    let network = network::Entity::find_by_id("admin_network")
+        .one(&test_app.db_connection)
+        .await
+        .expect("Failed to find network");
+
+    assert!(network.is_none());
+}
+
+#[tokio::test]
+async fn network_mutation_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let admin_key = create_api_key(&test_app, ApiKeyRole::Admin).await;
+
+    let response = sender
+        .send_graphql_with_key(CREATE_NETWORK_MUTATION, network_input(), Some(&admin_key))
+        .await
+        .unwrap();
+
+    let created = &response["createNetwork"];
+    assert_eq!(created["id"], "admin_network");
+    assert_eq!(created["name"], "Admin Network");
+    assert_eq!(created["backend"], "EVM");
+    assert_eq!(created["chainId"], "11155111");
+
+    let response = sender
+        .send_graphql_with_key(
+            UPDATE_NETWORK_MUTATION,
+            json!({
+                "input": {
+                    "id": "admin_network",
+                    "name": "Renamed Network",
+                }
+            }),
+            Some(&admin_key),
+        )
+        .await
+        .unwrap();
+
+    let updated = &response["updateNetwork"];
+    assert_eq!(updated["name"], "Renamed Network");
+    assert_eq!(
+        updated["authority"],
+        "0x0000000000000000000000000000000000000001"
+    );
+
+    let network = network::Entity::find_by_id("admin_network")
+        .one(&test_app.db_connection)
+        .await
+        .expect("Failed to find network")
+        .expect("Network does not exist");
+
+    assert_eq!(network.name, "Renamed Network");
+
+    sender
+        .send_graphql_with_key(
+            DELETE_NETWORK_MUTATION,
+            json!({ "id": "admin_network" }),
+            Some(&admin_key),
+        )
+        .await
+        .unwrap();
+
+    let network = network::Entity::find_by_id("admin_network")
+        .one(&test_app.db_connection)
+        .await
+        .expect("Failed to find network");
+
+    assert!(network.is_none());
+}
+
+#[tokio::test]
+async fn indexer_mutation_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let admin_key = create_api_key(&test_app, ApiKeyRole::Admin).await;
+    let network_id = &test_app.networks[0].model.id;
+
+    let response = sender
+        .send_graphql_with_key(
+            CREATE_INDEXER_MUTATION,
+            json!({ "networkId": network_id }),
+            Some(&admin_key),
+        )
+        .await
+        .unwrap();
+
+    let indexer = &response["createIndexer"];
+    assert_eq!(indexer["networkId"], *network_id);
+
+    let token = indexer["token"].as_str().expect("Token is not a string");
+    assert!(sender.send_heartbeat(token).await.is_ok());
+
+    sender
+        .send_graphql_with_key(
+            REVOKE_INDEXER_TOKEN_MUTATION,
+            json!({ "id": indexer["id"] }),
+            Some(&admin_key),
+        )
+        .await
+        .unwrap();
+
+    assert!(sender.send_heartbeat(token).await.is_err());
+}
+
+#[tokio::test]
+async fn entity_correction_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let admin_key = create_api_key(&test_app, ApiKeyRole::Admin).await;
+    let reporters = test_app
+        .global_setup::<Reporter>(&sender, EventName::UpdateReporter)
+        .await;
+
+    let reporter = reporters.first().expect("No reporters in test data");
+
+    let response = sender
+        .send_graphql_with_key(
+            UPDATE_REPORTER_MUTATION,
+            json!({
+                "input": {
+                    "networkId": reporter.network_id,
+                    "id": reporter.data.id,
+                    "name": "Corrected Name",
+                }
+            }),
+            Some(&admin_key),
+        )
+        .await
+        .unwrap();
+
+    let corrected = &response["updateReporter"];
+    assert_eq!(corrected["id"], reporter.data.id.to_string());
+    assert_eq!(corrected["name"], "Corrected Name");
+    assert_eq!(corrected["url"], reporter.data.url);
+    assert!(!corrected["overriddenAt"].is_null());
+
+    // a later chain event still applies over the correction
+    let network = test_app
+        .networks
+        .iter()
+        .find(|network| network.model.id == reporter.network_id)
+        .expect("Failed to find reporter network");
+    let mut payload = get_test_data(&network.network, network.model.chain_id.clone())
+        .into_iter()
+        .find(|payload| payload.event.name == EventName::UpdateReporter)
+        .expect("No reporter update in test data");
+
+    let mut chain_reporter = reporter.data.clone();
+    chain_reporter.name = "Chain Name".to_string();
+    payload.data = PushData::Reporter(chain_reporter);
+    payload.event.timestamp += 1;
+    payload.event.block += 1;
+
+    sender
+        .send("events", &payload, &network.token)
+        .await
+        .expect("Failed to send event");
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let updated = reporter::Entity::find_by_id((reporter.network_id.clone(), reporter.data.id))
+        .one(&test_app.db_connection)
+        .await
+        .expect("Failed to find reporter")
+        .expect("Reporter is not found");
+
+    assert_eq!(updated.name, "Chain Name");
+    assert!(updated.overridden_at.is_none());
+}
+
+#[tokio::test]
+async fn address_correction_risk_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let admin_key = create_api_key(&test_app, ApiKeyRole::Admin).await;
+    let addresses = test_app
+        .global_setup::<Address>(&sender, EventName::UpdateAddress)
+        .await;
+
+    let address = addresses.first().expect("No addresses in test data");
+    let correction = |risk: u8| {
+        json!({
+            "input": {
+                "networkId": address.network_id,
+                "address": address.data.address,
+                "risk": risk,
+            }
+        })
+    };
+
+    assert!(sender
+        .send_graphql_with_key(UPDATE_ADDRESS_MUTATION, correction(11), Some(&admin_key))
+        .await
+        .is_err());
+
+    let response = sender
+        .send_graphql_with_key(UPDATE_ADDRESS_MUTATION, correction(10), Some(&admin_key))
+        .await
+        .unwrap();
+
+    assert_eq!(response["updateAddress"]["risk"], 10);
+}
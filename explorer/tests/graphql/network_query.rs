@@ -1,7 +1,10 @@
 use super::replacer;
 use crate::helpers::{RequestSender, TestApp, TestNetwork};
 
-use serde_json::{json, Value};
+use {
+    hapi_explorer::entity::pagination::DEFAULT_MAX_PAGE_SIZE,
+    serde_json::{json, Value},
+};
 
 const GET_NETWORK_QUERY: &str = "
     query GetNetwork($id: String!) {
@@ -36,6 +39,12 @@ const GET_MANY_NETWORKS: &str = "
             }
             total
             pageCount
+            pageInfo {
+                hasNextPage
+                hasPreviousPage
+                startCursor
+                endCursor
+            }
         }
     }
 ";
@@ -185,6 +194,140 @@ async fn get_paginated_networks_test() {
     check_network(&data, networks.last().unwrap())
 }
 
+#[tokio::test]
+async fn get_cursor_paginated_networks_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let networks = &test_app.networks;
+
+    let page_size = 2;
+    let mut cursor = Value::Null;
+    let mut fetched = vec![];
+
+    loop {
+        let response = sender
+            .send_graphql(
+                GET_MANY_NETWORKS,
+                json!({
+                "input":
+                {
+                    "ordering": "ASC",
+                    "first": page_size,
+                    "after": cursor,
+                }
+                }),
+            )
+            .await
+            .unwrap();
+
+        let networks_response = &response["getManyNetworks"];
+        assert_eq!(networks_response["total"], networks.len());
+
+        let page_info = &networks_response["pageInfo"];
+        assert_eq!(page_info["hasPreviousPage"], !cursor.is_null());
+
+        fetched.extend(
+            networks_response["data"]
+                .as_array()
+                .expect("Empty response")
+                .clone(),
+        );
+
+        cursor = page_info["endCursor"].clone();
+
+        if page_info["hasNextPage"] == false {
+            break;
+        }
+    }
+
+    assert_eq!(fetched.len(), networks.len());
+
+    for (data, network) in networks.iter().zip(&fetched) {
+        check_network(data, network);
+    }
+
+    // Paging backward from the last network returns the preceding networks in the same order
+    let response = sender
+        .send_graphql(
+            GET_MANY_NETWORKS,
+            json!({
+            "input":
+            {
+                "ordering": "ASC",
+                "last": page_size,
+                "before": cursor,
+            }
+            }),
+        )
+        .await
+        .unwrap();
+
+    let networks_response = &response["getManyNetworks"];
+    let page = networks_response["data"]
+        .as_array()
+        .expect("Empty response");
+    let preceding = &networks[networks.len() - 1 - page_size..networks.len() - 1];
+
+    assert_eq!(page.len(), page_size);
+    assert_eq!(networks_response["pageInfo"]["hasNextPage"], true);
+    assert_eq!(
+        networks_response["pageInfo"]["hasPreviousPage"],
+        networks.len() - 1 > page_size
+    );
+
+    for (data, network) in preceding.iter().zip(page) {
+        check_network(data, network);
+    }
+}
+
+#[tokio::test]
+async fn page_size_limit_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+
+    let response = sender
+        .send_graphql(
+            GET_MANY_NETWORKS,
+            json!({
+            "input":
+            {
+                "pagination": {
+                    "pageNum": 1,
+                    "pageSize": DEFAULT_MAX_PAGE_SIZE + 1
+                }
+            }
+            }),
+        )
+        .await;
+    assert!(response.is_err());
+
+    let response = sender
+        .send_graphql(
+            GET_MANY_NETWORKS,
+            json!({
+            "input":
+            {
+                "first": DEFAULT_MAX_PAGE_SIZE + 1
+            }
+            }),
+        )
+        .await;
+    assert!(response.is_err());
+
+    let response = sender
+        .send_graphql(
+            GET_MANY_NETWORKS,
+            json!({
+            "input":
+            {
+                "after": "invalid cursor"
+            }
+            }),
+        )
+        .await;
+    assert!(response.is_err());
+}
+
 #[tokio::test]
 async fn get_searched_networks_test() {
     let test_app = TestApp::start(None).await;
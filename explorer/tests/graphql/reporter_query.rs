@@ -24,6 +24,22 @@ const GET_REPORTER_QUERY: &str = "
         }
     }
 ";
+const GET_REPORTER_RELATIONS_QUERY: &str = "
+    query GetReporter($id: UUID!, $networkId: String!) {
+        getReporter(id: $id, networkId: $networkId) {
+            addresses {
+                networkId
+                address
+                reporterId
+            }
+            assets {
+                networkId
+                address
+                reporterId
+            }
+        }
+    }
+";
 const GET_MANY_REPORTERS: &str = "
     query GetManyReporters(
         $input: ReporterInput!
@@ -112,6 +128,40 @@ async fn get_reporter_test() {
     }
 }
 
+#[tokio::test]
+async fn get_reporter_relations_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let reporters = test_app
+        .global_setup::<Reporter>(&sender, EventName::UpdateReporter)
+        .await;
+
+    for payload in reporters {
+        let response = sender
+            .send_graphql(
+                GET_REPORTER_RELATIONS_QUERY,
+                json!({
+                    "id": payload.data.id,
+                    "networkId": payload.network_id
+                }),
+            )
+            .await
+            .unwrap();
+
+        let reporter = &response["getReporter"];
+
+        for field in ["addresses", "assets"] {
+            let related = reporter[field].as_array().expect("Empty response");
+            assert_eq!(related.len(), 1);
+
+            for value in related {
+                assert_eq!(value["networkId"], payload.network_id);
+                assert_eq!(value["reporterId"], payload.data.id.to_string());
+            }
+        }
+    }
+}
+
 #[tokio::test]
 async fn get_many_reporters_test() {
     let test_app = TestApp::start(None).await;
@@ -259,6 +309,35 @@ async fn get_searched_reporters_test() {
             .first()
             .unwrap();
 
-        check_reporter(&payload, reporter)
+        check_reporter(&payload, reporter);
+
+        let account = &payload.data.account[0..10];
+        for search in [
+            "Authority".to_string(),
+            "authority.com".to_string(),
+            payload.data.id.to_string(),
+            account.to_string(),
+            account.to_lowercase(),
+        ] {
+            let response = sender
+                .send_graphql(
+                    GET_MANY_REPORTERS,
+                    json!({
+                    "input":
+                    {
+                        "filtering": {
+                            "networkId": payload.network_id,
+                        },
+                        "search" : search,
+                    }
+
+                    }),
+                )
+                .await
+                .unwrap();
+
+            let reporters_response = &response["getManyReporters"];
+            assert_eq!(reporters_response["total"], 1);
+        }
     }
 }
@@ -0,0 +1,114 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp, WAITING_INTERVAL};
+
+use {
+    futures_util::{SinkExt, StreamExt},
+    hapi_indexer::PushData,
+    serde_json::{json, Value},
+    tokio::time::{sleep, timeout, Duration},
+    tokio_tungstenite::{
+        connect_async,
+        tungstenite::{client::IntoClientRequest, Message},
+    },
+};
+
+const ADDRESS_CHANGED_SUBSCRIPTION: &str = "
+    subscription AddressChanged($filter: RiskUpdateFilter) {
+        addressChanged(filter: $filter) {
+            networkId
+            address
+            risk
+            category
+        }
+    }
+";
+
+const RECEIVING_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+async fn address_changed_subscription_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+
+    let url = format!(
+        "{}/graphql/ws",
+        test_app.server_addr.replace("http://", "ws://")
+    );
+    let mut request = url.into_client_request().expect("Invalid websocket url");
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        "graphql-transport-ws".parse().unwrap(),
+    );
+
+    let (mut socket, _) = connect_async(request)
+        .await
+        .expect("Failed to connect to websocket");
+
+    send_message(&mut socket, json!({ "type": "connection_init" })).await;
+    assert_eq!(receive_message(&mut socket).await["type"], "connection_ack");
+
+    // Only the updated address has a risk above the filter
+    send_message(
+        &mut socket,
+        json!({
+            "id": "1",
+            "type": "subscribe",
+            "payload": {
+                "query": ADDRESS_CHANGED_SUBSCRIPTION,
+                "variables": {
+                    "filter": { "networkId": network.model.id, "minRisk": 10 }
+                }
+            }
+        }),
+    )
+    .await;
+    sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &test_data).await;
+
+    let expected = test_data
+        .iter()
+        .filter_map(|payload| match &payload.data {
+            PushData::Address(address) if address.risk >= 10 => Some(address),
+            _ => None,
+        })
+        .last()
+        .expect("No address with high risk in test data");
+
+    let message = receive_message(&mut socket).await;
+    assert_eq!(message["type"], "next");
+
+    let value = &message["payload"]["data"]["addressChanged"];
+    assert_eq!(value["networkId"], network.model.id);
+    assert_eq!(value["address"], expected.address);
+    assert_eq!(value["risk"], expected.risk);
+}
+
+async fn send_message<S>(socket: &mut S, message: Value)
+where
+    S: SinkExt<Message> + Unpin,
+    S::Error: std::fmt::Debug,
+{
+    socket
+        .send(Message::Text(message.to_string()))
+        .await
+        .expect("Failed to send message");
+}
+
+async fn receive_message<S>(socket: &mut S) -> Value
+where
+    S: StreamExt<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    loop {
+        let message = timeout(RECEIVING_TIMEOUT, socket.next())
+            .await
+            .expect("Timed out waiting for message")
+            .expect("Websocket is closed")
+            .expect("Failed to receive message");
+
+        if let Message::Text(text) = message {
+            return serde_json::from_str(&text).expect("Invalid message");
+        }
+    }
+}
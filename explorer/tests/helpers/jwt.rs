@@ -6,6 +6,8 @@ use {
 pub(crate) fn create_jwt(secret: &str) -> String {
     let claims = TokenClaims {
         id: get_jwt_id(),
+        network_id: "ethereum".to_string(),
+        jti: get_jwt_id(),
         iat: 1,
         exp: 10000000000,
     };
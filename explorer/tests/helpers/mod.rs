@@ -4,10 +4,11 @@ mod test_app;
 mod test_data;
 
 pub(crate) use jwt::create_jwt;
-pub(crate) use request_sender::RequestSender;
+pub(crate) use request_sender::{RequestSender, TEST_CURSOR_BLOCK};
 pub(crate) use test_app::{
     FromTestPayload, TestApp, TestNetwork, METRICS_ENV_VAR, MIGRATION_COUNT, WAITING_INTERVAL,
 };
 pub(crate) use test_data::{
-    create_address_data, create_asset_data, create_reporter_data, get_test_data, TestData,
+    create_address_data, create_asset_data, create_reporter_data, get_configuration_data,
+    get_confirmation_data, get_test_data, TestData,
 };
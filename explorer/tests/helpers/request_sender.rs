@@ -1,11 +1,14 @@
 use {
     anyhow::{bail, Result},
-    hapi_indexer::get_id_from_jwt,
+    hapi_indexer::{get_id_from_jwt, IndexingCursor},
     reqwest::{Client, Response},
     serde::Serialize,
     serde_json::{json, Value},
 };
 
+/// Block reported in the cursor of test heartbeats
+pub const TEST_CURSOR_BLOCK: u64 = 12345;
+
 pub struct RequestSender {
     pub web_client: Client,
     address: String,
@@ -20,17 +23,28 @@ impl RequestSender {
     }
 
     pub(crate) async fn send_graphql(&self, query: &str, variables: Value) -> Result<Value> {
+        self.send_graphql_with_key(query, variables, None).await
+    }
+
+    pub(crate) async fn send_graphql_with_key(
+        &self,
+        query: &str,
+        variables: Value,
+        api_key: Option<&str>,
+    ) -> Result<Value> {
         let body = serde_json::to_string(&json!({ "query": query, "variables": variables }))
             .expect("Failed to serialize body");
 
-        let response = RequestSender::check_response(
-            self.web_client
-                .post(format!("{}/{}", &self.address, "graphql"))
-                .body(body)
-                .send()
-                .await?,
-        )
-        .await?;
+        let mut request = self
+            .web_client
+            .post(format!("{}/{}", &self.address, "graphql"))
+            .body(body);
+
+        if let Some(api_key) = api_key {
+            request = request.header("X-API-Key", api_key);
+        }
+
+        let response = RequestSender::check_response(request.send().await?).await?;
 
         if let Some(errors) = response.get("errors") {
             bail!("GraphQL request failed: {:?}", errors);
@@ -68,13 +82,18 @@ impl RequestSender {
 
     pub(crate) async fn send_heartbeat(&self, token: &str) -> Result<Value> {
         let id = get_id_from_jwt(token)?;
+
+        self.send_heartbeat_with_id(&id.to_string(), token).await
+    }
+
+    pub(crate) async fn send_heartbeat_with_id(&self, id: &str, token: &str) -> Result<Value> {
         let heartbeat_url = format!("{}/indexer/{}/heartbeat", self.address, id);
 
         let response = self
             .web_client
             .put(heartbeat_url)
             .bearer_auth(token)
-            .json(&json!({"cursor": "Block: 12345"}))
+            .json(&IndexingCursor::Block(TEST_CURSOR_BLOCK))
             .send()
             .await?;
 
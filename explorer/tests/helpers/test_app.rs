@@ -1,14 +1,14 @@
-use super::{create_jwt, get_test_data, RequestSender, TestData};
+use super::{get_test_data, RequestSender, TestData};
 
 use {
     hapi_core::{client::events::EventName, HapiCoreNetwork},
     hapi_explorer::{
         application::Application,
         configuration::Configuration,
-        entity::{address, asset, case, network::Model as NetworkModel, reporter},
+        entity::{address, asset, case, network, network::Model as NetworkModel, reporter},
         observability::setup_tracing,
     },
-    hapi_indexer::{PushData, PushPayload},
+    hapi_indexer::{ContractConfiguration, PushData, PushPayload},
     sea_orm::{DatabaseConnection, EntityTrait},
     std::{env, sync::Arc},
     tokio::{
@@ -21,7 +21,7 @@ use {
 };
 
 pub const WAITING_INTERVAL: u64 = 100;
-pub const MIGRATION_COUNT: u32 = 10;
+pub const MIGRATION_COUNT: u32 = 19;
 pub const METRICS_ENV_VAR: &str = "ENABLE_METRICS";
 const TRACING_ENV_VAR: &str = "ENABLE_TRACING";
 
@@ -254,6 +254,26 @@ impl TestApp {
                     reporter.unlock_timestamp.to_string()
                 );
             }
+            PushData::Authority(authority) => {
+                let network_model = network::Entity::find_by_id(network_id)
+                    .one(db)
+                    .await
+                    .expect("Failed to find network by id")
+                    .expect("Network does not exist");
+
+                assert_eq!(network_model.authority, authority);
+            }
+            PushData::Configuration(ContractConfiguration::Stake(configuration)) => {
+                let network_model = network::Entity::find_by_id(network_id)
+                    .one(db)
+                    .await
+                    .expect("Failed to find network by id")
+                    .expect("Network does not exist");
+
+                assert_eq!(network_model.stake_token, configuration.token);
+            }
+            // Reward configuration is not stored in explorer
+            PushData::Configuration(ContractConfiguration::Reward(_)) => {}
         }
     }
 
@@ -284,12 +304,22 @@ impl TestApp {
         res
     }
 
-    pub async fn send_events(&self, sender: &RequestSender, test_data: &Vec<PushPayload>) {
-        let token = create_jwt("my_ultra_secure_secret");
+    /// Returns the indexer token of the payload network
+    pub fn get_token(&self, payload: &PushPayload) -> &str {
+        self.networks
+            .iter()
+            .find(|network| {
+                network.network == payload.network_data.network
+                    && network.model.chain_id == payload.network_data.chain_id
+            })
+            .map(|network| network.token.as_str())
+            .expect("Failed to find network of the payload")
+    }
 
+    pub async fn send_events(&self, sender: &RequestSender, test_data: &Vec<PushPayload>) {
         for payload in test_data {
             sender
-                .send("events", &payload, &token)
+                .send("events", &payload, self.get_token(payload))
                 .await
                 .expect("Failed to send event");
 
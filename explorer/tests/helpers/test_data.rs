@@ -2,6 +2,7 @@ use {
     chrono::Utc,
     hapi_core::{
         client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
             entities::{
                 address::Address,
                 asset::{Asset, AssetId},
@@ -15,12 +16,18 @@ use {
     },
     hapi_indexer::{NetworkData, PushData, PushEvent, PushPayload},
     rand::{distributions::Alphanumeric, thread_rng, Rng},
-    std::str::FromStr,
+    std::{
+        str::FromStr,
+        sync::atomic::{AtomicU64, Ordering},
+    },
     uuid::Uuid,
 };
 
 use super::jwt::get_jwt_id;
 
+// Test events are created in chain order, each in its own block
+static BLOCK: AtomicU64 = AtomicU64::new(1);
+
 pub struct TestData<T> {
     pub data: T,
     pub network_id: String,
@@ -32,9 +39,10 @@ pub(crate) fn get_test_data(
 ) -> Vec<PushPayload> {
     let mut events = vec![];
 
+    // EVM accounts and addresses are checksummed, as the indexer sends them
     let mut reporter_payload = Reporter {
         id: Uuid::new_v4(),
-        account: "0x922ffdfcb57de5dd6f641f275e98b684ce5576a3".to_string(),
+        account: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
         role: ReporterRole::Publisher,
         status: ReporterStatus::Inactive,
         name: String::from("Publisher reporter"),
@@ -52,7 +60,7 @@ pub(crate) fn get_test_data(
     };
 
     let mut address_payload = Address {
-        address: "0x9e833a87087efd527b1a842742eb0f3548cd82ab".to_string(),
+        address: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
         case_id: case_payload.id.to_owned(),
         reporter_id: reporter_payload.id.to_owned(),
         risk: 6,
@@ -61,7 +69,7 @@ pub(crate) fn get_test_data(
     };
 
     let mut asset_payload = Asset {
-        address: "0xe9dbfa9e9d48393d9d22de10051dcbd91267b756".to_string(),
+        address: "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC".to_string(),
         asset_id: AssetId::from_str("12345678").expect("Failed to parse asset id"),
         case_id: case_payload.id.to_owned(),
         reporter_id: reporter_payload.id.to_owned(),
@@ -255,6 +263,77 @@ pub fn create_asset_data(
     )
 }
 
+pub(crate) fn get_confirmation_data(test_data: &[PushPayload]) -> Vec<PushPayload> {
+    test_data
+        .iter()
+        .filter_map(|payload| {
+            let (name, data) = match &payload.data {
+                PushData::Address(address) if payload.event.name == EventName::UpdateAddress => {
+                    let mut address = address.clone();
+                    address.confirmations += 1;
+
+                    (EventName::ConfirmAddress, PushData::Address(address))
+                }
+                PushData::Asset(asset) if payload.event.name == EventName::UpdateAsset => {
+                    let mut asset = asset.clone();
+                    asset.confirmations += 1;
+
+                    (EventName::ConfirmAsset, PushData::Asset(asset))
+                }
+                _ => return None,
+            };
+
+            Some(create_payload(payload.network_data.clone(), name, data))
+        })
+        .collect()
+}
+
+pub(crate) fn get_configuration_data(
+    network: &HapiCoreNetwork,
+    chain_id: Option<String>,
+) -> Vec<PushPayload> {
+    let network_data = NetworkData {
+        network: network.to_owned(),
+        chain_id,
+        indexer_id: Uuid::parse_str(&get_jwt_id()).expect("Failed to parse jwt id"),
+    };
+
+    let stake_configuration = StakeConfiguration {
+        token: generate_random_string(),
+        unlock_duration: 100,
+        validator_stake: 100.into(),
+        tracer_stake: 100.into(),
+        publisher_stake: 100.into(),
+        authority_stake: 100.into(),
+    };
+
+    let reward_configuration = RewardConfiguration {
+        token: generate_random_string(),
+        address_confirmation_reward: 10.into(),
+        address_tracer_reward: 10.into(),
+        asset_confirmation_reward: 10.into(),
+        asset_tracer_reward: 10.into(),
+    };
+
+    vec![
+        create_payload(
+            network_data.clone(),
+            EventName::SetAuthority,
+            PushData::Authority(generate_random_string()),
+        ),
+        create_payload(
+            network_data.clone(),
+            EventName::UpdateStakeConfiguration,
+            stake_configuration.into(),
+        ),
+        create_payload(
+            network_data,
+            EventName::UpdateRewardConfiguration,
+            reward_configuration.into(),
+        ),
+    ]
+}
+
 fn create_payload(network_data: NetworkData, name: EventName, data: PushData) -> PushPayload {
     let tx_hash = generate_random_string();
 
@@ -263,6 +342,8 @@ fn create_payload(network_data: NetworkData, name: EventName, data: PushData) ->
         tx_hash,
         tx_index: 0,
         timestamp: Utc::now().timestamp() as u64,
+        block: BLOCK.fetch_add(1, Ordering::Relaxed),
+        block_index: 0,
     };
 
     PushPayload {
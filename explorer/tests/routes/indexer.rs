@@ -1,9 +1,14 @@
-use crate::helpers::{create_jwt, RequestSender, TestApp};
+use crate::helpers::{create_jwt, get_test_data, RequestSender, TestApp};
+
+use {hapi_explorer::service::EntityMutation, hapi_indexer::get_id_from_jwt};
 
 /*
 Test cases:
  - heartbeat indexer
  - heartbeat indexer with wrong token
+ - heartbeat indexer with token of another indexer
+ - send events with token of another network
+ - heartbeat indexer with revoked token
  - get indexers
  - check count of indexers
  */
@@ -23,6 +28,29 @@ async fn indexer_processing_test() {
         .await
         .is_err());
 
+    // heartbeat indexer with token of another indexer
+    let (first, second) = (&test_app.networks[0], &test_app.networks[1]);
+    let second_id = get_id_from_jwt(&second.token).unwrap();
+
+    assert!(indexer_mock
+        .send_heartbeat_with_id(&second_id.to_string(), &first.token)
+        .await
+        .is_err());
+
+    // send events with token of another network
+    let test_data = get_test_data(&second.network, second.model.chain_id.clone());
+    assert!(indexer_mock
+        .send("events", &test_data[0], &first.token)
+        .await
+        .is_err());
+
+    // heartbeat indexer with revoked token
+    EntityMutation::set_indexer_token(&test_app.db_connection, second_id, None)
+        .await
+        .unwrap();
+
+    assert!(indexer_mock.send_heartbeat(&second.token).await.is_err());
+
     // get indexers
     let response = indexer_mock.get("indexer").await.unwrap();
 
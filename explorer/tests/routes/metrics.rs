@@ -59,6 +59,13 @@ async fn metrics_test() {
         entity_count,
     );
 
+    // Indexer health is exported for every indexer
+    let indexer_statuses = payload
+        .iter()
+        .filter(|line| line.starts_with("indexer_status{"))
+        .count();
+    assert_eq!(indexer_statuses, test_app.networks.len());
+
     std::env::set_var(METRICS_ENV_VAR, "0");
 }
 
@@ -2,4 +2,5 @@ mod cors;
 mod health_check;
 mod indexer;
 mod metrics;
+mod stats;
 mod webhook_processing;
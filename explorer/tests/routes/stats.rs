@@ -0,0 +1,95 @@
+use crate::helpers::{get_test_data, RequestSender, TestApp};
+
+use {
+    hapi_explorer::entity::{address, asset, statistics::CHART_LENGTH},
+    sea_orm::{EntityTrait, PaginatorTrait},
+};
+
+/*
+Test cases:
+ - get statistics for the network
+ - check breakdowns correspond to entity counts
+ - check response is cached
+ - get statistics for unknown network
+ - get statistics with invalid time window
+ */
+#[tokio::test]
+async fn stats_processing_test() {
+    let test_app = TestApp::start(None).await;
+    let sender = RequestSender::new(test_app.server_addr.to_owned());
+    let network = &test_app.networks[0];
+
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&sender, &test_data).await;
+
+    // get statistics for the network
+    let url = format!("stats?network_id={}&weeks=4", network.model.id);
+    let response = sender.get(&url).await.unwrap();
+    let data = &response["data"];
+
+    let address_count = address::Entity::find()
+        .count(&test_app.db_connection)
+        .await
+        .unwrap();
+    let asset_count = asset::Entity::find()
+        .count(&test_app.db_connection)
+        .await
+        .unwrap();
+
+    assert_eq!(data["dashboard"]["total_addresses_count"], address_count);
+    assert_eq!(data["dashboard"]["total_asset_count"], asset_count);
+    assert_eq!(data["charts"]["labels"].as_array().unwrap().len(), 4);
+
+    // check breakdowns correspond to entity counts
+    let networks = data["breakdowns"]["networks"].as_array().unwrap();
+    assert_eq!(networks.len(), 1);
+    assert_eq!(networks[0]["network_id"], network.model.id);
+    assert_eq!(networks[0]["addresses"], address_count);
+    assert_eq!(networks[0]["assets"], asset_count);
+
+    for breakdown in ["categories", "risks"] {
+        let values = data["breakdowns"][breakdown].as_array().unwrap();
+
+        let sum = |field: &str| {
+            values
+                .iter()
+                .map(|v| v[field].as_u64().unwrap())
+                .sum::<u64>()
+        };
+        assert_eq!(sum("addresses"), address_count);
+        assert_eq!(sum("assets"), asset_count);
+    }
+
+    // check response is cached
+    let another_network = &test_app.networks[1];
+    let test_data = get_test_data(
+        &another_network.network,
+        another_network.model.chain_id.clone(),
+    );
+    test_app.send_events(&sender, &test_data).await;
+
+    assert_eq!(sender.get(&url).await.unwrap(), response);
+
+    let response = sender.get("stats").await.unwrap();
+    assert_eq!(
+        response["data"]["charts"]["labels"]
+            .as_array()
+            .unwrap()
+            .len(),
+        CHART_LENGTH
+    );
+    assert_eq!(
+        response["data"]["breakdowns"]["networks"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+
+    // get statistics for unknown network
+    assert!(sender.get("stats?network_id=unknown").await.is_err());
+
+    // get statistics with invalid time window
+    let url = format!("stats?network_id={}&weeks=0", network.model.id);
+    assert!(sender.get(&url).await.is_err());
+}
@@ -1,18 +1,26 @@
-use crate::helpers::{create_jwt, get_test_data, RequestSender, TestApp, WAITING_INTERVAL};
-use tokio::time::{sleep, Duration};
+use crate::helpers::{
+    get_configuration_data, get_confirmation_data, get_test_data, RequestSender, TestApp,
+    WAITING_INTERVAL,
+};
+use {
+    hapi_core::client::events::EventName,
+    hapi_explorer::entity::event,
+    hapi_indexer::PushData,
+    sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter},
+    tokio::time::{sleep, Duration},
+};
 
 #[tokio::test]
 async fn webhook_processing_test() {
     let test_app = TestApp::start(None).await;
     let indexer_mock = RequestSender::new(test_app.server_addr.clone());
-    let token = create_jwt("my_ultra_secure_secret");
 
     for network in &test_app.networks {
         let test_data = get_test_data(&network.network, network.model.chain_id.clone());
 
         for payload in test_data {
             indexer_mock
-                .send("events", &payload, &token)
+                .send("events", &payload, &network.token)
                 .await
                 .expect("Failed to send event");
             sleep(Duration::from_millis(WAITING_INTERVAL)).await;
@@ -23,3 +31,210 @@ async fn webhook_processing_test() {
         }
     }
 }
+
+#[tokio::test]
+async fn confirmation_and_configuration_processing_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+
+    for network in &test_app.networks {
+        let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+        test_app.send_events(&indexer_mock, &test_data).await;
+
+        let confirmation_data = get_confirmation_data(&test_data);
+        let configuration_data =
+            get_configuration_data(&network.network, network.model.chain_id.clone());
+
+        for payload in confirmation_data.into_iter().chain(configuration_data) {
+            indexer_mock
+                .send("events", &payload, &network.token)
+                .await
+                .expect("Failed to send event");
+            sleep(Duration::from_millis(WAITING_INTERVAL)).await;
+
+            test_app
+                .check_entity(payload.data, network.model.id.clone())
+                .await;
+        }
+    }
+}
+
+/*
+Test cases:
+ - resend events
+ - check events are stored once
+ - send outdated update
+ - send update for unknown entity
+ */
+#[tokio::test]
+async fn idempotent_processing_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+    let db = &test_app.db_connection;
+
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&indexer_mock, &test_data).await;
+
+    // resend events
+    test_app.send_events(&indexer_mock, &test_data).await;
+
+    for payload in &test_data {
+        test_app
+            .check_entity(payload.data.clone(), network.model.id.clone())
+            .await;
+    }
+
+    // check events are stored once
+    assert_eq!(
+        event::Entity::find().count(db).await.unwrap(),
+        test_data.len() as u64
+    );
+
+    // send outdated update
+    let mut outdated = test_data
+        .iter()
+        .rev()
+        .find(|payload| matches!(payload.data, PushData::Address(_)))
+        .cloned()
+        .expect("No address in test data");
+
+    let current = outdated.data.clone();
+    if let PushData::Address(address) = &mut outdated.data {
+        address.risk = 1;
+    }
+    outdated.event.tx_hash = "outdated_tx_hash".to_string();
+    outdated.event.timestamp -= 3600;
+    outdated.event.block -= 1;
+
+    test_app
+        .send_events(&indexer_mock, &vec![outdated.clone()])
+        .await;
+    test_app
+        .check_entity(current, network.model.id.clone())
+        .await;
+
+    // send update for unknown entity
+    let mut unknown = outdated;
+    if let PushData::Address(address) = &mut unknown.data {
+        address.address = "unknown_address".to_string();
+    }
+    unknown.event.tx_hash = "unknown_tx_hash".to_string();
+
+    test_app
+        .send_events(&indexer_mock, &vec![unknown.clone()])
+        .await;
+    test_app
+        .check_entity(unknown.data, network.model.id.clone())
+        .await;
+}
+
+/*
+Test cases:
+ - send several events of one transaction
+ - check each event is stored
+ - resend events of the transaction
+ */
+#[tokio::test]
+async fn same_transaction_events_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+    let db = &test_app.db_connection;
+
+    // Case is created together with its address in one transaction
+    let mut test_data = get_test_data(&network.network, network.model.chain_id.clone())
+        .into_iter()
+        .filter(|payload| {
+            matches!(
+                payload.event.name,
+                EventName::CreateReporter | EventName::CreateCase | EventName::CreateAddress
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let tx_hash = test_data[1].event.tx_hash.clone();
+    for (index, payload) in test_data.iter_mut().skip(1).enumerate() {
+        payload.event.tx_hash = tx_hash.clone();
+        payload.event.tx_index = index as u64;
+    }
+
+    test_app.send_events(&indexer_mock, &test_data).await;
+
+    for payload in &test_data {
+        test_app
+            .check_entity(payload.data.clone(), network.model.id.clone())
+            .await;
+    }
+
+    let count_tx_events = || {
+        event::Entity::find()
+            .filter(event::Column::TxHash.eq(tx_hash.clone()))
+            .count(db)
+    };
+
+    assert_eq!(count_tx_events().await.unwrap(), 2);
+
+    // resend events of the transaction
+    test_app.send_events(&indexer_mock, &test_data).await;
+
+    assert_eq!(count_tx_events().await.unwrap(), 2);
+    assert_eq!(
+        event::Entity::find().count(db).await.unwrap(),
+        test_data.len() as u64
+    );
+}
+
+/*
+Test cases:
+ - send a later update from the block of the stored event
+ - send an earlier update from the same block after it
+ - check the later update is kept
+ */
+#[tokio::test]
+async fn same_block_ordering_test() {
+    let test_app = TestApp::start(None).await;
+    let indexer_mock = RequestSender::new(test_app.server_addr.clone());
+    let network = &test_app.networks[0];
+
+    let test_data = get_test_data(&network.network, network.model.chain_id.clone());
+    test_app.send_events(&indexer_mock, &test_data).await;
+
+    let current = test_data
+        .iter()
+        .rev()
+        .find(|payload| matches!(payload.data, PushData::Address(_)))
+        .cloned()
+        .expect("No address in test data");
+
+    // Block timestamps have second precision, so the events are told apart by their position
+    let update = |risk: u8, block_index: u64| {
+        let mut payload = current.clone();
+
+        if let PushData::Address(address) = &mut payload.data {
+            address.risk = risk;
+        }
+        payload.event.tx_hash = format!("same_block_tx_hash_{block_index}");
+        payload.event.block_index = block_index;
+
+        payload
+    };
+
+    // send a later update from the block of the stored event
+    let later = update(2, 2);
+    test_app
+        .send_events(&indexer_mock, &vec![later.clone()])
+        .await;
+    test_app
+        .check_entity(later.data.clone(), network.model.id.clone())
+        .await;
+
+    // send an earlier update from the same block after it
+    let earlier = update(1, 1);
+    test_app.send_events(&indexer_mock, &vec![earlier]).await;
+
+    // check the later update is kept
+    test_app
+        .check_entity(later.data, network.model.id.clone())
+        .await;
+}
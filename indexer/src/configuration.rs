@@ -2,8 +2,8 @@ use {
     config::{Config, ConfigError, File, FileFormat},
     hapi_core::HapiCoreNetwork,
     serde::Deserialize,
-    serde_with::{serde_as, DurationMilliSeconds},
-    std::{env, time::Duration},
+    serde_with::{serde_as, DurationMilliSeconds, OneOrMany},
+    std::{collections::HashSet, env, time::Duration},
 };
 
 pub const CONFIG_PATH: &str = "configuration.toml";
@@ -23,7 +23,12 @@ pub struct Configuration {
     #[serde(default = "default_listener")]
     pub listener: String,
 
-    pub indexer: IndexerConfiguration,
+    /// Bearer token for the admin API, the API is disabled if not set
+    pub admin_token: Option<String>,
+
+    /// Indexers to run, a single `[indexer]` table or an `[[indexer]]` array
+    #[serde_as(as = "OneOrMany<_>")]
+    pub indexer: Vec<IndexerConfiguration>,
 }
 
 #[serde_as]
@@ -35,8 +40,12 @@ pub struct IndexerConfiguration {
     /// Optionsl network chain ID
     pub chain_id: Option<String>,
 
-    /// The RPC node URL
-    pub rpc_node_url: String,
+    /// The RPC node URLs, a single URL or a list of URLs to fail over between
+    #[serde_as(as = "OneOrMany<_>")]
+    pub rpc_node_url: Vec<String>,
+
+    /// Largest number of requests per second to a single RPC node, unlimited if not set
+    pub rpc_rate_limit: Option<u32>,
 
     /// URL to send webhooks to
     pub webhook_url: String,
@@ -97,5 +106,168 @@ pub fn get_configuration() -> Result<Configuration, ConfigError> {
         )
         .build()?;
 
-    settings.try_deserialize::<Configuration>()
+    let configuration = settings.try_deserialize::<Configuration>()?;
+    configuration.validate()?;
+
+    Ok(configuration)
+}
+
+impl Configuration {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.indexer.is_empty() {
+            return Err(ConfigError::Message(
+                "At least one indexer must be configured".to_string(),
+            ));
+        }
+
+        let mut networks = HashSet::new();
+        let mut state_files = HashSet::new();
+
+        for cfg in &self.indexer {
+            if cfg.rpc_node_url.is_empty() {
+                return Err(ConfigError::Message(format!(
+                    "At least one RPC node URL must be configured for network {}",
+                    cfg.network
+                )));
+            }
+
+            if !networks.insert((cfg.network.to_string(), cfg.chain_id.clone())) {
+                return Err(ConfigError::Message(format!(
+                    "Duplicate indexer for network {} (chain id: {:?})",
+                    cfg.network, cfg.chain_id
+                )));
+            }
+
+            if !state_files.insert(cfg.state_file.clone()) {
+                return Err(ConfigError::Message(format!(
+                    "Indexers must not share the state file: {}",
+                    cfg.state_file
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(toml: &str) -> Result<Configuration, ConfigError> {
+        let configuration = Config::builder()
+            .add_source(File::from_str(toml, FileFormat::Toml))
+            .build()?
+            .try_deserialize::<Configuration>()?;
+        configuration.validate()?;
+
+        Ok(configuration)
+    }
+
+    #[test]
+    fn test_single_indexer() {
+        let cfg = parse(
+            r#"
+            [indexer]
+            network = "ethereum"
+            rpc_node_url = "http://localhost:8545"
+            webhook_url = "http://localhost:3000"
+            contract_address = "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0"
+            jwt_token = "token"
+            "#,
+        )
+        .expect("Failed to parse configuration");
+
+        assert_eq!(cfg.indexer.len(), 1);
+        assert_eq!(cfg.indexer[0].network, HapiCoreNetwork::Ethereum);
+        assert_eq!(cfg.indexer[0].rpc_node_url, vec!["http://localhost:8545"]);
+    }
+
+    #[test]
+    fn test_multiple_rpc_nodes() {
+        let cfg = parse(
+            r#"
+            [indexer]
+            network = "ethereum"
+            rpc_node_url = ["http://localhost:8545", "http://localhost:8546"]
+            rpc_rate_limit = 10
+            webhook_url = "http://localhost:3000"
+            contract_address = "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0"
+            jwt_token = "token"
+            "#,
+        )
+        .expect("Failed to parse configuration");
+
+        assert_eq!(
+            cfg.indexer[0].rpc_node_url,
+            vec!["http://localhost:8545", "http://localhost:8546"]
+        );
+        assert_eq!(cfg.indexer[0].rpc_rate_limit, Some(10));
+    }
+
+    #[test]
+    fn test_no_rpc_nodes() {
+        let err = parse(
+            r#"
+            [indexer]
+            network = "ethereum"
+            rpc_node_url = []
+            webhook_url = "http://localhost:3000"
+            contract_address = "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0"
+            jwt_token = "token"
+            "#,
+        );
+
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_multiple_indexers() {
+        let cfg = parse(
+            r#"
+            [[indexer]]
+            network = "ethereum"
+            rpc_node_url = "http://localhost:8545"
+            webhook_url = "http://localhost:3000"
+            contract_address = "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0"
+            state_file = "data/ethereum.json"
+            jwt_token = "token"
+
+            [[indexer]]
+            network = "near"
+            rpc_node_url = "http://localhost:3030"
+            webhook_url = "http://localhost:3000"
+            contract_address = "hapi.test.near"
+            state_file = "data/near.json"
+            jwt_token = "token"
+            "#,
+        )
+        .expect("Failed to parse configuration");
+
+        assert_eq!(cfg.indexer.len(), 2);
+        assert_eq!(cfg.indexer[1].network, HapiCoreNetwork::Near);
+    }
+
+    #[test]
+    fn test_shared_state_file() {
+        let err = parse(
+            r#"
+            [[indexer]]
+            network = "ethereum"
+            rpc_node_url = "http://localhost:8545"
+            webhook_url = "http://localhost:3000"
+            contract_address = "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0"
+            jwt_token = "token"
+
+            [[indexer]]
+            network = "bsc"
+            rpc_node_url = "http://localhost:8546"
+            webhook_url = "http://localhost:3000"
+            contract_address = "0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0"
+            jwt_token = "token"
+            "#,
+        );
+
+        assert!(err.is_err());
+    }
 }
@@ -0,0 +1,386 @@
+use {
+    axum::{
+        extract::{Path, Query, State},
+        http::{header::AUTHORIZATION, Request, StatusCode},
+        middleware::{self, Next},
+        response::Response,
+        routing::{get, put},
+        Json, Router,
+    },
+    serde::{Deserialize, Serialize},
+    std::sync::Arc,
+    subtle::ConstantTimeEq,
+};
+
+use super::{
+    server::{IndexerHandle, IndexerHandles},
+    IndexerState, IndexingCursor, PushPayload, PAYLOAD_HISTORY_SIZE,
+};
+
+const DEFAULT_PAYLOAD_LIMIT: usize = 10;
+
+type AdminResult<T> = Result<Json<T>, (StatusCode, String)>;
+
+#[derive(Clone)]
+struct AdminState {
+    indexers: IndexerHandles,
+    token: Arc<String>,
+}
+
+/// Creates the admin router, all routes require the bearer token
+pub(crate) fn create_admin_router(indexers: IndexerHandles, token: String) -> Router {
+    let state = AdminState {
+        indexers,
+        token: Arc::new(token),
+    };
+
+    Router::new()
+        .route("/stop", put(stop_all))
+        .route("/indexers/:key/stop", put(stop))
+        .route("/indexers/:key/pause", put(pause))
+        .route("/indexers/:key/resume", put(resume))
+        .route("/indexers/:key/cursor", put(set_cursor))
+        .route("/indexers/:key/check", put(check_for_updates))
+        .route("/indexers/:key/payloads", get(get_payloads))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorize))
+        .with_state(state)
+}
+
+async fn authorize<B>(
+    State(state): State<AdminState>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Result<Response, StatusCode> {
+    let token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "));
+
+    match token {
+        // Compared in constant time to not leak the token through response timing
+        Some(token) if bool::from(token.as_bytes().ct_eq(state.token.as_bytes())) => {
+            Ok(next.run(request).await)
+        }
+        _ => {
+            tracing::warn!("Unauthorized admin request");
+            Err(StatusCode::UNAUTHORIZED)
+        }
+    }
+}
+
+fn get_indexer<'a>(
+    state: &'a AdminState,
+    key: &str,
+) -> Result<&'a IndexerHandle, (StatusCode, String)> {
+    state
+        .indexers
+        .get(key)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, format!("Unknown indexer: {key}")))
+}
+
+fn conflict(action: &str, state: &IndexerState) -> (StatusCode, String) {
+    (
+        StatusCode::CONFLICT,
+        format!("Unable to {action} indexer in state {state:?}"),
+    )
+}
+
+#[derive(Serialize)]
+struct StateOutput {
+    state: IndexerState,
+}
+
+/// Applies the admin transition to the indexer state and returns the resulting state
+async fn apply<F>(state: &AdminState, key: &str, action: &str, f: F) -> AdminResult<StateOutput>
+where
+    F: FnOnce(&IndexerState) -> Option<IndexerState>,
+{
+    let indexer = get_indexer(state, key)?;
+    let mut current = indexer.state.lock().await;
+
+    let new_state = f(&current).ok_or_else(|| conflict(action, &current))?;
+    tracing::info!(key, action, from = ?*current, to = ?new_state, "Admin state change");
+
+    current.transition(new_state);
+
+    Ok(Json(StateOutput {
+        state: current.clone(),
+    }))
+}
+
+#[derive(Serialize)]
+struct StopOutput {
+    success: bool,
+}
+
+async fn stop_all(State(state): State<AdminState>) -> Json<StopOutput> {
+    for indexer in state.indexers.values() {
+        indexer
+            .state
+            .lock()
+            .await
+            .transition(IndexerState::Stopped {
+                message: "Stopped by admin".to_string(),
+            });
+    }
+
+    Json(StopOutput { success: true })
+}
+
+async fn stop(
+    State(state): State<AdminState>,
+    Path(key): Path<String>,
+) -> AdminResult<StateOutput> {
+    apply(&state, &key, "stop", |current| match current {
+        IndexerState::Stopped { .. } => None,
+        _ => Some(IndexerState::Stopped {
+            message: "Stopped by admin".to_string(),
+        }),
+    })
+    .await
+}
+
+async fn pause(
+    State(state): State<AdminState>,
+    Path(key): Path<String>,
+) -> AdminResult<StateOutput> {
+    apply(&state, &key, "pause", |current| match current {
+        IndexerState::CheckForUpdates { cursor }
+        | IndexerState::Processing { cursor }
+        | IndexerState::Waiting { cursor, .. } => Some(IndexerState::Paused {
+            cursor: cursor.clone(),
+        }),
+        _ => None,
+    })
+    .await
+}
+
+async fn resume(
+    State(state): State<AdminState>,
+    Path(key): Path<String>,
+) -> AdminResult<StateOutput> {
+    // Processing continues with the queued jobs and checks for updates once the queue is empty
+    apply(&state, &key, "resume", |current| match current {
+        IndexerState::Paused { cursor } => Some(IndexerState::Processing {
+            cursor: cursor.clone(),
+        }),
+        _ => None,
+    })
+    .await
+}
+
+#[derive(Deserialize)]
+struct SetCursorInput {
+    cursor: IndexingCursor,
+}
+
+async fn set_cursor(
+    State(state): State<AdminState>,
+    Path(key): Path<String>,
+    Json(input): Json<SetCursorInput>,
+) -> AdminResult<StateOutput> {
+    apply(&state, &key, "set cursor of", |current| match current {
+        IndexerState::Stopped { .. } => None,
+        _ => Some(IndexerState::CheckForUpdates {
+            cursor: input.cursor,
+        }),
+    })
+    .await
+}
+
+async fn check_for_updates(
+    State(state): State<AdminState>,
+    Path(key): Path<String>,
+) -> AdminResult<StateOutput> {
+    apply(&state, &key, "check updates of", |current| match current {
+        IndexerState::Waiting { cursor, .. } => Some(IndexerState::CheckForUpdates {
+            cursor: cursor.clone(),
+        }),
+        _ => None,
+    })
+    .await
+}
+
+#[derive(Deserialize)]
+struct PayloadsQuery {
+    limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct PayloadsOutput {
+    payloads: Vec<PushPayload>,
+}
+
+async fn get_payloads(
+    State(state): State<AdminState>,
+    Path(key): Path<String>,
+    Query(query): Query<PayloadsQuery>,
+) -> AdminResult<PayloadsOutput> {
+    let indexer = get_indexer(&state, &key)?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAYLOAD_LIMIT)
+        .min(PAYLOAD_HISTORY_SIZE);
+
+    // The latest payloads go first
+    let payloads = indexer
+        .payloads
+        .lock()
+        .await
+        .iter()
+        .rev()
+        .take(limit)
+        .cloned()
+        .collect();
+
+    Ok(Json(PayloadsOutput { payloads }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        axum::{body::Body, http::Method},
+        tokio::sync::Mutex,
+        tower::ServiceExt,
+    };
+
+    use super::*;
+
+    const TOKEN: &str = "admin-token";
+    const KEY: &str = "ethereum";
+
+    fn create_router(state: IndexerState) -> (Router, Arc<Mutex<IndexerState>>) {
+        let handle = IndexerHandle {
+            state: Arc::new(Mutex::new(state)),
+            payloads: Default::default(),
+        };
+        let state = handle.state.clone();
+        let indexers = Arc::new([(KEY.to_string(), handle)].into_iter().collect());
+
+        (create_admin_router(indexers, TOKEN.to_string()), state)
+    }
+
+    async fn send(
+        router: &Router,
+        uri: &str,
+        token: Option<&str>,
+        body: Option<serde_json::Value>,
+    ) -> StatusCode {
+        let mut request = Request::builder().method(Method::PUT).uri(uri);
+
+        if let Some(token) = token {
+            request = request.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+
+        let request = match body {
+            Some(body) => request
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .unwrap();
+
+        router.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized() {
+        let (router, state) = create_router(IndexerState::Processing {
+            cursor: IndexingCursor::Block(10),
+        });
+        let uri = format!("/indexers/{KEY}/pause");
+
+        assert_eq!(
+            send(&router, &uri, None, None).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            send(&router, &uri, Some("invalid"), None).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            *state.lock().await,
+            IndexerState::Processing {
+                cursor: IndexingCursor::Block(10),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pause_resume() {
+        let (router, state) = create_router(IndexerState::Processing {
+            cursor: IndexingCursor::Block(10),
+        });
+        let pause = format!("/indexers/{KEY}/pause");
+        let resume = format!("/indexers/{KEY}/resume");
+
+        assert_eq!(
+            send(&router, &pause, Some(TOKEN), None).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            *state.lock().await,
+            IndexerState::Paused {
+                cursor: IndexingCursor::Block(10),
+            }
+        );
+
+        // Paused indexer can not be paused again
+        assert_eq!(
+            send(&router, &pause, Some(TOKEN), None).await,
+            StatusCode::CONFLICT
+        );
+
+        assert_eq!(
+            send(&router, &resume, Some(TOKEN), None).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            *state.lock().await,
+            IndexerState::Processing {
+                cursor: IndexingCursor::Block(10),
+            }
+        );
+
+        assert_eq!(
+            send(&router, &resume, Some(TOKEN), None).await,
+            StatusCode::CONFLICT
+        );
+        assert_eq!(
+            send(&router, "/indexers/unknown/pause", Some(TOKEN), None).await,
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reindex() {
+        let (router, state) = create_router(IndexerState::Waiting {
+            cursor: IndexingCursor::Block(10),
+            until: 0,
+        });
+        let uri = format!("/indexers/{KEY}/cursor");
+        let body = serde_json::json!({ "cursor": { "Block": 5 } });
+
+        assert_eq!(
+            send(&router, &uri, Some(TOKEN), Some(body.clone())).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            *state.lock().await,
+            IndexerState::CheckForUpdates {
+                cursor: IndexingCursor::Block(5),
+            }
+        );
+
+        // Stopped indexer can not be reindexed
+        state.lock().await.transition(IndexerState::Stopped {
+            message: "Stopped by admin".to_string(),
+        });
+
+        assert_eq!(
+            send(&router, &uri, Some(TOKEN), Some(body)).await,
+            StatusCode::CONFLICT
+        );
+    }
+}
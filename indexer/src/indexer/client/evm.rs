@@ -1,7 +1,19 @@
 use {
     anyhow::{bail, Result},
-    ethers::{abi::Token, providers::Middleware, types::Filter},
-    hapi_core::{client::events::EventName, HapiCore, HapiCoreEvm},
+    ethers::{
+        abi::Token,
+        providers::Middleware,
+        types::{Filter, U256},
+        utils::{keccak256, to_checksum},
+    },
+    hapi_core::{
+        client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
+            entities::category::Category,
+            events::EventName,
+        },
+        HapiCoreEvm,
+    },
     std::{cmp::min, str::FromStr},
     uuid::Uuid,
 };
@@ -98,6 +110,12 @@ pub(super) async fn process_evm_job(
         .ok_or_else(|| anyhow::anyhow!("Unable to parse block number"))?
         .as_u64();
 
+    // Events of a transaction share the hash, so they are told apart by the log index
+    let log_index = log
+        .log_index
+        .ok_or_else(|| anyhow::anyhow!("Unable to parse log index"))?
+        .as_u64();
+
     let block = client
         .provider
         .get_block(block_number)
@@ -119,21 +137,32 @@ pub(super) async fn process_evm_job(
             | "ReporterActivated"
             | "ReporterDeactivated"
             | "ReporterStakeWithdrawn",
-            [reporter_id, ..],
-        ) => get_evm_reporter_payload(client, reporter_id).await?,
+            [reporter_id, tail @ ..],
+        ) => get_evm_reporter_payload(client, reporter_id, tail, block_number).await?,
         ("CaseCreated" | "CaseUpdated", [case_id, ..]) => {
-            get_evm_case_payload(client, case_id).await?
+            get_evm_case_payload(client, case_id, block_number).await?
+        }
+        ("AddressCreated" | "AddressUpdated", [addr, tail @ ..]) => {
+            get_evm_address_payload(client, addr, tail, block_number).await?
+        }
+        ("AddressConfirmed", [addr, ..]) => {
+            get_evm_address_payload(client, addr, &[], block_number).await?
         }
-        ("AddressCreated" | "AddressUpdated", [addr, ..]) => {
-            get_evm_address_payload(client, addr).await?
+        ("AssetCreated" | "AssetUpdated", [addr, id, tail @ ..]) => {
+            get_evm_asset_payload(client, addr, id, tail, block_number).await?
         }
-        ("AssetCreated" | "AssetUpdated", [addr, id, ..]) => {
-            get_evm_asset_payload(client, addr, id).await?
+        ("AssetConfirmed", [addr, id, ..]) => {
+            get_evm_asset_payload(client, addr, id, &[], block_number).await?
         }
-        ("AuthorityChanged" | "StakeConfigurationChanged" | "RewardConfigurationChanged", [..]) => {
-            tracing::info!("Configuration is changed");
+        ("RoleGranted", [role, account, ..]) if is_authority_role(role) => {
+            get_evm_authority_payload(account)?
+        }
+        ("RoleGranted", [role, ..]) => {
+            tracing::trace!(?role, "Ignoring role grant");
             None
         }
+        ("StakeConfigurationChanged", tokens) => get_evm_stake_configuration_payload(tokens)?,
+        ("RewardConfigurationChanged", tokens) => get_evm_reward_configuration_payload(tokens)?,
         _ => {
             tracing::warn!(name = log_header.name, tokens = ?log_header.tokens, "Ignoring event");
             None
@@ -141,13 +170,22 @@ pub(super) async fn process_evm_job(
     };
 
     if let Some(data) = data {
+        // Authority is changed by granting the authority role
+        let event_name = match log_header.to_ref() {
+            ("RoleGranted", [role, ..]) if is_authority_role(role) => EventName::SetAuthority,
+            (name, _) => EventName::from_str(name)?,
+        };
+
         Ok(Some(vec![PushPayload {
             network_data,
             event: PushEvent {
-                name: EventName::from_str(&log_header.name)?,
+                name: event_name,
                 tx_hash,
-                tx_index: 0,
+                tx_index: log_index,
                 timestamp: block.timestamp.as_u64(),
+                block: block_number,
+                // Log index is unique in the block
+                block_index: log_index,
             },
             data,
         }]))
@@ -159,12 +197,26 @@ pub(super) async fn process_evm_job(
 async fn get_evm_reporter_payload(
     client: &HapiCoreEvm,
     reporter_id: &Token,
+    event_data: &[Token],
+    block: u64,
 ) -> Result<Option<PushData>> {
     if let Some(reporter_id) = reporter_id.clone().into_uint() {
         let reporter_id = Uuid::from_u128(reporter_id.as_u128());
-        tracing::info!(?reporter_id, "Reporter is created or modified");
-
-        let reporter = client.get_reporter(&reporter_id.to_string()).await?;
+        tracing::info!(?reporter_id, block, "Reporter is created or modified");
+
+        let mut reporter = client
+            .get_reporter_at_block(&reporter_id.to_string(), block)
+            .await?;
+
+        // ReporterCreated and ReporterUpdated events carry the account and the role
+        if let [account, role, ..] = event_data {
+            if let Some(account) = account.clone().into_address() {
+                reporter.account = to_checksum(&account, None);
+            }
+            if let Some(role) = role.clone().into_uint() {
+                reporter.role = (role.low_u32() as u8).try_into()?;
+            }
+        }
 
         Ok(Some(reporter.into()))
     } else {
@@ -173,12 +225,18 @@ async fn get_evm_reporter_payload(
     }
 }
 
-async fn get_evm_case_payload(client: &HapiCoreEvm, case_id: &Token) -> Result<Option<PushData>> {
+async fn get_evm_case_payload(
+    client: &HapiCoreEvm,
+    case_id: &Token,
+    block: u64,
+) -> Result<Option<PushData>> {
     if let Some(case_id) = case_id.clone().into_uint() {
         let case_id = Uuid::from_u128(case_id.as_u128());
-        tracing::info!(?case_id, "Case is created or modified");
+        tracing::info!(?case_id, block, "Case is created or modified");
 
-        let case = client.get_case(&case_id.to_string()).await?;
+        let case = client
+            .get_case_at_block(&case_id.to_string(), block)
+            .await?;
 
         Ok(Some(case.into()))
     } else {
@@ -187,11 +245,22 @@ async fn get_evm_case_payload(client: &HapiCoreEvm, case_id: &Token) -> Result<O
     }
 }
 
-async fn get_evm_address_payload(client: &HapiCoreEvm, addr: &Token) -> Result<Option<PushData>> {
+async fn get_evm_address_payload(
+    client: &HapiCoreEvm,
+    addr: &Token,
+    event_data: &[Token],
+    block: u64,
+) -> Result<Option<PushData>> {
     if let Some(addr) = addr.clone().into_address() {
-        tracing::info!(?addr, "Address is created or modified");
+        tracing::info!(?addr, block, "Address is created or modified");
+
+        let mut address = client
+            .get_address_at_block(&format!("{addr:?}"), block)
+            .await?;
 
-        let address = client.get_address(&format!("{addr:?}")).await?;
+        if let [risk, category, ..] = event_data {
+            (address.risk, address.category) = decode_risk_and_category(risk, category)?;
+        }
 
         Ok(Some(address.into()))
     } else {
@@ -204,11 +273,19 @@ async fn get_evm_asset_payload(
     client: &HapiCoreEvm,
     addr: &Token,
     id: &Token,
+    event_data: &[Token],
+    block: u64,
 ) -> Result<Option<PushData>> {
     if let (Some(addr), Some(id)) = (addr.clone().into_address(), id.clone().into_uint()) {
-        tracing::info!(?addr, ?id, "Asset is created or modified");
+        tracing::info!(?addr, ?id, block, "Asset is created or modified");
 
-        let asset = client.get_asset(&format!("{addr:?}"), &id.into()).await?;
+        let mut asset = client
+            .get_asset_at_block(&format!("{addr:?}"), &id.into(), block)
+            .await?;
+
+        if let [risk, category, ..] = event_data {
+            (asset.risk, asset.category) = decode_risk_and_category(risk, category)?;
+        }
 
         Ok(Some(asset.into()))
     } else {
@@ -216,3 +293,94 @@ async fn get_evm_asset_payload(
         Ok(None)
     }
 }
+
+fn is_authority_role(role: &Token) -> bool {
+    role.clone().into_fixed_bytes() == Some(keccak256("AUTHORITY_ROLE").to_vec())
+}
+
+fn get_evm_authority_payload(account: &Token) -> Result<Option<PushData>> {
+    if let Some(account) = account.clone().into_address() {
+        tracing::info!(?account, "Authority is changed");
+
+        Ok(Some(PushData::Authority(to_checksum(&account, None))))
+    } else {
+        tracing::warn!(?account, "Unable to parse authority");
+        Ok(None)
+    }
+}
+
+fn get_evm_stake_configuration_payload(tokens: &[Token]) -> Result<Option<PushData>> {
+    if let [token, unlock_duration, validator_stake, tracer_stake, publisher_stake, authority_stake] =
+        tokens
+    {
+        tracing::info!("Stake configuration is changed");
+
+        Ok(Some(
+            StakeConfiguration {
+                token: decode_address(token)?,
+                unlock_duration: decode_uint(unlock_duration)?.as_u64(),
+                validator_stake: decode_uint(validator_stake)?.into(),
+                tracer_stake: decode_uint(tracer_stake)?.into(),
+                publisher_stake: decode_uint(publisher_stake)?.into(),
+                authority_stake: decode_uint(authority_stake)?.into(),
+            }
+            .into(),
+        ))
+    } else {
+        tracing::warn!(?tokens, "Unable to parse stake configuration");
+        Ok(None)
+    }
+}
+
+fn get_evm_reward_configuration_payload(tokens: &[Token]) -> Result<Option<PushData>> {
+    if let [token, address_confirmation_reward, address_tracer_reward, asset_confirmation_reward, asset_tracer_reward] =
+        tokens
+    {
+        tracing::info!("Reward configuration is changed");
+
+        Ok(Some(
+            RewardConfiguration {
+                token: decode_address(token)?,
+                address_confirmation_reward: decode_uint(address_confirmation_reward)?.into(),
+                address_tracer_reward: decode_uint(address_tracer_reward)?.into(),
+                asset_confirmation_reward: decode_uint(asset_confirmation_reward)?.into(),
+                asset_tracer_reward: decode_uint(asset_tracer_reward)?.into(),
+            }
+            .into(),
+        ))
+    } else {
+        tracing::warn!(?tokens, "Unable to parse reward configuration");
+        Ok(None)
+    }
+}
+
+fn decode_address(token: &Token) -> Result<String> {
+    token
+        .clone()
+        .into_address()
+        .map(|address| to_checksum(&address, None))
+        .ok_or_else(|| anyhow::anyhow!("Unable to parse address from {token:?}"))
+}
+
+fn decode_uint(token: &Token) -> Result<U256> {
+    token
+        .clone()
+        .into_uint()
+        .ok_or_else(|| anyhow::anyhow!("Unable to parse uint from {token:?}"))
+}
+
+fn decode_risk_and_category(risk: &Token, category: &Token) -> Result<(u8, Category)> {
+    let risk = risk
+        .clone()
+        .into_uint()
+        .ok_or_else(|| anyhow::anyhow!("Unable to parse risk"))?;
+    let category = category
+        .clone()
+        .into_uint()
+        .ok_or_else(|| anyhow::anyhow!("Unable to parse category"))?;
+
+    Ok((
+        risk.low_u32().try_into()?,
+        (category.low_u32() as u8).try_into()?,
+    ))
+}
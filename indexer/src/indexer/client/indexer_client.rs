@@ -1,6 +1,9 @@
 use {
     anyhow::Result,
-    hapi_core::{HapiCoreEvm, HapiCoreNear, HapiCoreNetwork, HapiCoreOptions, HapiCoreSolana},
+    hapi_core::{
+        Commitment, FeeOptions, HapiCoreEvm, HapiCoreNear, HapiCoreNetwork, HapiCoreOptions,
+        HapiCoreSolana, TransportOptions,
+    },
     std::time::Duration,
     tokio::time::sleep,
     uuid::Uuid,
@@ -42,17 +45,25 @@ pub(crate) struct IndexerClient {
 impl IndexerClient {
     pub fn new(
         network_data: NetworkData,
-        rpc_node_url: &str,
+        rpc_node_urls: &[String],
+        rpc_rate_limit: Option<u32>,
         contract_address: &str,
         fetching_delay: Duration,
     ) -> Result<Self> {
         let options = HapiCoreOptions {
-            provider_url: rpc_node_url.to_string(),
+            provider_urls: rpc_node_urls.to_vec(),
             contract_address: contract_address.to_string(),
             private_key: None,
             chain_id: None,
             account_id: None,
             network: network_data.network.clone(),
+            transport: TransportOptions {
+                rate_limit: rpc_rate_limit,
+                ..Default::default()
+            },
+            commitment: Commitment::default(),
+            fees: FeeOptions::default(),
+            nonce_account: None,
         };
 
         let client = match network_data.network {
@@ -94,8 +105,8 @@ impl IndexerClient {
             (HapiClient::Evm(client), IndexerJob::Log(log)) => {
                 process_evm_job(client, log, self.network_data.clone()).await
             }
-            (HapiClient::Solana(client), IndexerJob::Transaction(hash)) => {
-                process_solana_job(client, hash, self.network_data.clone()).await
+            (HapiClient::Solana(client), IndexerJob::Transaction(tx)) => {
+                process_solana_job(client, tx, self.network_data.clone()).await
             }
             (HapiClient::Near(client), IndexerJob::TransactionReceipt(receipt)) => {
                 process_near_job(client, receipt, self.network_data.clone()).await
@@ -5,3 +5,4 @@ mod solana;
 
 pub(crate) use indexer_client::IndexerClient;
 pub use near::NearReceipt;
+pub use solana::SolanaTransaction;
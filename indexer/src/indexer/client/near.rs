@@ -1,14 +1,23 @@
 use {
     anyhow::{bail, Result},
+    futures::{stream, StreamExt},
     hapi_core::{
-        client::{entities::asset::AssetId, events::EventName},
-        HapiCore, HapiCoreNear,
+        client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
+            entities::{asset::AssetId, category::Category},
+            events::EventName,
+        },
+        HapiCoreNear,
     },
     near_jsonrpc_client::methods::{
         EXPERIMENTAL_changes::RpcStateChangesInBlockByTypeRequest,
         EXPERIMENTAL_receipt::RpcReceiptRequest,
     },
-    near_jsonrpc_primitives::types::receipts::ReceiptReference,
+    near_jsonrpc_primitives::types::{
+        blocks::{RpcBlockError, RpcBlockRequest},
+        changes::RpcStateChangesError,
+        receipts::ReceiptReference,
+    },
     near_primitives::{
         hash::CryptoHash,
         types::{BlockId, BlockReference, Finality, FunctionArgs, StoreKey},
@@ -16,14 +25,15 @@ use {
             ActionView, ReceiptEnumView, ReceiptView, StateChangeCauseView, StateChangesRequestView,
         },
     },
-    std::{cmp::min, collections::HashSet},
+    std::{cmp::min, collections::HashSet, time::Duration},
+    tokio::time::sleep,
     uuid::Uuid,
 };
 
 use crate::{
     indexer::{
         client::indexer_client::PAGE_SIZE,
-        push::{NetworkData, PushEvent, PushPayload},
+        push::{NetworkData, PushData, PushEvent, PushPayload},
         IndexerJob,
     },
     IndexingCursor,
@@ -31,68 +41,208 @@ use crate::{
 
 use super::indexer_client::FetchingArtifacts;
 
+const DEFAULT_NEAR_CONCURRENCY: usize = 10;
+lazy_static::lazy_static! {
+    static ref NEAR_CONCURRENCY: usize = std::env::var("INDEXER_NEAR_CONCURRENCY").map_or(DEFAULT_NEAR_CONCURRENCY, |s| s.parse::<usize>().unwrap_or(DEFAULT_NEAR_CONCURRENCY)).max(1);
+}
+
+/// NEAR limits the number of actions in a receipt
+const MAX_RECEIPT_ACTIONS: u64 = 100;
+
+const NEAR_RETRY_COUNT: u32 = 3;
+const NEAR_RETRY_DELAY: Duration = Duration::from_millis(500);
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct NearReceipt {
     pub hash: CryptoHash,
     pub block_height: u64,
     pub timestamp: u64,
+    /// Position of the receipt among the contract receipts of the block
+    pub index: u64,
 }
 
-async fn get_receipts_list(
+/// Outcome of scanning a single block height
+enum BlockScan {
+    /// No block was produced at this height
+    Missing,
+    /// Receipts that changed the contract state in this block
+    Found(Vec<IndexerJob>),
+}
+
+/// Checks that no block was produced at the height, so the next known block jumps over it.
+/// Nodes without the block, e.g. non-archival nodes after garbage collection, return
+/// the same unknown block error, so it does not prove that the height is skipped
+async fn is_skipped_height(
     client: &HapiCoreNear,
-    start_block: u64,
+    block_height: u64,
     final_block: u64,
-) -> Result<Vec<IndexerJob>> {
-    let mut event_list = vec![];
-
-    for block_height in start_block..final_block + 1 {
-        let block_id = BlockId::Height(block_height);
+) -> Result<bool> {
+    // The final block and the blocks after it can still be produced
+    if block_height >= final_block {
+        return Ok(false);
+    }
 
-        let changes_in_block = client
+    for height in block_height + 1..=final_block {
+        match client
             .client
-            .call(RpcStateChangesInBlockByTypeRequest {
-                block_reference: BlockReference::BlockId(block_id.clone()),
-                state_changes_request: StateChangesRequestView::DataChanges {
-                    account_ids: vec![client.contract_address.clone()],
-                    key_prefix: StoreKey::from(vec![]),
-                },
+            .call(RpcBlockRequest {
+                block_reference: BlockReference::BlockId(BlockId::Height(height)),
             })
-            .await;
+            .await
+        {
+            Ok(block) => {
+                return Ok(block
+                    .header
+                    .prev_height
+                    .map_or(false, |prev_height| prev_height < block_height))
+            }
+            Err(e) if matches!(e.handler_error(), Some(RpcBlockError::UnknownBlock { .. })) => {
+                continue
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
 
-        match changes_in_block {
-            Ok(changes) => {
-                if !changes.changes.is_empty() {
-                    let timestamp = client
-                        .client
-                        .call(near_jsonrpc_primitives::types::blocks::RpcBlockRequest {
-                            block_reference: BlockReference::BlockId(block_id),
-                        })
-                        .await?
-                        .header
-                        .timestamp_nanosec;
-
-                    changes
-                        .changes
-                        .iter()
-                        .map(|change| get_hash_from_cause(&change.cause))
-                        .collect::<HashSet<CryptoHash>>()
-                        .iter()
-                        .for_each(|&hash| {
-                            event_list.push(IndexerJob::TransactionReceipt(NearReceipt {
-                                hash,
-                                block_height,
-                                timestamp,
-                            }));
-                        })
-                }
+    Ok(false)
+}
+
+async fn scan_block(
+    client: &HapiCoreNear,
+    block_height: u64,
+    final_block: u64,
+) -> Result<BlockScan> {
+    let block_id = BlockId::Height(block_height);
+
+    let changes = match client
+        .client
+        .call(RpcStateChangesInBlockByTypeRequest {
+            block_reference: BlockReference::BlockId(block_id.clone()),
+            state_changes_request: StateChangesRequestView::DataChanges {
+                account_ids: vec![client.contract_address.clone()],
+                key_prefix: StoreKey::from(vec![]),
+            },
+        })
+        .await
+    {
+        Ok(changes) => changes,
+        Err(e)
+            if matches!(
+                e.handler_error(),
+                Some(RpcStateChangesError::UnknownBlock { .. })
+            ) =>
+        {
+            if is_skipped_height(client, block_height, final_block).await? {
+                return Ok(BlockScan::Missing);
+            }
+
+            bail!("Block {block_height} is unknown to the node, it may be not archival");
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    if changes.changes.is_empty() {
+        return Ok(BlockScan::Found(vec![]));
+    }
+
+    let timestamp = client
+        .client
+        .call(RpcBlockRequest {
+            block_reference: BlockReference::BlockId(block_id),
+        })
+        .await?
+        .header
+        .timestamp_nanosec;
+
+    let mut seen = HashSet::new();
+
+    // Receipts are deduplicated in the order their changes appear in the block
+    let jobs = changes
+        .changes
+        .iter()
+        .map(|change| get_hash_from_cause(&change.cause))
+        .filter(|hash| seen.insert(*hash))
+        .enumerate()
+        .map(|(index, hash)| {
+            IndexerJob::TransactionReceipt(NearReceipt {
+                hash,
+                block_height,
+                timestamp,
+                index: index as u64,
+            })
+        })
+        .collect();
+
+    Ok(BlockScan::Found(jobs))
+}
+
+async fn scan_block_with_retries(
+    client: &HapiCoreNear,
+    block_height: u64,
+    final_block: u64,
+) -> Result<BlockScan> {
+    let mut attempt = 1;
+
+    loop {
+        match scan_block(client, block_height, final_block).await {
+            Ok(scan) => return Ok(scan),
+            Err(e) if attempt < NEAR_RETRY_COUNT => {
+                tracing::warn!(block_height, attempt, "Failed to scan block: {:?}", e);
+
+                sleep(NEAR_RETRY_DELAY * attempt).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e.context(format!("Failed to scan block {block_height}"))),
+        }
+    }
+}
+
+/// Scans blocks concurrently and returns the found jobs with the last height
+/// up to which all blocks were scanned
+async fn get_receipts_list(
+    client: &HapiCoreNear,
+    start_block: u64,
+    final_block: u64,
+) -> Result<(Vec<IndexerJob>, Option<u64>)> {
+    let mut event_list = vec![];
+    let mut last_scanned = None;
+    let mut missing_count = 0;
+
+    // Results are yielded in height order, so the scan stops at the first failed block
+    let mut scans = stream::iter(start_block..=final_block)
+        .map(|block_height| async move {
+            (
+                block_height,
+                scan_block_with_retries(client, block_height, final_block).await,
+            )
+        })
+        .buffered(*NEAR_CONCURRENCY);
+
+    while let Some((block_height, scan)) = scans.next().await {
+        match scan {
+            Ok(BlockScan::Found(mut jobs)) => event_list.append(&mut jobs),
+            Ok(BlockScan::Missing) => {
+                tracing::debug!(block_height, "Block is missing");
+                missing_count += 1;
             }
             Err(e) => {
                 tracing::error!(block_height, "Failed to fetch near jobs: {:?}", e);
+
+                if last_scanned.is_none() {
+                    return Err(e);
+                }
+
+                break;
             }
-        };
+        }
+
+        last_scanned = Some(block_height);
     }
 
-    Ok(event_list)
+    if missing_count > 0 {
+        tracing::info!(count = missing_count, "Skipped missing heights");
+    }
+
+    Ok((event_list, last_scanned))
 }
 
 #[tracing::instrument(skip(client))]
@@ -108,7 +258,7 @@ pub(super) async fn fetch_near_jobs(
 
     let latest_block = client
         .client
-        .call(near_jsonrpc_primitives::types::blocks::RpcBlockRequest {
+        .call(RpcBlockRequest {
             block_reference: BlockReference::Finality(Finality::Final),
         })
         .await?
@@ -120,14 +270,14 @@ pub(super) async fn fetch_near_jobs(
 
         let final_block = min(PAGE_SIZE.to_owned() - 1 + start_block, latest_block);
 
-        let event_list: Vec<IndexerJob> =
+        let (event_list, last_scanned) =
             get_receipts_list(client, start_block, final_block).await?;
 
-        tracing::info!(count = event_list.len(), "Found jobs");
+        tracing::info!(count = event_list.len(), ?last_scanned, "Found jobs");
 
         return Ok(FetchingArtifacts {
             jobs: event_list,
-            cursor: IndexingCursor::Block(final_block),
+            cursor: last_scanned.map_or(current_cursor.clone(), IndexingCursor::Block),
         });
     }
 
@@ -154,88 +304,148 @@ pub(super) async fn process_near_job(
         })
         .await?;
 
-    if let Some((method, args)) = get_method_from_receipt(&receipt_view) {
-        let event_name: EventName = {
-            if method == "ft_on_transfer" {
-                // because activation in NEAR is done by ft_transfer_call
-                EventName::ActivateReporter
-            } else {
-                match method.parse() {
-                    Ok(event_name) => event_name,
-                    Err(e) => {
-                        tracing::error!(method, "Failed to parse method {}: {:?}", method, e);
-                        return Ok(None);
-                    }
-                }
-            }
-        };
-
-        let data = match event_name {
-            EventName::CreateReporter
-            | EventName::UpdateReporter
-            | EventName::DeactivateReporter
-            | EventName::Unstake => {
-                tracing::info!("Reporter updated");
-
-                let id = get_id_from_args(&args).await?;
-                client.get_reporter(&id.to_string()).await?.into()
-            }
-            EventName::ActivateReporter => {
-                tracing::info!("Reporter activated");
-
-                let account_id = get_field_from_args(&args, "sender_id")?;
-                client.get_reporter_by_account(&account_id).await?.into()
-            }
-            EventName::CreateCase | EventName::UpdateCase => {
-                tracing::info!("Case is created or modified");
+    // Batches are sent as several function calls in one receipt
+    let mut payloads = vec![];
+
+    for (action_index, method, args) in get_methods_from_receipt(&receipt_view) {
+        if let Some((event_name, data)) =
+            get_near_payload(client, receipt.block_height, &method, &args).await?
+        {
+            payloads.push(PushPayload {
+                network_data: network_data.clone(),
+                event: PushEvent {
+                    name: event_name,
+                    tx_hash: receipt.hash.to_string(),
+                    tx_index: action_index,
+                    timestamp: receipt.timestamp,
+                    block: receipt.block_height,
+                    block_index: receipt.index * MAX_RECEIPT_ACTIONS + action_index,
+                },
+                data,
+            });
+        }
+    }
 
-                let id = get_id_from_args(&args).await?;
-                client.get_case(&id.to_string()).await?.into()
-            }
-            EventName::CreateAddress | EventName::UpdateAddress => {
-                tracing::info!("Address is created or modified");
+    Ok((!payloads.is_empty()).then_some(payloads))
+}
 
-                let address = get_field_from_args(&args, "address")?;
-                client.get_address(&address).await?.into()
-            }
-            EventName::ConfirmAddress | EventName::ConfirmAsset => {
-                tracing::info!("Confirmation is received");
-                return Ok(None);
-            }
-            EventName::CreateAsset | EventName::UpdateAsset => {
-                tracing::info!("Asset is created or modified");
-                let addr = get_field_from_args(&args, "address")?;
-                let asset_id = get_field_from_args(&args, "id")?;
-                client
-                    .get_asset(&addr, &asset_id.parse::<AssetId>()?)
-                    .await?
-                    .into()
+/// Returns the event and the entity data of a function call, entity state is read at the
+/// block where the receipt was executed
+async fn get_near_payload(
+    client: &HapiCoreNear,
+    block_height: u64,
+    method: &str,
+    args: &FunctionArgs,
+) -> Result<Option<(EventName, PushData)>> {
+    let event_name: EventName = {
+        if method == "ft_on_transfer" {
+            // because activation in NEAR is done by ft_transfer_call
+            EventName::ActivateReporter
+        } else {
+            match method.parse() {
+                Ok(event_name) => event_name,
+                Err(e) => {
+                    tracing::error!(method, "Failed to parse method {}: {:?}", method, e);
+                    return Ok(None);
+                }
             }
+        }
+    };
 
-            EventName::UpdateStakeConfiguration
-            | EventName::UpdateRewardConfiguration
-            | EventName::SetAuthority => {
-                tracing::info!("Configuration is changed");
-                return Ok(None);
-            }
-            EventName::Initialize => {
-                tracing::info!("Contract initialized");
-                return Ok(None);
-            }
-        };
+    let data = match event_name {
+        EventName::CreateReporter
+        | EventName::UpdateReporter
+        | EventName::DeactivateReporter
+        | EventName::Unstake => {
+            tracing::info!("Reporter updated");
+
+            let id = get_id_from_args(args).await?;
+            client
+                .get_reporter_at_block(&id.to_string(), block_height)
+                .await?
+                .into()
+        }
+        EventName::ActivateReporter => {
+            tracing::info!("Reporter activated");
+
+            let account_id = get_field_from_args(args, "sender_id")?;
+            client
+                .get_reporter_by_account_at_block(&account_id, block_height)
+                .await?
+                .into()
+        }
+        EventName::CreateCase | EventName::UpdateCase => {
+            tracing::info!("Case is created or modified");
+
+            let id = get_id_from_args(args).await?;
+            client
+                .get_case_at_block(&id.to_string(), block_height)
+                .await?
+                .into()
+        }
+        EventName::CreateAddress | EventName::UpdateAddress => {
+            tracing::info!("Address is created or modified");
+
+            let address = get_field_from_args(args, "address")?;
+            let mut address = client.get_address_at_block(&address, block_height).await?;
+
+            (address.risk, address.category) = get_risk_and_category_from_args(args)?;
+
+            address.into()
+        }
+        EventName::ConfirmAddress => {
+            tracing::info!("Address is confirmed");
+
+            let address = get_field_from_args(args, "address")?;
+            client
+                .get_address_at_block(&address, block_height)
+                .await?
+                .into()
+        }
+        EventName::CreateAsset | EventName::UpdateAsset => {
+            tracing::info!("Asset is created or modified");
+            let addr = get_field_from_args(args, "address")?;
+            let asset_id = get_field_from_args(args, "id")?;
+            let mut asset = client
+                .get_asset_at_block(&addr, &asset_id.parse::<AssetId>()?, block_height)
+                .await?;
+
+            (asset.risk, asset.category) = get_risk_and_category_from_args(args)?;
+
+            asset.into()
+        }
+        EventName::ConfirmAsset => {
+            tracing::info!("Asset is confirmed");
+
+            let addr = get_field_from_args(args, "address")?;
+            let asset_id = get_field_from_args(args, "id")?;
+            client
+                .get_asset_at_block(&addr, &asset_id.parse::<AssetId>()?, block_height)
+                .await?
+                .into()
+        }
+        EventName::UpdateStakeConfiguration => {
+            tracing::info!("Stake configuration is changed");
+
+            get_value_from_args::<StakeConfiguration>(args, "stake_configuration")?.into()
+        }
+        EventName::UpdateRewardConfiguration => {
+            tracing::info!("Reward configuration is changed");
+
+            get_value_from_args::<RewardConfiguration>(args, "reward_configuration")?.into()
+        }
+        EventName::SetAuthority => {
+            tracing::info!("Authority is changed");
+
+            PushData::Authority(get_field_from_args(args, "authority")?)
+        }
+        EventName::Initialize => {
+            tracing::info!("Contract initialized");
+            return Ok(None);
+        }
+    };
 
-        return Ok(Some(vec![PushPayload {
-            network_data,
-            event: PushEvent {
-                name: event_name,
-                tx_hash: receipt.hash.to_string(),
-                tx_index: 0,
-                timestamp: receipt.timestamp,
-            },
-            data,
-        }]));
-    }
-    Ok(None)
+    Ok(Some((event_name, data)))
 }
 
 fn get_hash_from_cause(cause: &StateChangeCauseView) -> CryptoHash {
@@ -246,25 +456,20 @@ fn get_hash_from_cause(cause: &StateChangeCauseView) -> CryptoHash {
     }
 }
 
-fn get_method_from_receipt(receipt: &ReceiptView) -> Option<(String, FunctionArgs)> {
+/// Returns the function calls of the receipt with their indexes among the receipt actions
+fn get_methods_from_receipt(receipt: &ReceiptView) -> Vec<(u64, String, FunctionArgs)> {
     match &receipt.receipt {
-        ReceiptEnumView::Action {
-            signer_id: _,
-            signer_public_key: _,
-            gas_price: _,
-            output_data_receivers: _,
-            input_data_ids: _,
-            actions,
-        } => match &actions[0] {
-            ActionView::FunctionCall {
-                method_name,
-                args,
-                gas: _,
-                deposit: _,
-            } => Some((method_name.clone(), args.clone())),
-            _ => None,
-        },
-        _ => None,
+        ReceiptEnumView::Action { actions, .. } => actions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, action)| match action {
+                ActionView::FunctionCall {
+                    method_name, args, ..
+                } => Some((index as u64, method_name.clone(), args.clone())),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
     }
 }
 
@@ -278,6 +483,27 @@ fn get_field_from_args(args: &FunctionArgs, field: &str) -> Result<String> {
     }
 }
 
+fn get_value_from_args<T: serde::de::DeserializeOwned>(
+    args: &FunctionArgs,
+    field: &str,
+) -> Result<T> {
+    let mut json: serde_json::Value = serde_json::from_slice(args)?;
+
+    serde_json::from_value(json[field].take())
+        .map_err(|e| anyhow::anyhow!("Failed to parse {} from {:?}: {}", field, json, e))
+}
+
+fn get_risk_and_category_from_args(args: &FunctionArgs) -> Result<(u8, Category)> {
+    let json: serde_json::Value = serde_json::from_slice(args)?;
+
+    let risk = json["risk_score"]
+        .as_u64()
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse risk_score from {:?}", json))?;
+    let category = get_field_from_args(args, "category")?;
+
+    Ok((risk.try_into()?, category.parse()?))
+}
+
 async fn get_id_from_args(args: &FunctionArgs) -> Result<Uuid> {
     let json: serde_json::Value = serde_json::from_slice(args)?;
 
@@ -287,3 +513,268 @@ async fn get_id_from_args(args: &FunctionArgs) -> Result<Uuid> {
         Err(anyhow::anyhow!("Failed to parse id from {:?}", json))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        hapi_core::{Commitment, FeeOptions, HapiCoreNetwork, HapiCoreOptions, TransportOptions},
+        mockito::{Matcher, Server, ServerGuard},
+        near_primitives::{
+            types::Balance,
+            views::{BlockHeaderView, BlockView},
+        },
+        serde_json::json,
+    };
+
+    use super::*;
+
+    const BLOCK_HEIGHT: u64 = 100;
+    const FINAL_BLOCK: u64 = 110;
+
+    fn create_client(server: &ServerGuard) -> HapiCoreNear {
+        HapiCoreNear::new(HapiCoreOptions {
+            provider_urls: vec![server.url()],
+            contract_address: "hapi.test.near".to_string(),
+            private_key: None,
+            chain_id: None,
+            account_id: None,
+            network: HapiCoreNetwork::Near,
+            transport: TransportOptions::default(),
+            commitment: Commitment::default(),
+            fees: FeeOptions::default(),
+            nonce_account: None,
+        })
+        .expect("Failed to create client")
+    }
+
+    fn unknown_block_response() -> String {
+        json!({
+            "jsonrpc": "2.0",
+            "error": {
+                "name": "HANDLER_ERROR",
+                "cause": {
+                    "name": "UNKNOWN_BLOCK",
+                    "info": { "error_message": "DB Not Found Error" }
+                },
+                "code": -32000,
+                "message": "Server error",
+                "data": "DB Not Found Error"
+            },
+            "id": 1
+        })
+        .to_string()
+    }
+
+    fn block_response(height: u64, prev_height: u64) -> String {
+        let header = BlockHeaderView {
+            height,
+            prev_height: Some(prev_height),
+            epoch_id: CryptoHash::default(),
+            next_epoch_id: CryptoHash::default(),
+            hash: CryptoHash::default(),
+            prev_hash: CryptoHash::default(),
+            prev_state_root: CryptoHash::default(),
+            chunk_receipts_root: CryptoHash::default(),
+            chunk_headers_root: CryptoHash::default(),
+            chunk_tx_root: CryptoHash::default(),
+            outcome_root: CryptoHash::default(),
+            chunks_included: 0,
+            challenges_root: CryptoHash::default(),
+            timestamp: 123,
+            timestamp_nanosec: 123,
+            random_value: CryptoHash::default(),
+            validator_proposals: vec![],
+            chunk_mask: vec![],
+            gas_price: 0,
+            block_ordinal: None,
+            rent_paid: Balance::default(),
+            validator_reward: Balance::default(),
+            total_supply: Balance::default(),
+            challenges_result: vec![],
+            last_final_block: CryptoHash::default(),
+            last_ds_final_block: CryptoHash::default(),
+            next_bp_hash: CryptoHash::default(),
+            block_merkle_root: CryptoHash::default(),
+            epoch_sync_data_hash: None,
+            approvals: vec![],
+            signature: near_crypto::Signature::default(),
+            latest_protocol_version: 0,
+            block_body_hash: None,
+        };
+
+        json!({
+            "jsonrpc": "2.0",
+            "result": BlockView {
+                author: "validator.test.near".parse().unwrap(),
+                header,
+                chunks: vec![],
+            },
+            "id": 1
+        })
+        .to_string()
+    }
+
+    async fn mock_unknown_changes(server: &mut ServerGuard) {
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(unknown_block_response())
+            .match_body(Matcher::PartialJson(json!({
+                "method": "EXPERIMENTAL_changes",
+                "params": { "block_id": BLOCK_HEIGHT }
+            })))
+            .create_async()
+            .await;
+    }
+
+    async fn mock_block(server: &mut ServerGuard, height: u64, body: String) {
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .match_body(Matcher::PartialJson(json!({
+                "method": "block",
+                "params": { "block_id": height }
+            })))
+            .create_async()
+            .await;
+    }
+
+    fn changes_response(receipts: &[CryptoHash]) -> String {
+        let changes: Vec<_> = receipts
+            .iter()
+            .map(|receipt_hash| {
+                json!({
+                    "cause": { "type": "receipt_processing", "receipt_hash": receipt_hash },
+                    "type": "data_update",
+                    "change": {
+                        "account_id": "hapi.test.near",
+                        "key_base64": "AA==",
+                        "value_base64": "AA=="
+                    }
+                })
+            })
+            .collect();
+
+        json!({
+            "jsonrpc": "2.0",
+            "result": {
+                "block_hash": CryptoHash::default(),
+                "changes": changes
+            },
+            "id": 1
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_receipt_order() {
+        let mut server = Server::new_async().await;
+
+        let receipts: Vec<_> = (0..5u8).map(|i| CryptoHash::hash_bytes(&[i])).collect();
+
+        // A receipt that changes several keys appears more than once
+        let changes = [
+            receipts[3],
+            receipts[0],
+            receipts[4],
+            receipts[3],
+            receipts[1],
+            receipts[2],
+            receipts[0],
+        ];
+
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(changes_response(&changes))
+            .match_body(Matcher::PartialJson(json!({
+                "method": "EXPERIMENTAL_changes",
+                "params": { "block_id": BLOCK_HEIGHT }
+            })))
+            .create_async()
+            .await;
+        mock_block(
+            &mut server,
+            BLOCK_HEIGHT,
+            block_response(BLOCK_HEIGHT, BLOCK_HEIGHT - 1),
+        )
+        .await;
+
+        let client = create_client(&server);
+
+        let jobs = match scan_block(&client, BLOCK_HEIGHT, FINAL_BLOCK).await {
+            Ok(BlockScan::Found(jobs)) => jobs,
+            _ => panic!("Block should be found"),
+        };
+
+        let positions: Vec<_> = jobs
+            .into_iter()
+            .map(|job| match job {
+                IndexerJob::TransactionReceipt(receipt) => (receipt.hash, receipt.index),
+                _ => panic!("Unexpected job"),
+            })
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                (receipts[3], 0),
+                (receipts[0], 1),
+                (receipts[4], 2),
+                (receipts[1], 3),
+                (receipts[2], 4),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_skipped_height() {
+        let mut server = Server::new_async().await;
+        mock_unknown_changes(&mut server).await;
+
+        // The next height is skipped too, the block after it is built on the previous block
+        mock_block(&mut server, BLOCK_HEIGHT + 1, unknown_block_response()).await;
+        mock_block(
+            &mut server,
+            BLOCK_HEIGHT + 2,
+            block_response(BLOCK_HEIGHT + 2, BLOCK_HEIGHT - 1),
+        )
+        .await;
+
+        let client = create_client(&server);
+
+        assert!(matches!(
+            scan_block(&client, BLOCK_HEIGHT, FINAL_BLOCK).await,
+            Ok(BlockScan::Missing)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_block() {
+        let mut server = Server::new_async().await;
+        mock_unknown_changes(&mut server).await;
+
+        // The next block is built on the unknown one, so the node has no data of it
+        mock_block(
+            &mut server,
+            BLOCK_HEIGHT + 1,
+            block_response(BLOCK_HEIGHT + 1, BLOCK_HEIGHT),
+        )
+        .await;
+
+        let client = create_client(&server);
+
+        assert!(scan_block(&client, BLOCK_HEIGHT, FINAL_BLOCK)
+            .await
+            .is_err());
+
+        // Heights from the final block can not be confirmed as skipped
+        assert!(scan_block(&client, BLOCK_HEIGHT, BLOCK_HEIGHT)
+            .await
+            .is_err());
+    }
+}
@@ -3,9 +3,10 @@ use {
     hapi_core::HapiCoreSolana,
     hapi_core::{
         client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
             entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
             events::EventName,
-            solana::DecodedInstruction,
+            solana::{DecodedInstruction, DecodedInstructionData, InstructionData},
         },
         get_solana_account,
     },
@@ -22,11 +23,27 @@ use crate::indexer::{
     IndexerJob, IndexingCursor,
 };
 
+const NETWORK_ACCOUNT_INDEX: usize = 1;
+const NEW_AUTHORITY_ACCOUNT_INDEX: usize = 2;
+const MINT_ACCOUNT_INDEX: usize = 2;
 const REPORTER_ACCOUNT_INDEX: usize = 2;
 const CASE_ACCOUNT_INDEX: usize = 3;
 const ADDRESS_ACCOUNT_INDEX: usize = 4;
 const ASSET_ACCOUNT_INDEX: usize = 4;
 
+/// Instruction indexes of a transaction fit in `u8`
+const MAX_TX_INSTRUCTIONS: u64 = 256;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SolanaTransaction {
+    pub signature: String,
+    pub slot: u64,
+    /// Position of the transaction among the program transactions of the slot.
+    /// Transactions are counted back from the last one in the slot, down from `u32::MAX`,
+    /// so positions don't change when fetching resumes in the middle of the slot
+    pub index: u64,
+}
+
 async fn get_signature_list(
     client: &HapiCoreSolana,
     signature_cursor: Option<Signature>,
@@ -34,6 +51,8 @@ async fn get_signature_list(
 ) -> Result<Vec<IndexerJob>> {
     let mut recent_tx = None;
     let mut signature_list = VecDeque::new();
+    let mut last_slot = None;
+    let mut position = 0;
 
     loop {
         let config = GetConfirmedSignaturesForAddress2Config {
@@ -53,14 +72,26 @@ async fn get_signature_list(
         if let Some(recent) = signature_batch.last() {
             recent_tx = Some(Signature::from_str(&recent.signature)?);
 
+            // Signatures are listed from the latest, in reverse order within a slot
             for sign in signature_batch {
                 tracing::info!(
                     tx_hash = sign.signature.to_string(),
                     block = sign.block_time,
+                    slot = sign.slot,
                     "Found transaction",
                 );
 
-                signature_list.push_front(IndexerJob::Transaction(sign.signature.to_string()));
+                position = match last_slot {
+                    Some(slot) if slot == sign.slot => position + 1,
+                    _ => 0,
+                };
+                last_slot = Some(sign.slot);
+
+                signature_list.push_front(IndexerJob::Transaction(SolanaTransaction {
+                    signature: sign.signature.to_string(),
+                    slot: sign.slot,
+                    index: u32::MAX as u64 - position,
+                }));
             }
 
             sleep(fetching_delay).await;
@@ -104,12 +135,13 @@ pub(super) async fn fetch_solana_jobs(
     })
 }
 
-#[tracing::instrument(skip(client, network_data))]
+#[tracing::instrument(skip(client, tx, network_data), fields(signature = %tx.signature))]
 pub(super) async fn process_solana_job(
     client: &HapiCoreSolana,
-    signature: &str,
+    tx: &SolanaTransaction,
     network_data: NetworkData,
 ) -> Result<Option<Vec<PushPayload>>> {
+    let signature = tx.signature.as_str();
     let instructions = client.get_hapi_instructions(signature).await?;
 
     if instructions.is_empty() {
@@ -140,6 +172,8 @@ pub(super) async fn process_solana_job(
                     tx_hash: signature.to_string(),
                     tx_index: instruction.id.into(),
                     timestamp: instruction.blocktime,
+                    block: tx.slot,
+                    block_index: tx.index * MAX_TX_INSTRUCTIONS + u64::from(instruction.id),
                 },
                 data,
             });
@@ -149,6 +183,7 @@ pub(super) async fn process_solana_job(
     Ok(Some(payloads))
 }
 
+/// Account data reflects the latest state, so values passed with the instruction take precedence
 async fn get_instruction_data(
     client: &HapiCoreSolana,
     instruction: &DecodedInstruction,
@@ -160,7 +195,23 @@ async fn get_instruction_data(
         | EventName::DeactivateReporter
         | EventName::Unstake => {
             let account = get_pubkey(&instruction.account_keys, REPORTER_ACCOUNT_INDEX)?;
-            let reporter = get_solana_account!(client, &account, Reporter)?;
+            let mut reporter = get_solana_account!(client, &account, Reporter)?;
+
+            match &instruction.data {
+                InstructionData::Decoded(DecodedInstructionData::CreateReporter(data)) => {
+                    reporter.account = data.account.to_string();
+                    reporter.name = data.name.clone();
+                    reporter.role = (data.role.clone() as u8).try_into()?;
+                    reporter.url = data.url.clone();
+                }
+                InstructionData::Decoded(DecodedInstructionData::UpdateReporter(data)) => {
+                    reporter.account = data.account.to_string();
+                    reporter.name = data.name.clone();
+                    reporter.role = (data.role.clone() as u8).try_into()?;
+                    reporter.url = data.url.clone();
+                }
+                _ => {}
+            }
 
             tracing::info!(?reporter.id, "Reporter is created or modified");
 
@@ -169,7 +220,20 @@ async fn get_instruction_data(
 
         EventName::CreateCase | EventName::UpdateCase => {
             let account = get_pubkey(&instruction.account_keys, CASE_ACCOUNT_INDEX)?;
-            let case = get_solana_account!(client, &account, Case)?;
+            let mut case = get_solana_account!(client, &account, Case)?;
+
+            match &instruction.data {
+                InstructionData::Decoded(DecodedInstructionData::CreateCase(data)) => {
+                    case.name = data.name.clone();
+                    case.url = data.url.clone();
+                }
+                InstructionData::Decoded(DecodedInstructionData::UpdateCase(data)) => {
+                    case.name = data.name.clone();
+                    case.url = data.url.clone();
+                    case.status = (data.status.clone() as u8).try_into()?;
+                }
+                _ => {}
+            }
 
             tracing::info!(?case.id, "Case is created or modified");
 
@@ -178,7 +242,19 @@ async fn get_instruction_data(
 
         EventName::CreateAddress | EventName::UpdateAddress => {
             let account = get_pubkey(&instruction.account_keys, ADDRESS_ACCOUNT_INDEX)?;
-            let address = get_solana_account!(client, &account, Address)?;
+            let mut address = get_solana_account!(client, &account, Address)?;
+
+            match &instruction.data {
+                InstructionData::Decoded(DecodedInstructionData::CreateAddress(data)) => {
+                    address.category = (data.category.clone() as u8).try_into()?;
+                    address.risk = data.risk;
+                }
+                InstructionData::Decoded(DecodedInstructionData::UpdateAddress(data)) => {
+                    address.category = (data.category.clone() as u8).try_into()?;
+                    address.risk = data.risk;
+                }
+                _ => {}
+            }
 
             tracing::info!(address.address, "Address is created or modified");
 
@@ -186,29 +262,109 @@ async fn get_instruction_data(
         }
         EventName::CreateAsset | EventName::UpdateAsset => {
             let account = get_pubkey(&instruction.account_keys, ASSET_ACCOUNT_INDEX)?;
-            let asset = get_solana_account!(client, &account, Asset)?;
+            let mut asset = get_solana_account!(client, &account, Asset)?;
+
+            match &instruction.data {
+                InstructionData::Decoded(DecodedInstructionData::CreateAsset(data)) => {
+                    asset.category = (data.category.clone() as u8).try_into()?;
+                    asset.risk = data.risk_score;
+                }
+                InstructionData::Decoded(DecodedInstructionData::UpdateAsset(data)) => {
+                    asset.category = (data.category.clone() as u8).try_into()?;
+                    asset.risk = data.risk_score;
+                }
+                _ => {}
+            }
 
             tracing::info!(asset.address, ?asset.asset_id, "Asset is created or modified");
 
             return Ok(Some(asset.into()));
         }
 
-        EventName::Initialize => {
-            tracing::info!("Network created");
+        EventName::ConfirmAddress => {
+            let account = get_pubkey(&instruction.account_keys, ADDRESS_ACCOUNT_INDEX)?;
+            let address = get_solana_account!(client, &account, Address)?;
+
+            tracing::info!(address.address, "Address is confirmed");
+
+            return Ok(Some(address.into()));
+        }
+        EventName::ConfirmAsset => {
+            let account = get_pubkey(&instruction.account_keys, ASSET_ACCOUNT_INDEX)?;
+            let asset = get_solana_account!(client, &account, Asset)?;
+
+            tracing::info!(asset.address, ?asset.asset_id, "Asset is confirmed");
+
+            return Ok(Some(asset.into()));
         }
-        EventName::UpdateStakeConfiguration
-        | EventName::UpdateRewardConfiguration
-        | EventName::SetAuthority => {
-            tracing::info!("Configuration is changed");
+
+        EventName::SetAuthority => {
+            let authority = get_pubkey(&instruction.account_keys, NEW_AUTHORITY_ACCOUNT_INDEX)?;
+
+            tracing::info!(%authority, "Authority is changed");
+
+            return Ok(Some(PushData::Authority(authority.to_string())));
         }
-        EventName::ConfirmAddress | EventName::ConfirmAsset => {
-            tracing::info!("Confirmation is received");
+        EventName::UpdateStakeConfiguration => {
+            let configuration = match &instruction.data {
+                InstructionData::Decoded(DecodedInstructionData::UpdateStakeConfiguration(
+                    data,
+                )) => StakeConfiguration {
+                    token: get_pubkey(&instruction.account_keys, MINT_ACCOUNT_INDEX)?.to_string(),
+                    unlock_duration: data.unlock_duration,
+                    validator_stake: data.validator_stake.into(),
+                    tracer_stake: data.tracer_stake.into(),
+                    publisher_stake: data.publisher_stake.into(),
+                    authority_stake: data.authority_stake.into(),
+                },
+                _ => get_network_account(client, &instruction.account_keys)
+                    .await?
+                    .try_into()?,
+            };
+
+            tracing::info!(configuration.token, "Stake configuration is changed");
+
+            return Ok(Some(configuration.into()));
+        }
+        EventName::UpdateRewardConfiguration => {
+            let configuration = match &instruction.data {
+                InstructionData::Decoded(DecodedInstructionData::UpdateRewardConfiguration(
+                    data,
+                )) => RewardConfiguration {
+                    token: get_pubkey(&instruction.account_keys, MINT_ACCOUNT_INDEX)?.to_string(),
+                    address_confirmation_reward: data.address_confirmation_reward.into(),
+                    address_tracer_reward: data.address_tracer_reward.into(),
+                    asset_confirmation_reward: data.asset_confirmation_reward.into(),
+                    asset_tracer_reward: data.asset_tracer_reward.into(),
+                },
+                _ => get_network_account(client, &instruction.account_keys)
+                    .await?
+                    .try_into()?,
+            };
+
+            tracing::info!(configuration.token, "Reward configuration is changed");
+
+            return Ok(Some(configuration.into()));
+        }
+        EventName::Initialize => {
+            tracing::info!("Network created");
         }
     }
 
     Ok(None)
 }
 
+async fn get_network_account(
+    client: &HapiCoreSolana,
+    accounts: &[String],
+) -> Result<hapi_core_solana::Network> {
+    let account = get_pubkey(accounts, NETWORK_ACCOUNT_INDEX)?;
+
+    Ok(client
+        .get_account_data::<hapi_core_solana::Network>(&account)
+        .await?)
+}
+
 fn get_pubkey(accounts: &[String], index: usize) -> Result<Pubkey> {
     Ok(Pubkey::from_str(
         accounts
@@ -1,8 +1,26 @@
-use anyhow::{bail, Result};
+use {
+    anyhow::{bail, Result},
+    tokio::time::Instant,
+};
 
 use crate::{Indexer, IndexingCursor};
 
 impl Indexer {
+    /// Sends a heartbeat unless one was sent within the wait interval
+    pub(crate) async fn send_due_heartbeat(&mut self, cursor: &IndexingCursor) -> Result<()> {
+        if self
+            .last_heartbeat
+            .is_some_and(|sent| sent.elapsed() < self.wait_interval_ms)
+        {
+            return Ok(());
+        }
+
+        self.send_heartbeat(cursor).await?;
+        self.last_heartbeat = Some(Instant::now());
+
+        Ok(())
+    }
+
     pub(crate) async fn send_heartbeat(&self, cursor: &IndexingCursor) -> Result<()> {
         let url = format!(
             "{}/indexer/{}/heartbeat",
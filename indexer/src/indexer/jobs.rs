@@ -1,4 +1,4 @@
-use super::client::NearReceipt;
+use super::client::{NearReceipt, SolanaTransaction};
 use {
     ethers::types::Log,
     serde::{Deserialize, Serialize},
@@ -6,7 +6,7 @@ use {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) enum IndexerJob {
-    Transaction(String),
+    Transaction(SolanaTransaction),
     Log(Log),
     TransactionReceipt(NearReceipt),
 }
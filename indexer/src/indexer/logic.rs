@@ -11,27 +11,33 @@ use crate::{
 
 use super::{
     now, Indexer, IndexerClient, IndexerJob, IndexerState, IndexingCursor, PersistedState,
+    PAYLOAD_HISTORY_SIZE,
 };
 
 impl Indexer {
     pub fn new(cfg: IndexerConfiguration) -> Result<Self> {
-        tracing::info!(network = ?cfg.network, "Initializing indexer");
+        tracing::info!(network = ?cfg.network, chain_id = ?cfg.chain_id, "Initializing indexer");
         let network_data = NetworkData {
             indexer_id: get_id_from_jwt(&cfg.jwt_token)?,
             network: cfg.network,
             chain_id: cfg.chain_id,
         };
+        let key = network_data.key();
         let client = IndexerClient::new(
             network_data,
             &cfg.rpc_node_url,
+            cfg.rpc_rate_limit,
             &cfg.contract_address,
             cfg.fetching_delay,
         )?;
 
         Ok(Self {
+            key,
             wait_interval_ms: cfg.wait_interval_ms,
+            last_heartbeat: None,
             state: Arc::new(Mutex::new(IndexerState::Init)),
             jobs: VecDeque::new(),
+            payloads: Arc::new(Mutex::new(VecDeque::with_capacity(PAYLOAD_HISTORY_SIZE))),
             client,
             state_file: PathBuf::from(cfg.state_file),
             web_client: reqwest::Client::new(),
@@ -40,11 +46,28 @@ impl Indexer {
         })
     }
 
+    #[tracing::instrument(name = "indexer", skip(self), fields(key = %self.key))]
     pub async fn run(&mut self) -> Result<()> {
         loop {
-            let new_state = self.next().await?;
+            let state = self.get_state().await;
 
-            if !self.check_transition(new_state).await {
+            if let IndexerState::Stopped { .. } = state {
+                break;
+            }
+
+            let new_state = match self.next(state.clone()).await {
+                Ok(new_state) => new_state,
+                Err(e) => {
+                    // Only this indexer stops, the failure is reported in its state
+                    self.state.lock().await.transition(IndexerState::Stopped {
+                        message: format!("Indexer failed: {e}"),
+                    });
+
+                    return Err(e);
+                }
+            };
+
+            if !self.check_transition(&state, new_state).await {
                 break;
             }
         }
@@ -52,20 +75,51 @@ impl Indexer {
         Ok(())
     }
 
-    async fn check_transition(&mut self, new_state: IndexerState) -> bool {
-        self.state.lock().await.transition(new_state)
+    async fn check_transition(
+        &mut self,
+        old_state: &IndexerState,
+        new_state: IndexerState,
+    ) -> bool {
+        let mut state = self.state.lock().await;
+
+        // The state was changed through the admin API while the step was running
+        if *state != *old_state {
+            // A stop of the step, e.g. with no transactions to index, is not lost
+            if let IndexerState::Stopped { .. } = new_state {
+                return state.transition(new_state);
+            }
+
+            match (&mut *state, new_state.cursor()) {
+                // The step moved the cursor, the paused indexer resumes from it
+                (IndexerState::Paused { cursor }, Some(step_cursor)) => {
+                    tracing::info!(%step_cursor, "Indexer paused by admin, keeping step cursor");
+
+                    *cursor = step_cursor.clone();
+                }
+                _ => tracing::warn!(
+                    state = ?*state,
+                    discarded = ?new_state,
+                    "State changed by admin, discarding step result"
+                ),
+            }
+
+            return !matches!(*state, IndexerState::Stopped { .. });
+        }
+
+        state.transition(new_state)
     }
 
     async fn get_state(&self) -> IndexerState {
         self.state.lock().await.clone()
     }
 
-    async fn next(&mut self) -> Result<IndexerState> {
-        match self.get_state().await {
+    async fn next(&mut self, state: IndexerState) -> Result<IndexerState> {
+        match state {
             IndexerState::Init => self.handle_init().await,
             IndexerState::CheckForUpdates { cursor } => self.handle_check_for_updates(cursor).await,
             IndexerState::Processing { cursor } => self.handle_process(cursor).await,
             IndexerState::Waiting { until, cursor } => self.handle_waiting(until, cursor).await,
+            IndexerState::Paused { cursor } => self.handle_paused(cursor).await,
             IndexerState::Stopped { .. } => bail!("Stopped indexer should not be running"),
         }
     }
@@ -124,6 +178,8 @@ impl Indexer {
         let artifacts = self.client.fetch_jobs(&cursor).await?;
         let state = self.get_updated_state(&artifacts.jobs, cursor, artifacts.cursor.clone())?;
 
+        // Jobs left in the queue belong to a cursor that was replaced by admin
+        self.jobs.clear();
         self.jobs.extend(artifacts.jobs);
 
         Ok(state)
@@ -131,6 +187,9 @@ impl Indexer {
 
     #[tracing::instrument(name = "process", skip(self))]
     async fn handle_process(&mut self, cursor: IndexingCursor) -> Result<IndexerState> {
+        // A long backfill keeps the indexer alive for the explorer
+        self.send_due_heartbeat(&cursor).await?;
+
         if let Some(job) = self.jobs.pop_front() {
             if let Some(payload) = self.client.handle_process(&job).await? {
                 for event in payload {
@@ -160,7 +219,7 @@ impl Indexer {
 
     #[tracing::instrument(name = "waiting", skip(self))]
     async fn handle_waiting(&mut self, until: u64, cursor: IndexingCursor) -> Result<IndexerState> {
-        self.send_heartbeat(&cursor).await?;
+        self.send_due_heartbeat(&cursor).await?;
 
         if now()? > until {
             Ok(IndexerState::CheckForUpdates { cursor })
@@ -169,4 +228,13 @@ impl Indexer {
             Ok(IndexerState::Waiting { until, cursor })
         }
     }
+
+    #[tracing::instrument(name = "paused", skip(self))]
+    async fn handle_paused(&mut self, cursor: IndexingCursor) -> Result<IndexerState> {
+        self.send_due_heartbeat(&cursor).await?;
+
+        sleep(self.wait_interval_ms).await;
+
+        Ok(IndexerState::Paused { cursor })
+    }
 }
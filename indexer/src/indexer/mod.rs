@@ -6,9 +6,10 @@ use {
         sync::Arc,
         time::{Duration, SystemTime, UNIX_EPOCH},
     },
-    tokio::sync::Mutex,
+    tokio::{sync::Mutex, time::Instant},
 };
 
+pub(crate) mod admin;
 pub(crate) mod client;
 pub(crate) mod heartbeat;
 pub(crate) mod jobs;
@@ -23,23 +24,36 @@ pub(crate) use {
     client::IndexerClient,
     jobs::IndexerJob,
     persistence::PersistedState,
+    push::PushPayload,
     state::{IndexerState, IndexingCursor},
 };
 
+/// The number of recently pushed payloads kept for the admin API
+pub(crate) const PAYLOAD_HISTORY_SIZE: usize = 100;
+
 fn now() -> Result<u64> {
     Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
 }
 
 pub struct Indexer {
+    /// Unique key of the indexed network
+    key: String,
+
     /// Current state of the indexer
     state: Arc<Mutex<IndexerState>>,
 
     /// Stack of transactions to index
     jobs: VecDeque<IndexerJob>,
 
+    /// Recently pushed payloads, the latest is at the back
+    payloads: Arc<Mutex<VecDeque<PushPayload>>>,
+
     /// The number of milliseconds between wait checks
     wait_interval_ms: Duration,
 
+    /// When the last heartbeat was sent, heartbeats are sent at most once per wait interval
+    last_heartbeat: Option<Instant>,
+
     /// Abstract client to access blockchain data
     client: IndexerClient,
 
@@ -2,6 +2,7 @@ use {
     anyhow::{bail, Result},
     hapi_core::{
         client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
             entities::{address::Address, asset::Asset, case::Case, reporter::Reporter},
             events::EventName,
         },
@@ -11,10 +12,10 @@ use {
     uuid::Uuid,
 };
 
-use super::Indexer;
+use super::{Indexer, PAYLOAD_HISTORY_SIZE};
 
 /// Webhook payload
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct PushPayload {
     pub network_data: NetworkData,
     pub event: PushEvent,
@@ -32,6 +33,16 @@ pub struct NetworkData {
     pub chain_id: Option<String>,
 }
 
+impl NetworkData {
+    /// Unique key of the indexed network, `network` or `network:chain_id`
+    pub fn key(&self) -> String {
+        match &self.chain_id {
+            Some(chain_id) => format!("{}:{}", self.network, chain_id),
+            None => self.network.to_string(),
+        }
+    }
+}
+
 /// Event data
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct PushEvent {
@@ -43,6 +54,10 @@ pub struct PushEvent {
     pub tx_index: u64,
     /// Timestamp of the transaction block
     pub timestamp: u64,
+    /// Number of the transaction block (slot for Solana)
+    pub block: u64,
+    /// Position of the event in the block, events of one block are ordered by it
+    pub block_index: u64,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -51,6 +66,15 @@ pub enum PushData {
     Asset(Asset),
     Case(Case),
     Reporter(Reporter),
+    Configuration(ContractConfiguration),
+    Authority(String),
+}
+
+/// Contract configuration
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum ContractConfiguration {
+    Stake(StakeConfiguration),
+    Reward(RewardConfiguration),
 }
 
 impl From<Address> for PushData {
@@ -77,6 +101,18 @@ impl From<Reporter> for PushData {
     }
 }
 
+impl From<StakeConfiguration> for PushData {
+    fn from(configuration: StakeConfiguration) -> Self {
+        Self::Configuration(ContractConfiguration::Stake(configuration))
+    }
+}
+
+impl From<RewardConfiguration> for PushData {
+    fn from(configuration: RewardConfiguration) -> Self {
+        Self::Configuration(ContractConfiguration::Reward(configuration))
+    }
+}
+
 impl Indexer {
     pub(crate) async fn send_webhook(&self, payload: &PushPayload) -> Result<()> {
         let url = format!("{}/events", self.webhook_url,);
@@ -93,6 +129,12 @@ impl Indexer {
             bail!("Webhook request failed: {:?}", response.text().await?);
         }
 
+        let mut payloads = self.payloads.lock().await;
+        if payloads.len() == PAYLOAD_HISTORY_SIZE {
+            payloads.pop_front();
+        }
+        payloads.push_back(payload.clone());
+
         Ok(())
     }
 }
@@ -118,6 +160,8 @@ mod tests {
                     .to_string(),
                 tx_index: 0,
                 timestamp: 1690888679,
+                block: 18000000,
+                block_index: 12,
             },
             data: PushData::Address(Address {
                 address: "0x922ffdfcb57de5dd6f641f275e98b684ce5576a3".to_string(),
@@ -134,7 +178,7 @@ mod tests {
 
         assert_eq!(
             json,
-            r#"{"network_data":{"indexer_id":"f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e","network":"Ethereum","chain_id":null},"event":{"name":"create_address","tx_hash":"acf0734ab380f3964e1f23b1fd4f5a5125250208ec17ff11c9999451c138949f","tx_index":0,"timestamp":1690888679},"data":{"Address":{"address":"0x922ffdfcb57de5dd6f641f275e98b684ce5576a3","case_id":"de1659f2-b802-49ee-98dd-6e4ce0453067","reporter_id":"1466cf4f-1d71-4153-b9ad-4a9c1b48101e","risk":0,"category":"None","confirmations":3}}}"#
+            r#"{"network_data":{"indexer_id":"f6b9e9a0-9b7a-4e1a-8b0a-9e2a5e8e4b5e","network":"Ethereum","chain_id":null},"event":{"name":"create_address","tx_hash":"acf0734ab380f3964e1f23b1fd4f5a5125250208ec17ff11c9999451c138949f","tx_index":0,"timestamp":1690888679,"block":18000000,"block_index":12},"data":{"Address":{"address":"0x922ffdfcb57de5dd6f641f275e98b684ce5576a3","case_id":"de1659f2-b802-49ee-98dd-6e4ce0453067","reporter_id":"1466cf4f-1d71-4153-b9ad-4a9c1b48101e","risk":0,"category":"None","confirmations":3}}}"#
         );
 
         // Deserialize the JSON back into a PushPayload
@@ -1,12 +1,13 @@
 use {
     anyhow::Result,
-    axum::{
-        extract::State,
-        routing::{get, put},
-        Json, Router, Server,
-    },
+    axum::{extract::State, routing::get, Json, Router, Server},
     serde::Serialize,
-    std::{future::Future, sync::Arc, time::Duration},
+    std::{
+        collections::{BTreeMap, VecDeque},
+        future::Future,
+        sync::Arc,
+        time::Duration,
+    },
     tokio::{
         sync::Mutex,
         task::{spawn, JoinHandle},
@@ -14,61 +15,95 @@ use {
     },
 };
 
-use super::{state::IndexerState, Indexer};
+use super::{admin::create_admin_router, state::IndexerState, Indexer, PushPayload};
+
+/// Shared data of a running indexer
+#[derive(Clone)]
+pub(crate) struct IndexerHandle {
+    pub state: Arc<Mutex<IndexerState>>,
+    pub payloads: Arc<Mutex<VecDeque<PushPayload>>>,
+}
+
+/// Shared data of all running indexers, keyed by network and chain id
+pub(crate) type IndexerHandles = Arc<BTreeMap<String, IndexerHandle>>;
 
-impl Indexer {
-    async fn shutdown_signal(&self) -> impl Future<Output = ()> {
-        let shared_state = self.state.clone();
-        async move {
-            loop {
-                sleep(Duration::from_secs(1)).await;
-                if matches!(*shared_state.lock().await, IndexerState::Stopped { .. }) {
+fn shutdown_signal(indexers: IndexerHandles) -> impl Future<Output = ()> {
+    async move {
+        loop {
+            sleep(Duration::from_secs(1)).await;
+
+            let mut all_stopped = true;
+            for indexer in indexers.values() {
+                if !matches!(*indexer.state.lock().await, IndexerState::Stopped { .. }) {
+                    all_stopped = false;
                     break;
                 }
             }
-        }
-    }
 
-    fn create_router(&self) -> Router {
-        Router::new()
-            .route("/state", get(get_state))
-            .route("/stop", put(stop))
-            .with_state(self.state.clone())
+            if all_stopped {
+                break;
+            }
+        }
     }
+}
 
-    pub async fn spawn_server(&self, addr: &str) -> Result<JoinHandle<Result<()>>> {
-        tracing::debug!(?addr, "Start server");
-
-        let server = Server::bind(&addr.parse()?)
-            .serve(self.create_router().into_make_service())
-            .with_graceful_shutdown(self.shutdown_signal().await);
+fn create_router(indexers: IndexerHandles, admin_token: Option<String>) -> Router {
+    let router = Router::new()
+        .route("/state", get(get_state))
+        .with_state(indexers.clone());
 
-        Ok(spawn(
-            async move { server.await.map_err(anyhow::Error::from) },
-        ))
+    match admin_token {
+        Some(token) => router.nest("/admin", create_admin_router(indexers, token)),
+        None => {
+            tracing::warn!("Admin token is not configured, admin API is disabled");
+            router
+        }
     }
 }
 
-#[derive(Serialize)]
-struct GetStateOutput {
-    state: IndexerState,
-}
+/// Spawns a server exposing the state of the given indexers and the admin API
+pub async fn spawn_server(
+    addr: &str,
+    indexers: &[Indexer],
+    admin_token: Option<String>,
+) -> Result<JoinHandle<Result<()>>> {
+    tracing::debug!(?addr, "Start server");
+
+    let handles: IndexerHandles = Arc::new(
+        indexers
+            .iter()
+            .map(|indexer| {
+                (
+                    indexer.key.clone(),
+                    IndexerHandle {
+                        state: indexer.state.clone(),
+                        payloads: indexer.payloads.clone(),
+                    },
+                )
+            })
+            .collect(),
+    );
 
-async fn get_state(State(shared_state): State<Arc<Mutex<IndexerState>>>) -> Json<GetStateOutput> {
-    let state = shared_state.lock().await.clone();
+    let server = Server::bind(&addr.parse()?)
+        .serve(create_router(handles.clone(), admin_token).into_make_service())
+        .with_graceful_shutdown(shutdown_signal(handles));
 
-    Json(GetStateOutput { state })
+    Ok(spawn(
+        async move { server.await.map_err(anyhow::Error::from) },
+    ))
 }
 
 #[derive(Serialize)]
-struct StopOutput {
-    success: bool,
+struct GetStateOutput {
+    indexers: BTreeMap<String, IndexerState>,
 }
 
-async fn stop(State(shared_state): State<Arc<Mutex<IndexerState>>>) -> Json<StopOutput> {
-    shared_state.lock().await.transition(IndexerState::Stopped {
-        message: "Stopped by user".to_string(),
-    });
+async fn get_state(State(indexers): State<IndexerHandles>) -> Json<GetStateOutput> {
+    let mut states = BTreeMap::new();
+
+    for (key, indexer) in indexers.iter() {
+        states.insert(key.clone(), indexer.state.lock().await.clone());
+    }
 
-    Json(StopOutput { success: true })
+    Json(GetStateOutput { indexers: states })
 }
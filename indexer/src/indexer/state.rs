@@ -18,7 +18,7 @@ impl TryFrom<IndexerJob> for IndexingCursor {
 
     fn try_from(value: IndexerJob) -> Result<Self> {
         match value {
-            IndexerJob::Transaction(tx) => Ok(IndexingCursor::Transaction(tx)),
+            IndexerJob::Transaction(tx) => Ok(IndexingCursor::Transaction(tx.signature)),
             IndexerJob::Log(log) => Ok(IndexingCursor::Block(
                 log.block_number
                     .ok_or(anyhow!("Unable to parse block number"))?
@@ -51,6 +51,8 @@ pub(crate) enum IndexerState {
     Processing { cursor: IndexingCursor },
     /// App is waiting: waiting for new blocks and transactions until timestamp
     Waiting { cursor: IndexingCursor, until: u64 },
+    /// App is paused by admin: no indexing until resumed
+    Paused { cursor: IndexingCursor },
     /// App is stopped: no more indexing, with exit message
     Stopped { message: String },
 }
@@ -59,15 +61,22 @@ impl IndexerState {
     pub fn transition(&mut self, new_state: Self) -> bool {
         match (&self, &new_state) {
             // Already stopped, don't proceed
+            (IndexerState::Stopped { .. }, _) => false,
+
+            // Stop the indexer and keep the exit message
             (_, IndexerState::Stopped { message }) => {
                 tracing::info!(message, "Indexer stopped");
 
+                *self = new_state;
                 false
             }
 
             // If the new state is waiting, and the current state is also waiting, just move on
             (IndexerState::Waiting { .. }, IndexerState::Waiting { .. }) => true,
 
+            // If the new state is paused, and the current state is also paused, just move on
+            (IndexerState::Paused { .. }, IndexerState::Paused { .. }) => true,
+
             // If the new state is processing, and the current state is also processing, just move on
             (IndexerState::Processing { .. }, IndexerState::Processing { .. }) => {
                 *self = new_state;
@@ -87,4 +96,58 @@ impl IndexerState {
             }
         }
     }
+
+    /// Returns the indexing cursor of the state, if the state has one
+    pub fn cursor(&self) -> Option<&IndexingCursor> {
+        match self {
+            IndexerState::CheckForUpdates { cursor }
+            | IndexerState::Processing { cursor }
+            | IndexerState::Waiting { cursor, .. }
+            | IndexerState::Paused { cursor } => Some(cursor),
+            IndexerState::Init | IndexerState::Stopped { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stop_transition() {
+        let mut state = IndexerState::Waiting {
+            cursor: IndexingCursor::Block(10),
+            until: 0,
+        };
+
+        let stopped = IndexerState::Stopped {
+            message: "Stopped by admin".to_string(),
+        };
+
+        assert!(!state.transition(stopped.clone()));
+        assert_eq!(state, stopped);
+
+        // Stopped indexer can not be restarted
+        assert!(!state.transition(IndexerState::CheckForUpdates {
+            cursor: IndexingCursor::Block(10),
+        }));
+        assert_eq!(state, stopped);
+    }
+
+    #[test]
+    fn test_pause_transition() {
+        let mut state = IndexerState::Processing {
+            cursor: IndexingCursor::Block(10),
+        };
+
+        assert!(state.transition(IndexerState::Paused {
+            cursor: IndexingCursor::Block(10),
+        }));
+        assert_eq!(state.cursor(), Some(&IndexingCursor::Block(10)));
+
+        assert!(state.transition(IndexerState::Paused {
+            cursor: IndexingCursor::Block(10),
+        }));
+        assert!(matches!(state, IndexerState::Paused { .. }));
+    }
 }
@@ -5,7 +5,8 @@ pub mod observability;
 pub use indexer::{
     jwt::get_id_from_jwt,
     persistence::PersistedState,
-    push::{NetworkData, PushData, PushEvent, PushPayload},
+    push::{ContractConfiguration, NetworkData, PushData, PushEvent, PushPayload},
+    server::spawn_server,
     state::IndexingCursor,
     Indexer,
 };
@@ -2,14 +2,14 @@ use {
     anyhow::{bail, Result},
     tokio::{
         select,
-        task::{spawn, JoinError},
+        task::{JoinError, JoinSet},
     },
 };
 
 use hapi_indexer::{
     configuration::get_configuration,
     observability::{setup_json_tracing, setup_tracing},
-    Indexer,
+    spawn_server, Indexer,
 };
 
 #[tokio::main]
@@ -29,17 +29,42 @@ async fn main() -> Result<()> {
         env!("CARGO_PKG_VERSION")
     );
 
-    let mut indexer = Indexer::new(cfg.indexer)?;
+    let indexers = cfg
+        .indexer
+        .into_iter()
+        .map(Indexer::new)
+        .collect::<Result<Vec<_>>>()?;
 
-    let server_task = indexer.spawn_server(&cfg.listener).await?;
-    let indexer_task = spawn(async move { indexer.run().await });
+    let server_task = spawn_server(&cfg.listener, &indexers, cfg.admin_token).await?;
+
+    let mut indexer_tasks = JoinSet::new();
+    for mut indexer in indexers {
+        indexer_tasks.spawn(async move { indexer.run().await });
+    }
+
+    let indexers_task = async move {
+        let mut failed = 0;
+
+        // A failed indexer doesn't stop the others
+        while let Some(indexer_result) = indexer_tasks.join_next().await {
+            if handle_result(indexer_result).await.is_err() {
+                failed += 1;
+            }
+        }
+
+        if failed > 0 {
+            bail!("{failed} indexer(s) failed");
+        }
+
+        Ok(())
+    };
 
     select! {
         server_result = server_task => {
             handle_result(server_result).await
         }
-        indexer_result = indexer_task => {
-            handle_result(indexer_result).await
+        indexers_result = indexers_task => {
+            indexers_result
         }
     }
 }
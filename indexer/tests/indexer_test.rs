@@ -15,8 +15,9 @@ mod simple_listener;
 
 use jwt::{get_jwt, get_jwt_id};
 use mocks::{
-    create_pushdata, create_test_batches, evm_mock::EvmMock, near_mock::NearMock,
-    solana_mock::SolanaMock, webhook_mock::WebhookServiceMock, RpcMock, TestBatch, PAGE_SIZE,
+    create_batch_test_data, create_pushdata, create_test_batches, evm_mock::EvmMock,
+    near_mock::NearMock, solana_mock::SolanaMock, webhook_mock::WebhookServiceMock, RpcMock,
+    TestBatch, PAGE_SIZE,
 };
 
 const TRACING_ENV_VAR: &str = "ENABLE_TRACING";
@@ -64,7 +65,7 @@ impl<T: RpcMock> IndexerTest<T> {
 
         for (index, batch) in batches.iter().enumerate() {
             self.rpc_mock.processing_jobs_mock(batch);
-            self.webhook_mock.set_mocks(batch);
+            self.webhook_mock.set_mocks::<T>(batch);
 
             println!("==> Created mocks in {} batch for:", index + 1);
             batch
@@ -77,7 +78,8 @@ impl<T: RpcMock> IndexerTest<T> {
         let cfg = IndexerConfiguration {
             network: T::get_network(),
             chain_id: None,
-            rpc_node_url: self.rpc_mock.get_mock_url(),
+            rpc_node_url: vec![self.rpc_mock.get_mock_url()],
+            rpc_rate_limit: None,
             webhook_url: self.webhook_mock.server.url(),
             contract_address: T::get_contract_address(),
             wait_interval_ms: FETCHING_DELAY,
@@ -134,6 +136,28 @@ impl<T: RpcMock> IndexerTest<T> {
         }
     }
 
+    pub async fn batch_test(&mut self) {
+        println!("\nBatch test");
+
+        let start_block = match &self.cursor {
+            IndexingCursor::Block(block) => block + 1,
+            _ => 0,
+        };
+
+        let pushdata = create_pushdata::<T>();
+        let batches = create_batch_test_data::<T>(&pushdata, start_block);
+
+        self.create_mocks(&batches, Some(pushdata));
+
+        self.indexing_iteration().await.unwrap();
+
+        // Each operation of the batch is sent with its own index in the transaction
+        self.webhook_mock.check_mocks();
+        self.check_cursor(&batches);
+
+        println!("==> Success: all batch operations were processed\n");
+    }
+
     pub async fn empty_contract_test(&mut self) {
         println!("\nEmpty contract test");
 
@@ -179,10 +203,16 @@ async fn solana_indexer_test() {
 
 #[tokio::test(flavor = "multi_thread")]
 async fn evm_indexer_test() {
-    IndexerTest::<EvmMock>::new().run_test().await;
+    let mut test = IndexerTest::<EvmMock>::new();
+
+    test.run_test().await;
+    test.batch_test().await;
 }
 
 #[tokio::test(flavor = "multi_thread")]
 async fn near_indexer_test() {
-    IndexerTest::<NearMock>::new().run_test().await;
+    let mut test = IndexerTest::<NearMock>::new();
+
+    test.run_test().await;
+    test.batch_test().await;
 }
@@ -9,14 +9,14 @@ use {
         utils::keccak256,
     },
     hapi_core::{client::events::EventName, HapiCoreNetwork},
-    hapi_indexer::{IndexingCursor, PushData},
+    hapi_indexer::{ContractConfiguration, IndexingCursor, PushData},
     mockito::{Matcher, Server, ServerGuard},
     rand::RngCore,
     serde_json::json,
     std::{str::FromStr, sync::Arc},
 };
 
-use super::{RpcMock, TestBatch};
+use super::{RpcMock, TestBatch, TestData};
 
 pub const CONTRACT_ADDRESS: &str = "0x2947F98C42597966a0ec25e92843c09ac18Fbab7";
 
@@ -59,6 +59,10 @@ impl RpcMock for EvmMock {
         4
     }
 
+    fn get_block_index(event: &TestData) -> u64 {
+        event.index
+    }
+
     fn initialize() -> Self {
         let server = Server::new();
 
@@ -101,7 +105,7 @@ impl RpcMock for EvmMock {
         };
 
         for batch in batches {
-            let to_block = from_block + batch.len() as u64 - 1;
+            let to_block = batch.last().expect("Empty batch").block;
 
             let logs = self.get_logs(batch);
             self.logs_request_mock(&logs, from_block, to_block);
@@ -144,12 +148,16 @@ impl EvmMock {
             .expect("Failed to parse address");
 
         for event in batch {
+            // Authority is changed by granting the authority role
             let signature = self
                 .contract
                 .abi()
                 .events()
-                .find(|e| {
-                    EventName::from_str(&e.name).unwrap_or(EventName::Initialize) == event.name
+                .find(|e| match event.name {
+                    EventName::SetAuthority => e.name == "RoleGranted",
+                    _ => {
+                        EventName::from_str(&e.name).unwrap_or(EventName::Initialize) == event.name
+                    }
                 })
                 .map(|e| e.signature())
                 .expect("Failed to get event signature");
@@ -164,7 +172,7 @@ impl EvmMock {
                     H256::from_str(&event.hash).expect("Failed to parse transaction hash"),
                 ),
                 transaction_index: None,
-                log_index: None,
+                log_index: Some(event.index.into()),
                 transaction_log_index: None,
                 log_type: None,
                 removed: None,
@@ -177,47 +185,57 @@ impl EvmMock {
                     log.data = ethers::abi::encode(&[version_token]).into();
                 }
                 EventName::SetAuthority => {
-                    let address = Self::generate_address()
-                        .parse::<Address>()
-                        .expect("Invalid address");
+                    let_extract!(
+                        PushData::Authority(authority),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let role_topic = H256::from(keccak256("AUTHORITY_ROLE"));
+                    let account: Address = authority.parse().expect("Invalid address");
+                    let sender: Address =
+                        Self::generate_address().parse().expect("Invalid address");
 
-                    log.data = ethers::abi::encode(&[Token::Address(address)]).into();
+                    log.topics.append(&mut vec![
+                        role_topic,
+                        H256::from(account),
+                        H256::from(sender),
+                    ]);
                 }
                 EventName::UpdateStakeConfiguration => {
-                    let token = Self::generate_address()
-                        .parse::<Address>()
-                        .expect("Invalid address");
-                    let unlock_duration: U256 = 100.into();
-                    let validator_stake: U256 = 100.into();
-                    let tracer_stake: U256 = 100.into();
-                    let publisher_stake: U256 = 100.into();
-                    let authority_stake: U256 = 100.into();
+                    let_extract!(
+                        PushData::Configuration(ContractConfiguration::Stake(data)),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let token: Address = data.token.parse().expect("Invalid address");
 
                     log.data = ethers::abi::encode(&[
                         Token::Address(token),
-                        Token::Uint(unlock_duration),
-                        Token::Uint(validator_stake),
-                        Token::Uint(tracer_stake),
-                        Token::Uint(publisher_stake),
-                        Token::Uint(authority_stake),
+                        Token::Uint(U256::from(data.unlock_duration)),
+                        Token::Uint(data.validator_stake.clone().into()),
+                        Token::Uint(data.tracer_stake.clone().into()),
+                        Token::Uint(data.publisher_stake.clone().into()),
+                        Token::Uint(data.authority_stake.clone().into()),
                     ])
                     .into();
                 }
                 EventName::UpdateRewardConfiguration => {
-                    let token = Self::generate_address()
-                        .parse::<Address>()
-                        .expect("Invalid address");
-                    let address_confirmation_reward: U256 = 100.into();
-                    let address_tracer_reward: U256 = 100.into();
-                    let asset_confirmation_reward: U256 = 100.into();
-                    let asset_tracer_reward: U256 = 100.into();
+                    let_extract!(
+                        PushData::Configuration(ContractConfiguration::Reward(data)),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let token: Address = data.token.parse().expect("Invalid address");
 
                     log.data = ethers::abi::encode(&[
                         Token::Address(token),
-                        Token::Uint(address_confirmation_reward),
-                        Token::Uint(address_tracer_reward),
-                        Token::Uint(asset_confirmation_reward),
-                        Token::Uint(asset_tracer_reward),
+                        Token::Uint(data.address_confirmation_reward.clone().into()),
+                        Token::Uint(data.address_tracer_reward.clone().into()),
+                        Token::Uint(data.asset_confirmation_reward.clone().into()),
+                        Token::Uint(data.asset_tracer_reward.clone().into()),
                     ])
                     .into();
                 }
@@ -254,7 +272,7 @@ impl EvmMock {
 
                     log.topics.append(&mut vec![id_topic]);
                 }
-                EventName::CreateAddress | EventName::UpdateAddress | EventName::ConfirmAddress => {
+                EventName::CreateAddress | EventName::UpdateAddress => {
                     let_extract!(
                         PushData::Address(data),
                         event.data.as_ref().expect("Empty data"),
@@ -273,7 +291,18 @@ impl EvmMock {
                     ])
                     .into();
                 }
-                EventName::CreateAsset | EventName::UpdateAsset | EventName::ConfirmAsset => {
+                EventName::ConfirmAddress => {
+                    let_extract!(
+                        PushData::Address(data),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let addr: Address = data.address.parse().expect("Invalid address");
+
+                    log.topics.append(&mut vec![H256::from(addr)]);
+                }
+                EventName::CreateAsset | EventName::UpdateAsset => {
                     let_extract!(
                         PushData::Asset(data),
                         event.data.as_ref().expect("Empty data"),
@@ -294,6 +323,19 @@ impl EvmMock {
                     ])
                     .into();
                 }
+                EventName::ConfirmAsset => {
+                    let_extract!(
+                        PushData::Asset(data),
+                        event.data.as_ref().expect("Empty data"),
+                        panic!("Wrong message encoding")
+                    );
+
+                    let addr: Address = data.address.parse().expect("Invalid address");
+                    let asset_id: U256 = data.asset_id.clone().into();
+
+                    log.topics.append(&mut vec![H256::from(addr)]);
+                    log.data = ethers::abi::encode(&[Token::Uint(asset_id)]).into();
+                }
             }
 
             res.push(log);
@@ -453,6 +495,8 @@ impl EvmMock {
 
                 (raw_tx, format!("0x{}", responce))
             }
+            // Configuration and authority are taken from the event itself
+            PushData::Authority(_) | PushData::Configuration(_) => return,
         };
 
         let tx = serde_json::to_value(raw_tx).expect("Failed to serialize raw transaction");
@@ -470,7 +514,7 @@ impl EvmMock {
             .with_body(&response.to_string())
             .match_body(Matcher::PartialJson(json!({
                 "method": "eth_call",
-                "params": [ tx, "latest" ]
+                "params": [ tx ]
             })))
             .create();
     }
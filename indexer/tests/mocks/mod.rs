@@ -3,6 +3,7 @@ use crate::jwt::get_jwt_id;
 use {
     hapi_core::{
         client::{
+            configuration::{RewardConfiguration, StakeConfiguration},
             entities::{
                 address::Address,
                 asset::{Asset, AssetId},
@@ -61,6 +62,9 @@ pub trait RpcMock {
 
     // Multiplier for the delay between fetching iterations
     fn get_delay_multiplier() -> u32;
+
+    // Returns the position of the event in its block
+    fn get_block_index(event: &TestData) -> u64;
 }
 
 pub type TestBatch = Vec<TestData>;
@@ -69,6 +73,8 @@ pub type TestBatch = Vec<TestData>;
 pub struct TestData {
     pub network_data: NetworkData,
     pub hash: String,
+    // Index of the event in the transaction
+    pub index: u64,
     pub name: EventName,
     pub data: Option<PushData>,
     pub block: u64,
@@ -113,11 +119,31 @@ pub fn create_pushdata<T: RpcMock>() -> Vec<PushData> {
         confirmations: 3,
     };
 
+    let stake_configuration = StakeConfiguration {
+        token: T::generate_address(),
+        unlock_duration: 100,
+        validator_stake: 100.into(),
+        tracer_stake: 100.into(),
+        publisher_stake: 100.into(),
+        authority_stake: 100.into(),
+    };
+
+    let reward_configuration = RewardConfiguration {
+        token: T::generate_address(),
+        address_confirmation_reward: 100.into(),
+        address_tracer_reward: 100.into(),
+        asset_confirmation_reward: 100.into(),
+        asset_tracer_reward: 100.into(),
+    };
+
     vec![
         PushData::Reporter(reporter.clone()),
         PushData::Case(case.clone()),
         PushData::Address(address.clone()),
         PushData::Asset(asset.clone()),
+        PushData::Authority(T::generate_address()),
+        PushData::from(stake_configuration),
+        PushData::from(reward_configuration),
     ]
 }
 
@@ -130,18 +156,27 @@ pub fn create_test_batches<T: RpcMock>(pushdata: &Vec<PushData>) -> Vec<TestBatc
     let case = pushdata[1].clone();
     let address = pushdata[2].clone();
     let asset = pushdata[3].clone();
+    let authority = pushdata[4].clone();
+    let stake_configuration = pushdata[5].clone();
+    let reward_configuration = pushdata[6].clone();
 
     let data = [
         // ==> First Run
         // First batch
         (EventName::Initialize, None),
-        (EventName::SetAuthority, None),
+        (EventName::SetAuthority, Some(authority)),
         (EventName::CreateReporter, Some(reporter.clone())),
-        (EventName::UpdateStakeConfiguration, None),
+        (
+            EventName::UpdateStakeConfiguration,
+            Some(stake_configuration),
+        ),
         (EventName::ActivateReporter, Some(reporter.clone())),
         (EventName::UpdateReporter, Some(reporter.clone())),
         // Second batch
-        (EventName::UpdateRewardConfiguration, None),
+        (
+            EventName::UpdateRewardConfiguration,
+            Some(reward_configuration),
+        ),
         (EventName::CreateCase, Some(case.clone())),
         (EventName::UpdateCase, Some(case)),
         (EventName::CreateAddress, Some(address.clone())),
@@ -169,6 +204,7 @@ pub fn create_test_batches<T: RpcMock>(pushdata: &Vec<PushData>) -> Vec<TestBatc
                 chain_id: None,
             },
             hash: hash.clone(),
+            index: 0,
             name: name.clone(),
             data: data.clone(),
             block: index as u64,
@@ -181,3 +217,49 @@ pub fn create_test_batches<T: RpcMock>(pushdata: &Vec<PushData>) -> Vec<TestBatc
 
     vec![first_batch, second_batch, third_batch]
 }
+
+// Create a batch where a case and its address are created in one transaction,
+// the transaction is preceded by a block without HAPI entities
+pub fn create_batch_test_data<T: RpcMock>(
+    pushdata: &[PushData],
+    start_block: u64,
+) -> Vec<TestBatch> {
+    let hashes = T::get_hashes();
+    let indexer_id = Uuid::parse_str(&get_jwt_id()).expect("Failed to parse indexer id");
+
+    let events = [
+        (&hashes[0], 0, EventName::Initialize, None, start_block),
+        (
+            &hashes[1],
+            0,
+            EventName::CreateCase,
+            Some(pushdata[1].clone()),
+            start_block + 1,
+        ),
+        (
+            &hashes[1],
+            1,
+            EventName::CreateAddress,
+            Some(pushdata[2].clone()),
+            start_block + 1,
+        ),
+    ];
+
+    let batch = events
+        .into_iter()
+        .map(|(hash, index, name, data, block)| TestData {
+            network_data: NetworkData {
+                indexer_id,
+                network: T::get_network(),
+                chain_id: None,
+            },
+            hash: hash.clone(),
+            index,
+            name,
+            data,
+            block,
+        })
+        .collect();
+
+    vec![batch]
+}
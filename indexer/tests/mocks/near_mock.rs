@@ -27,7 +27,7 @@ use {
         AddressView as NearAddress, AssetView as NearAsset, Case as NearCase,
         Reporter as NearReporter,
     },
-    hapi_indexer::{IndexingCursor, PushData},
+    hapi_indexer::{ContractConfiguration, IndexingCursor, PushData},
 };
 
 use super::{RpcMock, TestBatch, TestData};
@@ -61,7 +61,7 @@ impl RpcMock for NearMock {
 
     fn get_hashes() -> [String; 17] {
         (0..17)
-            .map(|i| CryptoHash::hash_bytes(i.to_string().as_bytes()).to_string())
+            .map(|_| CryptoHash::hash_bytes(&rand::random::<[u8; 32]>()).to_string())
             .collect::<Vec<String>>()
             .try_into()
             .expect("Failed to convert")
@@ -75,6 +75,11 @@ impl RpcMock for NearMock {
         17
     }
 
+    fn get_block_index(event: &TestData) -> u64 {
+        // Each receipt is the only one in its block
+        event.index
+    }
+
     fn initialize() -> Self {
         let server = Server::new();
 
@@ -171,8 +176,21 @@ impl RpcMock for NearMock {
     }
 
     fn processing_jobs_mock(&mut self, batch: &TestBatch) {
+        // Events that share the hash are actions of one receipt
+        let mut receipts: Vec<Vec<&TestData>> = vec![];
+
         for data in batch {
-            self.mock_transaction(data);
+            match receipts
+                .iter_mut()
+                .find(|receipt| receipt[0].hash == data.hash)
+            {
+                Some(receipt) => receipt.push(data),
+                None => receipts.push(vec![data]),
+            }
+        }
+
+        for receipt in receipts {
+            self.mock_transaction(&receipt);
         }
     }
 }
@@ -197,8 +215,9 @@ impl NearMock {
             .create();
     }
 
-    fn mock_transaction(&mut self, data: &TestData) {
-        let result = make_receipt_response(data);
+    fn mock_transaction(&mut self, receipt: &[&TestData]) {
+        let hash = receipt[0].hash.as_str();
+        let result = make_receipt_response(hash, receipt);
 
         let response = json!({
             "jsonrpc": "2.0",
@@ -208,7 +227,7 @@ impl NearMock {
 
         let payload = RpcReceiptRequest {
             receipt_reference: ReceiptReference {
-                receipt_id: CryptoHash::from_str(data.hash.as_str()).unwrap(),
+                receipt_id: CryptoHash::from_str(hash).unwrap(),
             },
         };
 
@@ -237,6 +256,9 @@ impl NearMock {
             PushData::Asset(asset) => {
                 args_from_json(json!({ "address": asset.address, "id": asset.asset_id.to_string()}))
             }
+            PushData::Authority(_) | PushData::Configuration(_) => {
+                panic!("Configuration is not requested from the contract")
+            }
         };
 
         let encoded_entity: Vec<u8> = match data {
@@ -256,6 +278,7 @@ impl NearMock {
                 let asset: NearAsset = a.clone().try_into().expect("Failed to convert");
                 serde_json::to_string(&asset).unwrap().into_bytes()
             }
+            PushData::Authority(_) | PushData::Configuration(_) => unreachable!(),
         };
 
         let result = methods::query::RpcQueryResponse {
@@ -282,12 +305,19 @@ impl NearMock {
             },
         };
 
+        // Entities are requested at the event block, so the block reference is not matched
+        let mut request = get_value_from_method(payload);
+        request["params"]
+            .as_object_mut()
+            .expect("Invalid request params")
+            .remove("finality");
+
         self.server
             .mock("POST", "/")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(&response.to_string())
-            .match_body(Matcher::PartialJson(get_value_from_method(payload)))
+            .match_body(Matcher::PartialJson(request))
             .create();
     }
 }
@@ -299,7 +329,26 @@ fn get_value_from_method<M: RpcMethod>(method: M) -> Value {
     request
 }
 
-fn make_receipt_response(data: &TestData) -> RpcReceiptResponse {
+fn make_receipt_response(hash: &str, receipt: &[&TestData]) -> RpcReceiptResponse {
+    RpcReceiptResponse {
+        receipt_view: near_primitives::views::ReceiptView {
+            predecessor_id: reporter_id(),
+            receiver_id: contract_id(),
+            receipt_id: CryptoHash::from_str(hash).unwrap(),
+
+            receipt: near_primitives::views::ReceiptEnumView::Action {
+                signer_id: reporter_id(),
+                signer_public_key: near_crypto::PublicKey::from_str(PUBLIC_KEY).unwrap(),
+                gas_price: 0,
+                output_data_receivers: vec![],
+                input_data_ids: vec![],
+                actions: receipt.iter().copied().map(make_action).collect(),
+            },
+        },
+    }
+}
+
+fn make_action(data: &TestData) -> near_primitives::views::ActionView {
     let mut method = data.name.to_string();
 
     let mut args = json!({"some": "data"});
@@ -320,31 +369,21 @@ fn make_receipt_response(data: &TestData) -> RpcReceiptResponse {
             PushData::Address(address) => get_address_json(address),
             PushData::Case(case) => get_case_json(case),
             PushData::Asset(asset) => get_asset_json(asset),
+            PushData::Authority(authority) => json!({ "authority": authority }),
+            PushData::Configuration(ContractConfiguration::Stake(configuration)) => {
+                json!({ "stake_configuration": configuration })
+            }
+            PushData::Configuration(ContractConfiguration::Reward(configuration)) => {
+                json!({ "reward_configuration": configuration })
+            }
         };
     };
 
-    let encoded_args = FunctionArgs::from(args.to_string().into_bytes());
-
-    RpcReceiptResponse {
-        receipt_view: near_primitives::views::ReceiptView {
-            predecessor_id: reporter_id(),
-            receiver_id: contract_id(),
-            receipt_id: CryptoHash::from_str(data.hash.as_str()).unwrap(),
-
-            receipt: near_primitives::views::ReceiptEnumView::Action {
-                signer_id: reporter_id(),
-                signer_public_key: near_crypto::PublicKey::from_str(PUBLIC_KEY).unwrap(),
-                gas_price: 0,
-                output_data_receivers: vec![],
-                input_data_ids: vec![],
-                actions: vec![near_primitives::views::ActionView::FunctionCall {
-                    method_name: method,
-                    args: encoded_args,
-                    gas: Gas::default(),
-                    deposit: Balance::default(),
-                }],
-            },
-        },
+    near_primitives::views::ActionView::FunctionCall {
+        method_name: method,
+        args: FunctionArgs::from(args.to_string().into_bytes()),
+        gas: Gas::default(),
+        deposit: Balance::default(),
     }
 }
 
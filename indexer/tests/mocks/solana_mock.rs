@@ -1,10 +1,22 @@
 use {
     anchor_lang::AccountSerialize,
     hapi_core::{
-        client::solana::{byte_array_from_str, test_helpers::create_test_tx, InstructionData},
+        client::{
+            events::EventName,
+            solana::{
+                byte_array_from_str,
+                instruction_data::{
+                    CreateAddressData, CreateAssetData, CreateCaseData, CreateNetworkData,
+                    CreateReporterData, UpdateAddressData, UpdateAssetData, UpdateCaseData,
+                    UpdateReporterData,
+                },
+                test_helpers::create_test_tx,
+                DecodedInstructionData, InstructionData,
+            },
+        },
         HapiCoreNetwork,
     },
-    hapi_indexer::{IndexingCursor, PushData},
+    hapi_indexer::{ContractConfiguration, IndexingCursor, PushData},
     mockito::{Matcher, Server, ServerGuard},
     serde_json::{json, Value},
     solana_account_decoder::{UiAccount, UiAccountEncoding},
@@ -65,6 +77,11 @@ impl RpcMock for SolanaMock {
         6
     }
 
+    fn get_block_index(event: &TestData) -> u64 {
+        // Each transaction is the only one in its slot
+        u32::MAX as u64 * 256 + event.index
+    }
+
     fn initialize() -> Self {
         let mut server = Server::new();
 
@@ -120,7 +137,7 @@ impl RpcMock for SolanaMock {
                 .map(|data| {
                     json!({
                         "signature": data.hash,
-                        "slot": 100,
+                        "slot": data.block,
                     })
                 })
                 .collect();
@@ -147,18 +164,30 @@ impl RpcMock for SolanaMock {
 
     fn processing_jobs_mock(&mut self, batch: &TestBatch) {
         // Mocking transaction request with instruction
-        batch
-            .iter()
-            .for_each(|event| self.mock_transaction(event.name.to_string(), &event.hash));
+        batch.iter().for_each(|event| self.mock_transaction(event));
     }
 }
 
 impl SolanaMock {
-    fn get_transaction(name: String, hash: &str) -> EncodedConfirmedTransactionWithStatusMeta {
+    fn get_transaction(event: &TestData) -> EncodedConfirmedTransactionWithStatusMeta {
+        let name = event.name.to_string();
+
+        // New authority and configuration mint share the account index with reporter
+        let third_account = match &event.data {
+            Some(PushData::Authority(authority)) => authority.clone(),
+            Some(PushData::Configuration(ContractConfiguration::Stake(configuration))) => {
+                configuration.token.clone()
+            }
+            Some(PushData::Configuration(ContractConfiguration::Reward(configuration))) => {
+                configuration.token.clone()
+            }
+            _ => String::from(REPORTER),
+        };
+
         let mut account_keys = vec![
             String::from(PROGRAM_ID),
             String::default(),
-            String::from(REPORTER),
+            third_account,
             String::from(CASE),
         ];
 
@@ -169,11 +198,8 @@ impl SolanaMock {
         }
 
         create_test_tx(
-            &vec![(
-                name.as_str(),
-                InstructionData::Raw(String::from("Some data")),
-            )],
-            hash.to_string(),
+            &vec![(name.as_str(), get_instruction_data(event))],
+            event.hash.clone(),
             account_keys,
         )
     }
@@ -208,10 +234,10 @@ impl SolanaMock {
             .create();
     }
 
-    fn mock_transaction(&mut self, name: String, hash: &str) {
+    fn mock_transaction(&mut self, event: &TestData) {
         let response = json!({
            "jsonrpc": "2.0",
-           "result": json!(SolanaMock::get_transaction(name, hash)),
+           "result": json!(SolanaMock::get_transaction(event)),
            "id": 1
         });
 
@@ -223,14 +249,14 @@ impl SolanaMock {
             .match_body(Matcher::PartialJson(json!({
                 "method": "getTransaction",
                 "params": [
-                    hash,
+                    event.hash,
                     "json"
                   ]
             })))
             .create();
     }
 
-    fn get_account_data(payload_data: PushData) -> (Pubkey, Vec<u8>) {
+    fn get_account_data(payload_data: PushData) -> Option<(Pubkey, Vec<u8>)> {
         let mut data = Vec::new();
 
         let address = match payload_data {
@@ -309,13 +335,18 @@ impl SolanaMock {
 
                 REPORTER
             }
+            PushData::Authority(_) | PushData::Configuration(_) => return None,
         };
 
-        (Pubkey::from_str(address).expect("Invalid address"), data)
+        Some((Pubkey::from_str(address).expect("Invalid address"), data))
     }
 
     fn mock_accounts(&mut self, payload_data: &PushData) {
-        let (address, data) = SolanaMock::get_account_data(payload_data.clone());
+        // Authority and configuration are taken from the instruction
+        let (address, data) = match SolanaMock::get_account_data(payload_data.clone()) {
+            Some(account) => account,
+            None => return,
+        };
 
         let account = Account {
             lamports: 100,
@@ -364,3 +395,113 @@ pub fn encode_address(address: &str) -> [u8; 64] {
 
     res
 }
+
+fn get_instruction_data(event: &TestData) -> InstructionData {
+    let data = match (&event.name, &event.data) {
+        (EventName::Initialize, _) => {
+            DecodedInstructionData::CreateNetwork(CreateNetworkData::default())
+        }
+        (
+            EventName::UpdateStakeConfiguration,
+            Some(PushData::Configuration(ContractConfiguration::Stake(configuration))),
+        ) => {
+            DecodedInstructionData::UpdateStakeConfiguration(hapi_core_solana::StakeConfiguration {
+                unlock_duration: configuration.unlock_duration,
+                validator_stake: configuration.validator_stake.clone().into(),
+                tracer_stake: configuration.tracer_stake.clone().into(),
+                publisher_stake: configuration.publisher_stake.clone().into(),
+                authority_stake: configuration.authority_stake.clone().into(),
+                ..Default::default()
+            })
+        }
+        (
+            EventName::UpdateRewardConfiguration,
+            Some(PushData::Configuration(ContractConfiguration::Reward(configuration))),
+        ) => DecodedInstructionData::UpdateRewardConfiguration(
+            hapi_core_solana::RewardConfiguration {
+                address_tracer_reward: configuration.address_tracer_reward.clone().into(),
+                address_confirmation_reward: configuration
+                    .address_confirmation_reward
+                    .clone()
+                    .into(),
+                asset_tracer_reward: configuration.asset_tracer_reward.clone().into(),
+                asset_confirmation_reward: configuration.asset_confirmation_reward.clone().into(),
+            },
+        ),
+        (EventName::SetAuthority, _) => DecodedInstructionData::SetAuthority,
+        (EventName::CreateReporter, Some(PushData::Reporter(reporter))) => {
+            DecodedInstructionData::CreateReporter(CreateReporterData {
+                reporter_id: reporter.id.as_u128(),
+                account: Pubkey::from_str(&reporter.account).expect("Invalid reporter address"),
+                name: reporter.name.clone(),
+                role: reporter.role.clone().into(),
+                url: reporter.url.clone(),
+                bump: 255,
+            })
+        }
+        (EventName::UpdateReporter, Some(PushData::Reporter(reporter))) => {
+            DecodedInstructionData::UpdateReporter(UpdateReporterData {
+                account: Pubkey::from_str(&reporter.account).expect("Invalid reporter address"),
+                name: reporter.name.clone(),
+                role: reporter.role.clone().into(),
+                url: reporter.url.clone(),
+            })
+        }
+        (EventName::ActivateReporter, _) => DecodedInstructionData::ActivateReporter,
+        (EventName::DeactivateReporter, _) => DecodedInstructionData::DeactivateReporter,
+        (EventName::Unstake, _) => DecodedInstructionData::Unstake,
+        (EventName::CreateCase, Some(PushData::Case(case))) => {
+            DecodedInstructionData::CreateCase(CreateCaseData {
+                case_id: case.id.as_u128(),
+                name: case.name.clone(),
+                url: case.url.clone(),
+                bump: 255,
+            })
+        }
+        (EventName::UpdateCase, Some(PushData::Case(case))) => {
+            DecodedInstructionData::UpdateCase(UpdateCaseData {
+                name: case.name.clone(),
+                url: case.url.clone(),
+                status: case.status.clone().into(),
+            })
+        }
+        (EventName::CreateAddress, Some(PushData::Address(address))) => {
+            DecodedInstructionData::CreateAddress(CreateAddressData {
+                address: encode_address(&address.address),
+                category: address.category.clone().into(),
+                risk: address.risk,
+                bump: 255,
+            })
+        }
+        (EventName::UpdateAddress, Some(PushData::Address(address))) => {
+            DecodedInstructionData::UpdateAddress(UpdateAddressData {
+                category: address.category.clone().into(),
+                risk: address.risk,
+            })
+        }
+        (EventName::ConfirmAddress, _) => DecodedInstructionData::ConfirmAddress(255),
+        (EventName::CreateAsset, Some(PushData::Asset(asset))) => {
+            let mut asset_id = [0_u8; 64];
+            byte_array_from_str(&asset.asset_id.to_string(), &mut asset_id)
+                .expect("Failed to parse asset id");
+
+            DecodedInstructionData::CreateAsset(CreateAssetData {
+                addr: encode_address(&asset.address),
+                asset_id,
+                category: asset.category.clone().into(),
+                risk_score: asset.risk,
+                bump: 255,
+            })
+        }
+        (EventName::UpdateAsset, Some(PushData::Asset(asset))) => {
+            DecodedInstructionData::UpdateAsset(UpdateAssetData {
+                category: asset.category.clone().into(),
+                risk_score: asset.risk,
+            })
+        }
+        (EventName::ConfirmAsset, _) => DecodedInstructionData::ConfirmAsset(255),
+        (name, data) => panic!("Unexpected test data for {name}: {data:?}"),
+    };
+
+    InstructionData::Decoded(data)
+}
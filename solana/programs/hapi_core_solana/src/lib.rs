@@ -6,9 +6,10 @@ mod error;
 mod state;
 
 use context::*;
-use error::{print_error, ErrorCode};
+use error::print_error;
 use state::confirmation::*;
 
+pub use error::ErrorCode;
 pub use state::{
     address::Address,
     asset::Asset,